@@ -12,12 +12,14 @@
 //       - warm_optimized: with skip_expensive_for_stale (~2-3s)
 //       - warm_worktrees_only: no branch enumeration (~600ms)
 //   - timeout_effect: Compare with/without 500ms command timeout on rust repo / GH #461 fix
+//   - parallelism: 100 branches with sequential, bounded (default), and unbounded task pools
 //
 // Run examples:
 //   cargo bench --bench list                         # All benchmarks
 //   cargo bench --bench list skeleton                # Progressive rendering
 //   cargo bench --bench list real_repo_many_branches # GH #461 scenario (large repo + many branches)
 //   cargo bench --bench list timeout_effect          # Test timeout fix for GH #461
+//   cargo bench --bench list parallelism             # Validate WORKTRUNK_MAX_PARALLELISM default
 //   cargo bench --bench list -- --skip cold          # Skip cold cache variants
 //   cargo bench --bench list -- --skip real          # Skip rust repo clone
 
@@ -520,12 +522,42 @@ fn bench_timeout_effect(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_parallelism(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallelism");
+    let binary = get_release_binary();
+
+    let config = BenchConfig::branches(100, 2, false);
+    let temp = create_repo(&config.repo);
+    let repo_path = temp.path().join("repo");
+    run_git(&repo_path, &["status"]);
+
+    // 1 = one task at a time, unset = min(cores, 8), 0 = global pool (2x cores)
+    for (label, max_parallelism) in [
+        ("sequential", Some("1")),
+        ("bounded", None),
+        ("unbounded", Some("0")),
+    ] {
+        group.bench_function(label, |b| {
+            run_benchmark(
+                b,
+                binary,
+                &repo_path,
+                &config,
+                &["list", "--branches"],
+                max_parallelism.map(|n| ("WORKTRUNK_MAX_PARALLELISM", n)),
+            );
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default()
         .sample_size(30)
         .measurement_time(std::time::Duration::from_secs(15))
         .warm_up_time(std::time::Duration::from_secs(3));
-    targets = bench_skeleton, bench_complete, bench_worktree_scaling, bench_real_repo, bench_many_branches, bench_divergent_branches, bench_real_repo_many_branches, bench_timeout_effect
+    targets = bench_skeleton, bench_complete, bench_worktree_scaling, bench_real_repo, bench_many_branches, bench_divergent_branches, bench_real_repo_many_branches, bench_timeout_effect, bench_parallelism
 }
 criterion_main!(benches);
@@ -29,8 +29,8 @@ eval "$(wt config shell init zsh)"
     )]
     Init {
         /// Shell to generate code for
-        #[arg(value_enum)]
-        shell: Shell,
+        #[arg(value_enum, required_unless_present = "print_path")]
+        shell: Option<Shell>,
 
         /// Command name for shell integration (defaults to binary name)
         ///
@@ -39,6 +39,25 @@ eval "$(wt config shell init zsh)"
         /// instead of `wt`, useful on Windows where `wt` conflicts with Windows Terminal.
         #[arg(long)]
         cmd: Option<String>,
+
+        /// Print the files `install` would write, then exit
+        ///
+        /// Prints the shell config file candidates, in order of preference
+        /// (`install` edits the first that exists), then the completions file
+        /// for fish. Nothing is written. Without a shell argument, uses the
+        /// shell from `$SHELL`.
+        #[arg(long)]
+        print_path: bool,
+
+        /// Output format for --print-path (table, json)
+        #[arg(
+            long,
+            value_enum,
+            default_value = "table",
+            hide_possible_values = true,
+            requires = "print_path"
+        )]
+        format: super::OutputFormat,
     },
 
     /// Write shell integration to config files
@@ -57,8 +76,22 @@ Install for specific shell only:
 wt config shell install zsh
 ```
 
+Preview the lines and target files without writing anything:
+```console
+wt config shell install --dry-run
+```
+
 Shows proposed changes and waits for confirmation before modifying any files.
-Use --yes to skip confirmation."#
+Use --yes to skip confirmation.
+
+## cmd (Windows)
+
+cmd.exe integration runs through [clink](https://chrisant996.github.io/clink/) and is only installed when requested:
+```console
+wt config shell install cmd
+```
+
+This writes `%LOCALAPPDATA%\clink\wt.lua`. Directory changes and `--execute` work; tab completions are not available, and `--execute` commands use cmd syntax."#
     )]
     Install {
         /// Shell to install (default: all)
@@ -135,6 +168,20 @@ Detects various forms of the integration pattern regardless of:
     )]
     ShowTheme,
 
+    /// Show shell integration status
+    #[command(
+        after_long_help = r#"Reports, for each supported shell, whether the config file sources the shell integration and where the line was found. Uses the same detection as `wt config shell install`.
+
+## Examples
+
+Check which shells are configured:
+
+```console
+wt config shell status
+```"#
+    )]
+    Status,
+
     /// Generate static shell completions for package managers
     ///
     /// Outputs static completion scripts for Homebrew and other package managers.
@@ -222,14 +269,45 @@ wt config show --full
 
 This tests:
 - **CI tool status** — Whether `gh` (GitHub) or `glab` (GitLab) is installed and authenticated
-- **Commit generation** — Whether the LLM command can generate commit messages"#
+- **Commit generation** — Whether the LLM command can generate commit messages
+
+## Effective values
+
+Use `--effective` to print the settings actually in effect for the current project, after applying defaults, the user config, `WORKTRUNK_*` environment variables, and `[projects."..."]` overrides. Each value is annotated with its source (`default`, `user`, or `project`):
+
+```console
+wt config show --effective
+wt config show --effective --format=json
+```"#
     )]
     Show {
         /// Run diagnostic checks (CI tools, commit generation)
-        #[arg(long)]
+        #[arg(long, conflicts_with = "effective")]
         full: bool,
+
+        /// Print effective settings with their sources
+        #[arg(long)]
+        effective: bool,
+
+        /// Output format for --effective (table, json)
+        #[arg(
+            long,
+            value_enum,
+            default_value = "table",
+            hide_possible_values = true,
+            requires = "effective"
+        )]
+        format: super::OutputFormat,
     },
 
+    /// Open user config in an editor
+    #[command(
+        after_long_help = r#"Opens the user config (`~/.config/worktrunk/config.toml`, or the `--config` / `WORKTRUNK_CONFIG_PATH` override) in `$VISUAL` or `$EDITOR`, falling back to `vi` (`notepad` on Windows).
+
+Creates the file with the documented example first if it doesn't exist. After the editor exits, the config is re-parsed and any error is reported immediately."#
+    )]
+    Edit,
+
     /// Manage internal data and cache
     #[command(
         after_long_help = r#"State is stored in `.git/` (config entries and log files), separate from configuration files.
@@ -456,6 +534,7 @@ as `worktrunk.hints.<name> = true`.
 | Name | Trigger | Message |
 |------|---------|---------|
 | `worktree-path` | First `wt switch --create` | Customize worktree locations: wt config create |
+| `pack-refs` | `wt list --branches` with over 10,000 branches | Speed up listing: git pack-refs --all or git gc |
 
 ## Examples
 
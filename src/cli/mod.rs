@@ -39,6 +39,31 @@ pub(super) fn parse_key_val(s: &str) -> Result<(String, String), String> {
     Ok((key.to_string(), value.to_string()))
 }
 
+/// Parse a duration like `7d` or `48h` for `wt list --since`.
+///
+/// Units: `s` (seconds), `m` (minutes), `h` (hours), `d` (days), `w` (weeks).
+fn parse_duration(s: &str) -> Result<std::time::Duration, String> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| format!("missing unit in `{s}`; use e.g. 7d, 48h, 30m"))?;
+    let (amount, unit) = s.split_at(split);
+    let amount: u64 = amount
+        .parse()
+        .map_err(|_| format!("invalid duration `{s}`; use e.g. 7d, 48h, 30m"))?;
+    let seconds_per_unit = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(format!("unknown unit `{unit}`; use s, m, h, d, or w")),
+    };
+    Ok(std::time::Duration::from_secs(
+        amount.saturating_mul(seconds_per_unit),
+    ))
+}
+
 /// Custom styles for help output - matches worktrunk's color scheme
 fn help_styles() -> Styles {
     Styles::styled()
@@ -186,6 +211,47 @@ pub(crate) enum OutputFormat {
     ClaudeCode,
 }
 
+/// Output formats for `wt list`, which has line-oriented and markdown output
+/// on top of the shared table and JSON formats.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub(crate) enum ListFormat {
+    /// Human-readable table format
+    Table,
+    /// JSON output
+    Json,
+    /// JSON Lines: one object per line
+    Jsonl,
+    /// GitHub-flavored markdown table
+    Markdown,
+}
+
+impl ListFormat {
+    /// Whether the output is structured JSON (`json` or `jsonl`).
+    pub(crate) fn is_json(self) -> bool {
+        matches!(self, Self::Json | Self::Jsonl)
+    }
+}
+
+/// How `wt list` groups rows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum ListGroupBy {
+    /// A single table
+    #[default]
+    None,
+    /// Group by dominant status: conflicts, dirty, behind main, clean
+    Status,
+}
+
+/// How `wt list` orders rows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum ListSort {
+    /// Current and primary worktrees first, then most recent commit first
+    #[default]
+    Recent,
+    /// Worktrees by path, then branches by name
+    Paths,
+}
+
 #[derive(Parser)]
 #[command(name = "wt")]
 #[command(about = "Git worktree management for parallel AI agent workflows", long_about = None)]
@@ -230,17 +296,37 @@ pub(crate) struct Cli {
     )]
     pub config: Option<std::path::PathBuf>,
 
+    /// User config profile to apply
+    #[arg(
+        long,
+        global = true,
+        value_name = "name",
+        display_order = 102,
+        help_heading = "Global Options"
+    )]
+    pub profile: Option<String>,
+
     /// Verbose output (-v: hooks, templates; -vv: debug report)
     #[arg(
         long,
         short = 'v',
         global = true,
         action = clap::ArgAction::Count,
-        display_order = 102,
+        display_order = 103,
         help_heading = "Global Options"
     )]
     pub verbose: u8,
 
+    /// Suppress hints and progress messages
+    #[arg(
+        long,
+        short = 'q',
+        global = true,
+        display_order = 104,
+        help_heading = "Global Options"
+    )]
+    pub quiet: bool,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -268,7 +354,7 @@ wt switch pr:123                 # Switch to PR #123's branch
 
 The `--create` flag creates a new branch from the `--base` branch (defaults to default branch). Without `--create`, the branch must already exist.
 
-**Upstream tracking:** Branches created with `--create` have no upstream tracking configured. This prevents accidental pushes to the wrong branch — for example, `--base origin/main` would otherwise make `git push` target `main`. Use `git push -u origin <branch>` to set up tracking when you're ready.
+**Upstream tracking:** Branches created with `--create` have no upstream tracking configured. This prevents accidental pushes to the wrong branch — for example, `--base origin/main` would otherwise make `git push` target `main`. Use `--track origin` to track an existing `origin/<branch>`, or `git push -u origin <branch>` to set up tracking when you're ready.
 
 Without `--create`, switching to a remote branch (e.g., `wt switch feature` when only `origin/feature` exists) creates a local branch tracking the remote — this is the standard git behavior and is preserved.
 
@@ -381,6 +467,7 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
 - [`wt merge`](@/merge.md) — Integrate changes back to the default branch
 "#
     )]
+    #[command(group(clap::ArgGroup::new("target").args(["branch", "from_stdin"])))]
     Switch {
         /// Branch name or shortcut
         ///
@@ -389,24 +476,82 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
         #[arg(add = crate::completion::worktree_branch_completer())]
         branch: Option<String>,
 
+        /// Read the branch name from stdin
+        ///
+        /// Reads one line and trims whitespace, for piping a picker's
+        /// selection: `wt list --format=json | jq -r '.[].branch' | fzf | wt switch --from-stdin`.
+        #[arg(long, conflicts_with_all = ["branches", "remotes"])]
+        from_stdin: bool,
+
+        /// Create worktrees from a JSON manifest on stdin
+        ///
+        /// Reads an array of `{"branch": ..., "base": ...}` objects (`base`
+        /// defaults to the default branch) and creates a new branch and worktree
+        /// for each, continuing past failures. Repeats of a branch fail as
+        /// duplicates. Prints a JSON summary to stdout and exits non-zero if any
+        /// entry failed. Hooks don't run.
+        #[arg(long, conflicts_with_all = ["branch", "from_stdin", "branches", "remotes", "create", "base", "clobber", "copy_env", "stash", "execute", "execute_args", "open", "dry_run"])]
+        json_stdin: bool,
+
         /// Include branches without worktrees (interactive picker)
-        #[arg(long, conflicts_with_all = ["create", "base", "execute", "execute_args", "clobber"])]
+        #[arg(long, conflicts_with_all = ["create", "base", "execute", "execute_args", "open", "clobber"])]
         branches: bool,
 
         /// Include remote branches (interactive picker)
-        #[arg(long, conflicts_with_all = ["create", "base", "execute", "execute_args", "clobber"])]
+        #[arg(long, conflicts_with_all = ["create", "base", "execute", "execute_args", "open", "clobber"])]
         remotes: bool,
 
         /// Create a new branch
-        #[arg(short = 'c', long, requires = "branch")]
+        #[arg(short = 'c', long, requires = "target")]
         create: bool,
 
+        /// With `--create`, switch to the branch's worktree if it already has one
+        ///
+        /// Makes `wt switch --create` idempotent for scripts that don't know
+        /// whether the worktree exists yet.
+        #[arg(long, requires = "create")]
+        reuse: bool,
+
+        /// Create a detached worktree at a commit
+        ///
+        /// Treats the positional argument as a commit-ish (SHA, tag, branch)
+        /// and checks it out with no branch — for throwaway worktrees. The path
+        /// is named `detached-<short SHA>`.
+        #[arg(long, requires = "target", conflicts_with_all = ["create", "base"])]
+        detach: bool,
+
         /// Base branch
         ///
-        /// Defaults to default branch.
-        #[arg(short = 'b', long, requires = "branch", add = crate::completion::branch_value_completer())]
+        /// Defaults to default branch. Any commit-ish works, e.g. a tag
+        /// (`--base v1.2.0`) or SHA; `--base HEAD` branches from the current
+        /// worktree's commit. A remote branch with the same name as the new
+        /// branch (`--create feature --base origin/feature`) is tracked.
+        #[arg(short = 'b', long, requires = "target", add = crate::completion::branch_value_completer())]
         base: Option<String>,
 
+        /// Base on the remote's default branch
+        ///
+        /// Uses the primary remote's default branch (e.g. `origin/main`) as the
+        /// base instead of the local default branch, which may be behind.
+        /// Falls back to the local default branch when the remote HEAD isn't
+        /// configured.
+        #[arg(long, requires = "create", conflicts_with = "base")]
+        base_from_remote_default: bool,
+
+        /// Fetch from the primary remote before creating
+        ///
+        /// Refreshes remote-tracking refs first, e.g. so
+        /// `--base-from-remote-default` starts from the latest commit.
+        #[arg(long, requires = "create")]
+        fetch: bool,
+
+        /// Track the new branch on a remote
+        ///
+        /// Sets `<remote>/<branch>` as the upstream of the branch created by
+        /// `--create`. Warns if the remote branch doesn't exist yet.
+        #[arg(long, value_name = "REMOTE", requires = "create")]
+        track: Option<String>,
+
         /// Command to run after switch
         ///
         /// Replaces the wt process with the command after switching, giving
@@ -432,7 +577,13 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
         /// Template example: `-x 'code {{ worktree_path }}'` opens VS Code
         /// at the worktree, `-x 'tmux new -s {{ branch | sanitize }}'` starts
         /// a tmux session named after the branch.
-        #[arg(short = 'x', long, requires = "branch")]
+        ///
+        /// The command also receives `WORKTRUNK_BRANCH`,
+        /// `WORKTRUNK_WORKTREE_PATH`, and `WORKTRUNK_PRIMARY_PATH` in its
+        /// environment. With shell integration it runs in your shell, so
+        /// `-x 'source .venv/bin/activate'` changes the session; the variables
+        /// are unset afterwards.
+        #[arg(short = 'x', long, requires = "target")]
         execute: Option<String>,
 
         /// Additional arguments for --execute command (after --)
@@ -442,14 +593,55 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
         #[arg(last = true, requires = "execute")]
         execute_args: Vec<String>,
 
+        /// Open the worktree in an editor after switching
+        ///
+        /// Runs `editor-command` from user config (default: `$VISUAL .` or
+        /// `$EDITOR .`) in the worktree. Combines with `--execute`; the editor
+        /// runs last.
+        #[arg(long, requires = "target")]
+        open: bool,
+
         /// Skip approval prompts
         #[arg(short, long)]
         yes: bool,
 
         /// Remove stale paths at target
-        #[arg(long, requires = "branch")]
+        #[arg(long, requires = "target")]
         clobber: bool,
 
+        /// Stash changes before switching
+        ///
+        /// Stashes the current worktree's uncommitted changes (including
+        /// untracked files) and restores them the next time `wt switch`
+        /// returns to that worktree.
+        #[arg(long, requires = "target")]
+        stash: bool,
+
+        /// Use a named path template
+        ///
+        /// Selects an entry from `[templates]` in user config instead of
+        /// `worktree-path`, e.g. `--template fix` for
+        /// `fix = "../{{ repo }}/fixes/{{ branch | sanitize }}"`.
+        #[arg(long, requires = "target")]
+        template: Option<String>,
+
+        /// Directory name for the new worktree
+        ///
+        /// Placed in the directory where `worktree-path` puts worktrees, so
+        /// `--name scratch` creates `../scratch` with the default template.
+        /// Must be relative; the branch name is unaffected.
+        #[arg(long, requires = "create", value_name = "DIR")]
+        name: Option<String>,
+
+        /// Copy untracked env files into a new worktree
+        ///
+        /// Copies `.env` and `.envrc` from the current worktree. Setting
+        /// `copy-on-create` in user config replaces that list and copies on
+        /// every create, without the flag. Missing files are skipped and
+        /// existing files are never overwritten.
+        #[arg(long, requires = "target")]
+        copy_env: bool,
+
         /// Skip directory change after switching
         ///
         /// Hooks still run normally. Useful when hooks handle navigation
@@ -457,9 +649,39 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
         #[arg(long)]
         no_cd: bool,
 
+        /// Only change directory, printing nothing
+        ///
+        /// Writes the cd directive (plus any `--execute` command) and suppresses
+        /// messages, hints and hook announcements; warnings still show. For
+        /// minimal shell wrappers. Requires shell integration.
+        #[arg(long, requires = "target", conflicts_with_all = ["no_cd", "open", "format", "dry_run"])]
+        cd_only: bool,
+
+        /// Show the worktree path and base without switching
+        ///
+        /// Renders the `worktree-path` template (or `--template`) for the
+        /// branch, prints the absolute path to stdout, and exits without
+        /// creating anything, running hooks, or changing directory.
+        #[arg(long, requires = "target", conflicts_with_all = ["execute", "open", "stash"])]
+        dry_run: bool,
+
         /// Skip hooks
         #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
         verify: bool,
+
+        /// Output format (table, json)
+        ///
+        /// With `json`, prints the switch result to stdout as an object with
+        /// `path`, `branch`, `created`, `base`, and `from_remote`. Status
+        /// messages still go to stderr.
+        #[arg(
+            long,
+            value_enum,
+            default_value = "table",
+            hide_possible_values = true,
+            requires = "target"
+        )]
+        format: OutputFormat,
     },
 
     /// List worktrees and their status
@@ -541,10 +763,13 @@ The Status column has multiple subcolumns. Within each, only the first matching
 |-----------|--------|---------|
 | Working tree (1) | `+` | Staged files |
 | Working tree (2) | `!` | Modified files (unstaged) |
+| | `§` | Submodule changes (new commits or dirty content) |
 | Working tree (3) | `?` | Untracked files |
 | Worktree | `✘` | Merge conflicts |
 | | `⤴` | Rebase in progress |
 | | `⤵` | Merge in progress |
+| | `⤷` | Cherry-pick in progress |
+| | `÷` | Bisect in progress |
 | | `/` | Branch without worktree |
 | | `⚑` | Branch-worktree mismatch (branch name doesn't match worktree path) |
 | | `⊟` | Prunable (directory missing) |
@@ -563,7 +788,7 @@ The Status column has multiple subcolumns. Within each, only the first matching
 | | `⇡` | Ahead of remote |
 | | `⇣` | Behind remote |
 
-Rows are dimmed when [safe to delete](@/remove.md#branch-cleanup) (`_` same commit with clean working tree or `⊂` content integrated).
+Rows are dimmed when [safe to delete](@/remove.md#branch-cleanup) (`_` same commit with clean working tree or `⊂` content integrated). Turn off with `[list] dim-removable = false`.
 
 ---
 
@@ -595,6 +820,9 @@ wt list --format=json | jq '.[] | select(.remote.ahead > 0) | {branch, ahead: .r
 
 # Stale CI (local changes not reflected in CI)
 wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
+
+# One object per line, for streaming consumers
+wt list --format=jsonl | jq -c 'select(.working_tree.modified)'
 ```
 
 **Fields:**
@@ -608,7 +836,7 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `working_tree` | object | Working tree state (see below) |
 | `main_state` | string | Relation to the default branch (see below) |
 | `integration_reason` | string | Why branch is integrated (see below) |
-| `operation_state` | string | `"conflicts"`, `"rebase"`, or `"merge"` (absent when clean) |
+| `operation_state` | string | `"conflicts"`, `"rebase"`, `"merge"`, `"cherry_pick"`, or `"bisect"` (absent when clean) |
 | `main` | object | Relationship to the default branch (see below, absent when is_main) |
 | `remote` | object | Tracking branch info (see below, absent when no tracking) |
 | `worktree` | object | Worktree metadata (see below) |
@@ -618,6 +846,7 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `ci` | object | CI status (see below, absent when no CI) |
 | `url` | string | Dev server URL from project config (absent when not configured) |
 | `url_active` | boolean | Whether the URL's port is listening (absent when not configured) |
+| `active` | boolean | A process is running inside the worktree (only with `--active-only`) |
 | `statusline` | string | Pre-formatted status with ANSI colors |
 | `symbols` | string | Raw status symbols without colors (e.g., `"!?↓"`) |
 
@@ -629,6 +858,8 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `short_sha` | string | Short commit SHA (7 chars) |
 | `message` | string | Commit message (first line) |
 | `timestamp` | number | Unix timestamp |
+| `author_name` | string | Commit author name |
+| `author_email` | string | Commit author email |
 
 ### working_tree object
 
@@ -639,6 +870,7 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `untracked` | boolean | Has untracked files |
 | `renamed` | boolean | Has renamed files |
 | `deleted` | boolean | Has deleted files |
+| `submodule` | boolean | Has submodule changes (new commits or dirty content) |
 | `diff` | object | Lines changed vs HEAD: `{added, deleted}` |
 
 ### main object
@@ -705,14 +937,35 @@ Missing a field that would be generally useful? Open an issue at https://github.
         #[command(subcommand)]
         subcommand: Option<ListSubcommand>,
 
-        /// Output format (table, json)
+        /// Output format (table, json, jsonl, markdown)
+        ///
+        /// `jsonl` prints each item as a single-line JSON object, with the
+        /// same schema as the `json` array elements.
+        ///
+        /// `markdown` prints a GitHub-flavored markdown table with the same
+        /// columns as the table view, as plain text, for pasting into PR
+        /// descriptions and notes.
         #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
-        format: OutputFormat,
+        format: ListFormat,
+
+        /// Only include these JSON fields (comma-separated)
+        ///
+        /// Field names match the `--format=json` keys; use dots for nested
+        /// fields, e.g. `--fields branch,path,main.ahead,main.behind`.
+        /// Requires `--format=json` or `jsonl`.
+        #[arg(long, value_name = "FIELDS", value_delimiter = ',')]
+        fields: Vec<String>,
 
         /// Include branches without worktrees
         #[arg(long)]
         branches: bool,
 
+        /// Hide worktree rows, showing only branches
+        ///
+        /// Requires `--branches` (or `branches = true` in `[list]` config).
+        #[arg(long)]
+        no_worktrees: bool,
+
         /// Include remote branches
         #[arg(long)]
         remotes: bool,
@@ -721,6 +974,142 @@ Missing a field that would be generally useful? Open an issue at https://github.
         #[arg(long)]
         full: bool,
 
+        /// Fetch from the primary remote before listing
+        ///
+        /// Refreshes remote-tracking refs so upstream ahead/behind counts are
+        /// current. Runs once up front; skipped when no remote is configured.
+        #[arg(long)]
+        fetch: bool,
+
+        /// Skip upstream tracking status
+        ///
+        /// Hides the Remote⇅ column and skips the per-branch upstream lookup
+        /// and ahead/behind count. Faster for local-only repositories.
+        /// Overrides `[list] upstream`.
+        #[arg(long)]
+        no_upstream: bool,
+
+        /// Print a key for the Status column symbols after the table
+        #[arg(long)]
+        legend: bool,
+
+        /// Count changes, conflicts and ahead in the summary line
+        ///
+        /// The summary after the table always lists how many worktrees have
+        /// changes or are ahead; `--stats` adds worktrees with conflicts and
+        /// shows each count even when it's zero. Table output only.
+        #[arg(long)]
+        stats: bool,
+
+        /// Hide items whose last commit is older than this
+        ///
+        /// A number and unit: `s`, `m`, `h`, `d`, or `w` (e.g. `7d`, `48h`).
+        /// The primary and current worktrees are always shown.
+        #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+        since: Option<std::time::Duration>,
+
+        /// Mark upstreams not fetched within this long
+        ///
+        /// Adds a dimmed ◷ after the Remote⇅ counts when the remote-tracking
+        /// ref hasn't been refreshed within the duration (e.g. `1d`), so its
+        /// ahead/behind may be outdated; `--fetch` refreshes it. Uses file
+        /// times under the git directory, without network access.
+        #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+        since_fetch: Option<std::time::Duration>,
+
+        /// Show only worktrees whose path matches this glob
+        ///
+        /// A leading `~` expands to the home directory, e.g.
+        /// `--path-filter '~/work/*'`. `*` also matches across `/`, so a
+        /// directory pattern covers everything beneath it. Branches without
+        /// worktrees are hidden.
+        #[arg(long, value_name = "GLOB")]
+        path_filter: Option<String>,
+
+        /// Show only worktrees with a running process inside them
+        ///
+        /// A worktree counts as active when some process, such as a dev server
+        /// or an editor, has its working directory inside it; `wt` and the
+        /// shell running it don't count. JSON output marks these worktrees
+        /// with `"active": true`. Uses `/proc` on Linux and `lsof` on other
+        /// Unix systems; not supported on Windows. Branches without worktrees
+        /// are hidden.
+        #[arg(long)]
+        active_only: bool,
+
+        /// Show only items that conflict with the default branch
+        ///
+        /// Runs conflict detection (including uncommitted changes) for every
+        /// row, then keeps rows with conflicts. Slower; disables progressive
+        /// rendering.
+        #[arg(long)]
+        conflicts_only: bool,
+
+        /// Group rows under headers (none, status)
+        ///
+        /// With `status`, worktrees are grouped by their dominant status:
+        /// conflicts, dirty, behind main, then clean. Disables progressive
+        /// rendering, since groups are only known once status is computed.
+        #[arg(
+            long,
+            value_enum,
+            value_name = "GROUP",
+            default_value = "none",
+            hide_possible_values = true
+        )]
+        group_by: ListGroupBy,
+
+        /// Row order (recent, paths)
+        ///
+        /// `recent` (default) puts the current and primary worktrees first,
+        /// then orders by most recent commit. `paths` orders worktrees by
+        /// path and branches by name, independent of commit times, so
+        /// output stays stable across runs (e.g. diffing `--format=json`
+        /// snapshots).
+        #[arg(
+            long,
+            value_enum,
+            value_name = "ORDER",
+            default_value = "recent",
+            hide_possible_values = true
+        )]
+        sort: ListSort,
+
+        /// How to render the Path column (relative, absolute, home)
+        ///
+        /// `relative` (default) shows paths relative to the main worktree,
+        /// `absolute` shows full paths, and `home` shows absolute paths with
+        /// `~` for the home directory. Overrides `[list] path-style`. JSON
+        /// output always has absolute paths.
+        #[arg(long, value_enum, value_name = "STYLE", hide_possible_values = true)]
+        path_style: Option<worktrunk::config::PathStyle>,
+
+        /// Show remote names in the Upstream column
+        ///
+        /// Renders `origin ⇡2 ⇣1` instead of just the arrows. On by default
+        /// when the repository has more than one remote. Overrides
+        /// `[list] remote-names`.
+        #[arg(long)]
+        remote_names: bool,
+
+        /// Lay out the table for this many columns
+        ///
+        /// Overrides the detected terminal width (and `COLUMNS`), e.g. when
+        /// piping to a pager or capturing output for a log. Values below 20
+        /// are raised to 20 so the Branch column stays visible.
+        #[arg(long, value_name = "COLS")]
+        max_width: Option<usize>,
+
+        /// Unaligned table with ASCII symbols
+        ///
+        /// Replaces status glyphs with ASCII (`^` ahead, `v` behind, `M`
+        /// merging, ...), separates cells with a single space and drops colors
+        /// and column alignment. For screen readers and terminals without
+        /// wide-character support. Branch names, paths and messages print
+        /// as-is. Empty cells print as `-`.
+        #[arg(long, conflicts_with_all = ["max_width", "progressive"])]
+        plain: bool,
+
         /// Show fast info immediately, update with slow info
         ///
         /// Displays local data (branches, paths, status) first, then updates
@@ -835,6 +1224,8 @@ Removal runs in the background by default (returns immediately). Logs are writte
         verify: bool,
 
         /// Skip approval prompts
+        ///
+        /// Also skips the confirmation configured by remove-confirm.
         #[arg(short, long)]
         yes: bool,
 
@@ -990,14 +1381,32 @@ Skip committing/squashing (rebase still runs unless --no-rebase):
 wt merge --no-commit
 ```
 
+Keep individual commits behind an explicit merge commit:
+
+```console
+wt merge --no-ff
+```
+
+Merge the default branch into the current worktree instead, keeping it:
+
+```console
+wt merge --into-current
+```
+
+After resolving conflicts, finish the merge (or back out with `--abort`):
+
+```console
+wt merge --continue
+```
+
 ## Pipeline
 
 `wt merge` runs these steps:
 
 1. **Squash** — Stages uncommitted changes, then combines all commits since target into one (like GitHub's "Squash and merge"). Use `--stage` to control what gets staged: `all` (default), `tracked`, or `none`. A backup ref is saved to `refs/wt-backup/<branch>`. With `--no-squash`, uncommitted changes become a separate commit and individual commits are preserved.
-2. **Rebase** — Rebases onto target if behind. Skipped if already up-to-date. Conflicts abort immediately.
+2. **Rebase** — Rebases onto target if behind. Skipped if already up-to-date. Conflicts stop the merge; resolve them and run `wt merge --continue`, or `wt merge --abort` to back out.
 3. **Pre-merge hooks** — Hooks run after rebase, before merge. Failures abort. See [`wt hook`](@/hook.md).
-4. **Merge** — Fast-forward merge to the target branch. Non-fast-forward merges are rejected.
+4. **Merge** — Fast-forward merge to the target branch. Non-fast-forward merges are rejected. With `--no-ff`, an explicit merge commit (first parent: target) is created on top of the rebased branch and the target fast-forwards to it, so `git log --first-parent` on the target shows one entry per merged branch.
 5. **Pre-remove hooks** — Hooks run before removing worktree. Failures abort.
 6. **Cleanup** — Removes the worktree and branch. Use `--no-remove` to keep the worktree. When already on the target branch or in the main worktree, the worktree is preserved.
 7. **Post-merge hooks** — Hooks run after cleanup. Failures are logged but don't abort.
@@ -1076,9 +1485,66 @@ lint = "cargo clippy"
         #[arg(short, long)]
         yes: bool,
 
+        /// Ask before removing the worktree
+        ///
+        /// Names the worktree and waits for `y`; any other answer keeps it.
+        /// `--yes` skips the question. Without a terminal, pass `--yes` or
+        /// `--no-remove` instead.
+        #[arg(long, conflicts_with = "no_remove")]
+        confirm: bool,
+
         /// What to stage before committing [default: all]
         #[arg(long)]
         stage: Option<crate::commands::commit::StageMode>,
+
+        /// Always create a merge commit
+        ///
+        /// Records the branch with an explicit merge commit on the target,
+        /// even when a fast-forward is possible. Implies `--no-squash`; the
+        /// message comes from the same LLM or fallback path as squash.
+        #[arg(long = "no-ff", conflicts_with = "squash")]
+        no_ff: bool,
+
+        /// Merge target into the current branch instead
+        ///
+        /// Brings the target's latest commits into the current worktree with
+        /// `git merge`, keeping the worktree. Conflicts leave the merge in
+        /// progress to resolve.
+        #[arg(long, conflicts_with_all = [
+            "squash", "no_squash", "commit", "no_commit", "rebase", "no_rebase",
+            "remove", "no_remove", "verify", "no_verify", "confirm", "stage", "no_ff", "format",
+        ])]
+        into_current: bool,
+
+        /// Abort a merge stopped on conflicts
+        ///
+        /// Runs `git rebase --abort` (or `git merge --abort` after
+        /// `--into-current`), returning the branch to its state before that
+        /// step. Fails when no merge is in progress.
+        #[arg(long, conflicts_with_all = [
+            "target", "squash", "no_squash", "commit", "no_commit", "rebase", "no_rebase",
+            "remove", "no_remove", "verify", "no_verify", "yes", "confirm", "stage", "no_ff",
+            "into_current", "continue_merge", "format",
+        ])]
+        abort: bool,
+
+        /// Resume a merge stopped on conflicts
+        ///
+        /// After resolving and staging the conflicts, continues the rebase,
+        /// then finishes the remaining `wt merge` steps: pre-merge hooks,
+        /// updating the target, removal and post-merge hooks. Pass the same
+        /// target and options as the original command. After
+        /// `--into-current`, only completes the merge.
+        #[arg(long = "continue", conflicts_with = "into_current")]
+        continue_merge: bool,
+
+        /// Output format (table, json)
+        ///
+        /// With `json`, prints the merge result to stdout as an object with
+        /// `target`, `squashed`, `kept`, `merge_commit`, and
+        /// `removed_worktree`. Status messages still go to stderr.
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
     },
     /// Deprecated: use `wt switch` instead
     ///
@@ -1094,6 +1560,71 @@ lint = "cargo clippy"
         remotes: bool,
     },
 
+    /// Show commits a branch has beyond the default branch
+    ///
+    /// Lists commits newest first, as short SHA and subject, after noting
+    /// where the branch forked from the default branch.
+    #[command(after_long_help = r#"## Examples
+
+Commits on the current worktree's branch:
+
+```console
+wt log
+```
+
+Commits on another branch, up to 50:
+
+```console
+wt log feature --limit 50
+```
+"#)]
+    Log {
+        /// Branch to show
+        ///
+        /// Defaults to the current worktree's branch.
+        #[arg(add = crate::completion::branch_value_completer())]
+        branch: Option<String>,
+
+        /// Maximum number of commits to show
+        #[arg(
+            long,
+            default_value_t = 20,
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+        )]
+        limit: usize,
+    },
+
+    /// Show how much a worktree has changed
+    ///
+    /// Prints added and deleted line counts since the worktree forked from
+    /// the default branch, including uncommitted changes, without the diff
+    /// itself. Notes the fork point once the worktree has commits of its own.
+    #[command(after_long_help = r#"## Examples
+
+Changes in the current worktree:
+
+```console
+wt diff
+```
+
+Changes in another worktree, versus a release branch:
+
+```console
+wt diff feature --vs release
+```
+"#)]
+    Diff {
+        /// Branch whose worktree to summarize
+        ///
+        /// Defaults to the current worktree.
+        #[arg(add = crate::completion::worktree_only_completer())]
+        branch: Option<String>,
+
+        /// Compare against this ref instead of the default branch
+        #[arg(long, value_name = "REF", add = crate::completion::branch_value_completer())]
+        vs: Option<String>,
+    },
+
     /// Run individual operations
     ///
     /// The building blocks of `wt merge` — commit, squash, rebase, push — plus standalone utilities.
@@ -1328,10 +1859,11 @@ Hooks can use template variables that expand at runtime:
 | `{{ remote_url }}` | Remote URL |
 | `{{ upstream }}` | Upstream tracking branch (if set) |
 | `{{ target }}` | Target branch (merge hooks only) |
+| `{{ squashed }}` | `true` when the merge squashed commits (post-merge hooks only) |
 | `{{ base }}` | Base branch (creation hooks only) |
 | `{{ base_worktree_path }}` | Base branch worktree (creation hooks only) |
 
-Some variables may not be defined: `upstream` is only set when the branch tracks a remote; `target`, `base`, and `base_worktree_path` are hook-specific. Using an undefined variable directly errors — use conditionals for optional behavior:
+Some variables may not be defined: `upstream` is only set when the branch tracks a remote; `target`, `squashed`, `base`, and `base_worktree_path` are hook-specific. Using an undefined variable directly errors — use conditionals for optional behavior:
 
 ```toml
 [post-create]
@@ -1395,6 +1927,26 @@ if ctx['branch'].startswith('feature/') and 'backend' in ctx['repo']:
 
 The JSON includes all template variables plus `hook_type` and `hook_name`.
 
+### Environment variables
+
+Hook commands also receive the worktree context as environment variables, which is convenient for scripts that don't parse JSON:
+
+| Variable | Description |
+|----------|-------------|
+| `WORKTRUNK_BRANCH` | Branch name (`HEAD` when detached) |
+| `WORKTRUNK_WORKTREE_PATH` | Absolute path to the worktree |
+| `WORKTRUNK_PRIMARY_PATH` | Primary worktree path (same as `{{ primary_worktree_path }}`, falling back to the repo root) |
+
+The same variables are set for `wt switch --execute` commands.
+
+Post-merge hooks additionally receive the merge itself:
+
+| Variable | Description |
+|----------|-------------|
+| `WORKTRUNK_MERGED_BRANCH` | Branch that was merged |
+| `WORKTRUNK_TARGET_BRANCH` | Branch it was merged into |
+| `WORKTRUNK_SQUASHED` | `true` if the branch's commits were squashed, otherwise `false` |
+
 ## Running hooks manually
 
 `wt hook <type>` runs hooks on demand — useful for testing during development, running in CI pipelines, or re-running after a failure.
@@ -1584,6 +2136,12 @@ Show current configuration and file locations:
 wt config show
 ```
 
+Open the user config in `$EDITOR`:
+
+```console
+wt config edit
+```
+
 ## Configuration files
 
 | File | Location | Contains | Committed & shared |
@@ -1650,7 +2208,15 @@ worktree-path = "{{ repo_path }}/.worktrees/{{ branch | sanitize }}"
 worktree-path = "~/worktrees/{{ repo }}/{{ branch | sanitize }}"
 ```
 
-`~` expands to the home directory. Relative paths are relative to the repository root.
+A leading `~` (or `~user`) expands to the home directory, and environment variables like `$HOME` or `${XDG_DATA_HOME}` are expanded. Relative paths are relative to the repository root. `wt switch` errors if the rendered path climbs out of the directory that holds the branch's component, as in `{{ branch }}/../../elsewhere`.
+
+**Named templates** for different kinds of work, selected with `wt switch --template <name>`. Without `--template`, `worktree-path` applies.
+
+```toml
+[templates]
+fix = "{{ repo_path }}/../{{ repo }}/fixes/{{ branch | sanitize }}"
+feature = "{{ repo_path }}/../{{ repo }}/features/{{ branch | sanitize }}"
+```
 
 ## LLM commit messages
 
@@ -1669,8 +2235,24 @@ Persistent flag values for `wt list`. Override on command line as needed.
 full = false       # Show CI status and main…± diffstat columns (--full)
 branches = false   # Include branches without worktrees (--branches)
 remotes = false    # Include remote-only branches (--remotes)
+branch-width = 40  # Truncate longer branch names with … (0 = no limit)
+show-author = false  # Author column with the last commit author's initials
+path-style = "relative"  # Path column: relative, absolute, or home (--path-style)
+remote-names = false  # Remote name in the Upstream column (--remote-names; default: on with multiple remotes)
+time-format = "compact"  # Age column: compact ("2d") or long ("2 days ago")
+upstream = true  # Upstream ahead/behind in the Remote⇅ column (--no-upstream to skip)
+dim-removable = true  # Gray out rows with no unique work (integrated or empty branches)
 ```
 
+Choose which columns appear, and in what order, with `columns`. Unlisted columns are hidden; the table still drops low-priority columns when the terminal is narrow.
+
+```toml
+[list]
+columns = ["branch", "status", "ahead_behind", "path"]
+```
+
+Available columns: `branch`, `status`, `working_diff`, `ahead_behind`, `branch_diff`, `path`, `upstream`, `url`, `ci_status`, `commit`, `time`, `author`, `message`.
+
 ### Commit
 
 Shared by `wt step commit`, `wt step squash`, and `wt merge`.
@@ -1693,6 +2275,18 @@ remove = true      # Remove worktree after merge (--no-remove to keep)
 verify = true      # Run project hooks (--no-verify to skip)
 ```
 
+### Remove
+
+When `wt remove` asks before removing a worktree. `--yes` always skips the prompt. Without a terminal, removals that would prompt require `--yes` instead.
+
+```toml
+remove-confirm = "unsafe"  # "unsafe", "always", or "never"
+```
+
+- `unsafe` (default): ask when commits not in the default branch would be lost — force-deleting an unmerged branch (`-D`), or removing a detached worktree that's ahead. The prompt also notes when a force-deleted branch has commits not pushed to its upstream
+- `always`: ask before every worktree removal
+- `never`: don't ask
+
 ### Select
 
 Pager behavior for `wt switch` interactive picker diff previews.
@@ -1705,6 +2299,71 @@ Pager behavior for `wt switch` interactive picker diff previews.
 # pager = "delta --paging=never"
 ```
 
+### Editor
+
+Command `wt switch --open` runs in the worktree after switching. Defaults to `$VISUAL .` (or `$EDITOR .`), and supports the same template variables as `--execute`.
+
+```toml
+editor-command = "code {{ worktree_path }}"
+```
+
+### Maintenance
+
+Write git's commit-graph in the background on the first `wt list` in a repo that doesn't have one. The commit-graph speeds up the ahead/behind and integration checks `wt list` runs for every branch. Worktrunk records that it ran (`worktrunk.maintenance.commit-graph` in git config) and doesn't try again. Default off.
+
+```toml
+maintenance = true
+```
+
+### Stale base
+
+`wt switch --create` warns when the base branch is more than this many commits behind its upstream, e.g. a local `main` that hasn't been pulled in weeks. Counts against the last-fetched remote-tracking ref, so combine with `--fetch` for an up-to-date check. Default 10; 0 disables the warning.
+
+```toml
+stale-base-threshold = 10
+```
+
+### Integration hint
+
+After `wt switch`, when shell integration is installed for the current shell but not active (e.g. the shell hasn't been restarted, or `wt` runs from a script), a hint suggests restarting the shell. Set to false to stop the reminder. The install hint still shows when integration is missing.
+
+```toml
+show-integration-hint = true
+```
+
+### Copy on create
+
+Untracked files copied from the current worktree into each new worktree created by `wt switch`, before post-create hooks run. Paths are relative to the worktree root; missing files are skipped and existing files are never overwritten. Unset, only `wt switch --copy-env` copies, using `.env` and `.envrc`.
+
+```toml
+copy-on-create = [".env", ".env.local"]
+```
+
+### Theme
+
+Colors for `wt list`. Each role takes a color name (`green`, `bright-blue`) or hex value (`#ff8800`), optionally with `bold`, `dim`, `italic` or `underline`; `none` removes styling. Unset roles keep the built-in colors, shown here except for `current` and `ci-failed`.
+
+```toml
+[theme]
+addition = "green"
+deletion = "red"
+current = "bold cyan"
+primary = "none"
+ci-passed = "green"
+ci-failed = "bright-red"
+dimmed = "dim"
+```
+
+### Profiles
+
+Named profiles override top-level settings, e.g. different worktree layouts on work and personal machines. Select one with `--profile <name>` or the `WORKTRUNK_CONFIG_PROFILE` environment variable. A profile only changes the keys it sets; everything else keeps its top-level value.
+
+```toml
+[profiles.work]
+worktree-path = "~/work/{{ repo }}/{{ branch | sanitize }}"
+merge.squash = false
+```
+
 ### User project-specific settings
 
 For context:
@@ -1876,6 +2535,8 @@ wt config shell init fish | source
 
 Without shell integration, `wt switch` prints the target directory but cannot `cd` into it.
 
+To check which shells are configured, run `wt config shell status`.
+
 ### First-run prompts
 
 On first run without shell integration, Worktrunk offers to install it. Similarly, on first commit without LLM configuration, it offers to configure a detected tool (`claude`, `codex`). Declining sets `skip-shell-integration-prompt` or `skip-commit-generation-prompt` automatically.
@@ -1914,9 +2575,11 @@ WORKTRUNK_COMMIT__GENERATION__COMMAND="echo 'test: automated commit'" wt merge
 |----------|---------|
 | `WORKTRUNK_BIN` | Override binary path for shell wrappers (useful for testing dev builds) |
 | `WORKTRUNK_CONFIG_PATH` | Override user config file location |
+| `WORKTRUNK_CONFIG_PROFILE` | Select a `[profiles.<name>]` table from user config (same as `--profile`) |
 | `WORKTRUNK_DIRECTIVE_FILE` | Internal: set by shell wrappers to enable directory changes |
 | `WORKTRUNK_SHELL` | Internal: set by shell wrappers to indicate shell type (e.g., `powershell`) |
 | `WORKTRUNK_MAX_CONCURRENT_COMMANDS` | Max parallel git commands (default: 32). Lower if hitting file descriptor limits. |
+| `WORKTRUNK_MAX_PARALLELISM` | Max concurrent `wt list` tasks (default: CPU cores, up to 8; `0` = no cap). Lower if disk I/O thrashes. |
 | `NO_COLOR` | Disable colored output ([standard](https://no-color.org/)) |
 | `CLICOLOR_FORCE` | Force colored output even when not a TTY |
 <!-- subdoc: show -->
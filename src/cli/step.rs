@@ -136,6 +136,7 @@ wt step squash --show-prompt | less
 ```console
 wt step push             # Fast-forward main to current branch
 wt step push develop     # Fast-forward develop instead
+wt step push -u          # Also make main track origin/main
 ```
 
 Similar to `git push . HEAD:<target>`, but uses `receive.denyCurrentBranch=updateInstead` internally.
@@ -147,6 +148,14 @@ Similar to `git push . HEAD:<target>`, but uses `receive.denyCurrentBranch=updat
         /// Defaults to default branch.
         #[arg(add = crate::completion::branch_value_completer())]
         target: Option<String>,
+
+        /// Make the target track its remote branch
+        ///
+        /// After the fast-forward, sets the target's upstream to
+        /// `<remote>/<target>` on the primary remote. Skipped with a note when
+        /// no such remote branch exists.
+        #[arg(short = 'u', long)]
+        set_upstream: bool,
     },
 
     /// Rebase onto target
@@ -38,12 +38,14 @@ pub fn delete_branch_if_safe(
 
     // Determine outcome based on integration and force flag
     let outcome = match (reason, force_delete) {
+        // Force even when integrated: squash merges and the like are
+        // integrated by our checks but still "unmerged" to `git branch -d`
         (Some(r), _) => {
-            repo.run_command(&["branch", "-D", branch_name])?;
+            repo.delete_branch(branch_name, true)?;
             BranchDeletionOutcome::Integrated(r)
         }
         (None, true) => {
-            repo.run_command(&["branch", "-D", branch_name])?;
+            repo.delete_branch(branch_name, true)?;
             BranchDeletionOutcome::ForceDeleted
         }
         (None, false) => BranchDeletionOutcome::NotDeleted,
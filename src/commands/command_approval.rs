@@ -25,7 +25,7 @@ use color_print::cformat;
 use worktrunk::config::UserConfig;
 use worktrunk::git::{GitError, HookType};
 use worktrunk::styling::{
-    INFO_SYMBOL, WARNING_SYMBOL, eprint, eprintln, format_bash_with_gutter, hint_message,
+    INFO_SYMBOL, WARNING_SYMBOL, eprint, eprintln, format_bash_with_gutter, print_hint,
     prompt_message, stderr, warning_message,
 };
 
@@ -97,10 +97,7 @@ pub fn approve_command_batch(
                 "{}",
                 warning_message(format!("Failed to save command approval: {e}"))
             );
-            eprintln!(
-                "{}",
-                hint_message("Approval will be requested again next time.")
-            );
+            print_hint("Approval will be requested again next time.");
         }
     }
 
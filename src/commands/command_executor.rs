@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::path::Path;
 use worktrunk::HookType;
 use worktrunk::config::{Command, CommandConfig, UserConfig, expand_template};
-use worktrunk::git::Repository;
+use worktrunk::git::{Repository, short_sha};
 use worktrunk::path::to_posix_path;
 
 use super::hook_filter::HookSource;
@@ -54,6 +54,27 @@ impl<'a> CommandContext<'a> {
         self.repo.project_identifier().ok()
     }
 
+    /// Environment variables exported to hook and `--execute` commands.
+    ///
+    /// Mirrors the `branch`, `worktree_path`, and `primary_worktree_path` template
+    /// variables so scripts can read them without template expansion.
+    pub fn worktree_env(&self) -> Vec<(&'static str, String)> {
+        let mut env = vec![
+            ("WORKTRUNK_BRANCH", self.branch_or_head().to_string()),
+            (
+                "WORKTRUNK_WORKTREE_PATH",
+                self.worktree_path.to_string_lossy().into_owned(),
+            ),
+        ];
+        if let Ok(primary) = self.repo.home_path() {
+            env.push((
+                "WORKTRUNK_PRIMARY_PATH",
+                primary.to_string_lossy().into_owned(),
+            ));
+        }
+        env
+    }
+
     /// Get the commit generation config, merging project-specific settings.
     pub fn commit_generation(&self) -> worktrunk::config::CommitGenerationConfig {
         self.config.commit_generation(self.project_id().as_deref())
@@ -115,9 +136,7 @@ pub fn build_hook_context(
     if let Ok(commit) = ctx.repo.run_command(&["rev-parse", "HEAD"]) {
         let commit = commit.trim();
         map.insert("commit".into(), commit.into());
-        if commit.len() >= 7 {
-            map.insert("short_commit".into(), commit[..7].into());
-        }
+        map.insert("short_commit".into(), short_sha(commit).into());
     }
 
     if let Ok(remote) = ctx.repo.primary_remote() {
@@ -3,7 +3,7 @@ use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::CommitGenerationConfig;
 use worktrunk::styling::{
-    eprintln, format_with_gutter, hint_message, info_message, progress_message, success_message,
+    eprintln, format_with_gutter, info_message, print_hint, print_progress, success_message,
 };
 
 use super::command_executor::CommandContext;
@@ -67,12 +67,9 @@ impl<'a> CommitGenerator<'a> {
 
     pub fn emit_hint_if_needed(&self) {
         if !self.config.is_configured() {
-            eprintln!(
-                "{}",
-                hint_message(cformat!(
-                    "Using fallback commit message. For LLM setup guide, run <bright-black>wt config --help</>"
-                ))
-            );
+            print_hint(cformat!(
+                "Using fallback commit message. For LLM setup guide, run <bright-black>wt config --help</>"
+            ));
         }
     }
 
@@ -126,7 +123,7 @@ impl<'a> CommitGenerator<'a> {
                 cformat!("{action} <bright-black>({parts_str}</>{paren_close}")
             };
 
-            eprintln!("{}", progress_message(full_progress_msg));
+            print_progress(full_progress_msg);
         }
 
         self.emit_hint_if_needed();
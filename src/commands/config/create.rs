@@ -7,12 +7,12 @@ use color_print::cformat;
 use std::path::PathBuf;
 use worktrunk::git::Repository;
 use worktrunk::path::format_path_for_display;
-use worktrunk::styling::{eprintln, hint_message, info_message, success_message};
+use worktrunk::styling::{eprintln, info_message, print_hint, success_message};
 
 use super::state::require_user_config_path;
 
 /// Example user configuration file content (displayed in help with values uncommented)
-const USER_CONFIG_EXAMPLE: &str = include_str!("../../../dev/config.example.toml");
+pub(super) const USER_CONFIG_EXAMPLE: &str = include_str!("../../../dev/config.example.toml");
 
 /// Example project configuration file content
 const PROJECT_CONFIG_EXAMPLE: &str = include_str!("../../../dev/wt.example.toml");
@@ -109,7 +109,7 @@ fn create_config_file(
                 "To view, run <bright-black>wt config show</>. To create a project config, run <bright-black>wt config create --project</>"
             )
         };
-        eprintln!("{}", hint_message(hint));
+        print_hint(hint);
         return Ok(());
     }
 
@@ -133,7 +133,7 @@ fn create_config_file(
     );
     eprintln!();
     for hint in success_hints {
-        eprintln!("{}", hint_message(*hint));
+        print_hint(*hint);
     }
 
     Ok(())
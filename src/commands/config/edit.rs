@@ -0,0 +1,77 @@
+//! Config file editing.
+//!
+//! Opens the user config in the user's editor, creating it first if needed.
+
+use std::process::Stdio;
+
+use anyhow::Context;
+use color_print::cformat;
+use shell_escape::unix::escape;
+use worktrunk::config::{UserConfig, get_config_path};
+use worktrunk::git::WorktrunkError;
+use worktrunk::path::{format_path_for_display, to_posix_path};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{
+    eprintln, error_message, format_with_gutter, print_hint, success_message,
+};
+
+use super::create::{USER_CONFIG_EXAMPLE, comment_out_config};
+
+/// Editor used when neither `$VISUAL` nor `$EDITOR` is set
+#[cfg(windows)]
+pub(super) const DEFAULT_EDITOR: &str = "notepad";
+#[cfg(not(windows))]
+pub(super) const DEFAULT_EDITOR: &str = "vi";
+
+/// Pick the editor command: `$VISUAL`, then `$EDITOR`, then the platform default.
+pub(super) fn resolve_editor(visual: Option<String>, editor: Option<String>) -> String {
+    worktrunk::config::resolve_editor(visual, editor).unwrap_or_else(|| DEFAULT_EDITOR.to_string())
+}
+
+/// Handle the config edit command
+pub fn handle_config_edit() -> anyhow::Result<()> {
+    let path = get_config_path().context(
+        "Cannot determine config directory. Set $HOME or $XDG_CONFIG_HOME environment variable",
+    )?;
+
+    if !path.exists() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create config directory")?;
+        }
+        std::fs::write(&path, comment_out_config(USER_CONFIG_EXAMPLE))
+            .context("Failed to write config file")?;
+        eprintln!(
+            "{}",
+            success_message(cformat!(
+                "Created user config: <bold>{}</>",
+                format_path_for_display(&path)
+            ))
+        );
+    }
+
+    // The editor value may carry flags (`code --wait`), so run it through the shell
+    let editor = resolve_editor(std::env::var("VISUAL").ok(), std::env::var("EDITOR").ok());
+    let path_arg = escape(to_posix_path(&path.to_string_lossy()).into());
+    Cmd::shell(format!("{editor} {path_arg}"))
+        .stdin(Stdio::inherit())
+        .forward_signals()
+        .stream()
+        .with_context(|| format!("Editor '{editor}' failed"))?;
+
+    // Surface mistakes now rather than on the next command that loads config
+    let contents = std::fs::read_to_string(&path).context("Failed to read config file")?;
+    if let Err(e) = toml::from_str::<UserConfig>(&contents) {
+        eprintln!(
+            "{}",
+            error_message(cformat!(
+                "Invalid config: <bold>{}</>",
+                format_path_for_display(&path)
+            ))
+        );
+        eprintln!("{}", format_with_gutter(&e.to_string(), None));
+        print_hint(cformat!("To fix, run <bright-black>wt config edit</>"));
+        return Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into());
+    }
+
+    Ok(())
+}
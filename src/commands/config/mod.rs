@@ -3,14 +3,16 @@
 //! Commands for managing user config, project config, state, and hints.
 
 mod create;
+mod edit;
 mod hints;
 mod show;
 mod state;
 
 // Re-export public functions
 pub use create::handle_config_create;
+pub use edit::handle_config_edit;
 pub use hints::{handle_hints_clear, handle_hints_get};
-pub use show::handle_config_show;
+pub use show::{handle_config_show, handle_shell_status};
 pub use state::{
     handle_logs_get, handle_state_clear, handle_state_clear_all, handle_state_get,
     handle_state_set, handle_state_show,
@@ -24,6 +26,7 @@ mod tests {
     use worktrunk::config::{ProjectConfig, UserConfig};
 
     use super::create::comment_out_config;
+    use super::edit::{DEFAULT_EDITOR, resolve_editor};
     use super::show::{render_ci_tool_status, warn_unknown_keys};
     use super::state::{get_user_config_path, require_user_config_path, resolve_user_config_path};
 
@@ -117,6 +120,23 @@ mod tests {
         assert_eq!(comment_out_config(input), expected);
     }
 
+    // ==================== resolve_editor tests ====================
+
+    #[test]
+    fn test_resolve_editor_prefers_visual() {
+        assert_eq!(
+            resolve_editor(Some("code --wait".into()), Some("vim".into())),
+            "code --wait"
+        );
+    }
+
+    #[test]
+    fn test_resolve_editor_falls_back() {
+        assert_eq!(resolve_editor(None, Some("nano".into())), "nano");
+        assert_eq!(resolve_editor(Some("  ".into()), None), DEFAULT_EDITOR);
+        assert_eq!(resolve_editor(None, None), DEFAULT_EDITOR);
+    }
+
     // ==================== warn_unknown_keys tests ====================
 
     #[test]
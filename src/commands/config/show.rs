@@ -22,7 +22,7 @@ use worktrunk::styling::{
 };
 
 use super::state::require_user_config_path;
-use crate::cli::version_str;
+use crate::cli::{OutputFormat, version_str};
 use crate::commands::configure_shell::{ConfigAction, scan_shell_configs};
 use crate::commands::list::ci_status::{CiPlatform, CiToolsStatus, get_platform_for_repo};
 use crate::help_pager::show_help_in_pager;
@@ -30,7 +30,11 @@ use crate::llm::test_commit_generation;
 use crate::output;
 
 /// Handle the config show command
-pub fn handle_config_show(full: bool) -> anyhow::Result<()> {
+pub fn handle_config_show(full: bool, effective: bool, format: OutputFormat) -> anyhow::Result<()> {
+    if effective {
+        return show_effective_config(format);
+    }
+
     // Build the complete output as a string
     let mut show_output = String::new();
 
@@ -69,6 +73,212 @@ pub fn handle_config_show(full: bool) -> anyhow::Result<()> {
     Ok(())
 }
 
+// ==================== Effective Config ====================
+
+/// Where an effective config value comes from
+#[derive(Debug, Clone, Copy)]
+enum ValueSource {
+    /// Built-in default (not set anywhere)
+    Default,
+    /// User config file or `WORKTRUNK_*` environment variable
+    User,
+    /// `[projects."<id>"]` override in the user config
+    Project,
+}
+
+impl ValueSource {
+    fn as_str(self) -> &'static str {
+        match self {
+            ValueSource::Default => "default",
+            ValueSource::User => "user",
+            ValueSource::Project => "project",
+        }
+    }
+}
+
+/// Print the effective (merged) config for the current project, with value sources
+fn show_effective_config(format: OutputFormat) -> anyhow::Result<()> {
+    let config = UserConfig::load().context("Failed to load config")?;
+    let project = Repository::current()
+        .ok()
+        .and_then(|repo| repo.project_identifier().ok());
+    let entries = effective_entries(&config, project.as_deref())?;
+
+    match format {
+        OutputFormat::Json => {
+            let values: Vec<serde_json::Value> = entries
+                .iter()
+                .map(|(key, value, source)| {
+                    serde_json::json!({
+                        "key": key,
+                        "value": value,
+                        "source": source.as_str()
+                    })
+                })
+                .collect();
+            let output = serde_json::json!({
+                "project": project,
+                "values": values
+            });
+            worktrunk::styling::println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+        OutputFormat::Table | OutputFormat::ClaudeCode => {
+            let mut out = String::new();
+            if let Some(project) = &project {
+                writeln!(out, "# project: {project}")?;
+            }
+            for (key, value, source) in &entries {
+                let value = toml::Value::try_from(value)?;
+                writeln!(out, "{key} = {value}  # {}", source.as_str())?;
+            }
+            worktrunk::styling::print!("{out}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolve every user-facing setting to `(key, value, source)`, in config-file order.
+///
+/// Settings without a default (e.g. `select.pager`) are omitted when unset.
+fn effective_entries(
+    config: &UserConfig,
+    project: Option<&str>,
+) -> anyhow::Result<Vec<(&'static str, serde_json::Value, ValueSource)>> {
+    use serde_json::{Value, to_value};
+
+    let resolved = config.resolved(project);
+    let global = to_value(config)?;
+    let overrides = match project.and_then(|p| config.projects.get(p)) {
+        Some(overrides) => to_value(overrides)?,
+        None => Value::Null,
+    };
+
+    let is_set = |value: &Value, key: &str| {
+        let pointer = format!("/{}", key.replace('.', "/"));
+        value.pointer(&pointer).is_some_and(|v| !v.is_null())
+    };
+    let source = |key: &str| {
+        // The deprecated `[commit-generation]` section feeds `commit.generation`
+        let legacy = key
+            .strip_prefix("commit.generation.")
+            .map(|field| format!("commit-generation.{field}"));
+        let set_in = |value: &Value| {
+            is_set(value, key)
+                || legacy
+                    .as_deref()
+                    .is_some_and(|legacy| is_set(value, legacy))
+        };
+        if set_in(&overrides) {
+            ValueSource::Project
+        } else if set_in(&global) {
+            ValueSource::User
+        } else {
+            ValueSource::Default
+        }
+    };
+
+    let worktree_path = match project {
+        Some(project) => config.worktree_path_for_project(project),
+        None => config.worktree_path(),
+    };
+    let branch_width = match resolved.list.branch_width() {
+        usize::MAX => 0,
+        width => width,
+    };
+
+    let values: Vec<(&'static str, Option<Value>)> = vec![
+        ("worktree-path", Some(to_value(worktree_path)?)),
+        (
+            "remove-confirm",
+            Some(to_value(config.remove_confirm(project))?),
+        ),
+        (
+            "editor-command",
+            config.editor_command().map(to_value).transpose()?,
+        ),
+        (
+            "stale-base-threshold",
+            // 0 disables the check
+            Some(to_value(config.stale_base_threshold().unwrap_or(0))?),
+        ),
+        (
+            "show-integration-hint",
+            Some(to_value(config.show_integration_hint())?),
+        ),
+        ("maintenance", Some(to_value(config.maintenance)?)),
+        (
+            "copy-on-create",
+            config.copy_on_create.as_ref().map(to_value).transpose()?,
+        ),
+        (
+            "templates",
+            (!config.templates.is_empty())
+                .then(|| to_value(&config.templates))
+                .transpose()?,
+        ),
+        (
+            "profiles",
+            (!config.profiles.is_empty())
+                .then(|| to_value(&config.profiles))
+                .transpose()?,
+        ),
+        ("list.full", Some(to_value(resolved.list.full())?)),
+        ("list.branches", Some(to_value(resolved.list.branches())?)),
+        ("list.remotes", Some(to_value(resolved.list.remotes())?)),
+        (
+            "list.timeout-ms",
+            resolved.list.timeout_ms().map(to_value).transpose()?,
+        ),
+        ("list.branch-width", Some(to_value(branch_width)?)),
+        (
+            "list.columns",
+            resolved.list.columns.as_ref().map(to_value).transpose()?,
+        ),
+        (
+            "list.show-author",
+            Some(to_value(resolved.list.show_author())?),
+        ),
+        (
+            "list.path-style",
+            Some(to_value(resolved.list.path_style())?),
+        ),
+        (
+            "list.remote-names",
+            resolved.list.remote_names.map(to_value).transpose()?,
+        ),
+        (
+            "list.time-format",
+            Some(to_value(resolved.list.time_format())?),
+        ),
+        ("commit.stage", Some(to_value(resolved.commit.stage())?)),
+        (
+            "commit.generation.command",
+            resolved
+                .commit_generation
+                .command
+                .as_ref()
+                .map(to_value)
+                .transpose()?,
+        ),
+        ("merge.squash", Some(to_value(resolved.merge.squash())?)),
+        ("merge.commit", Some(to_value(resolved.merge.commit())?)),
+        ("merge.rebase", Some(to_value(resolved.merge.rebase())?)),
+        ("merge.remove", Some(to_value(resolved.merge.remove())?)),
+        ("merge.verify", Some(to_value(resolved.merge.verify())?)),
+        (
+            "select.pager",
+            resolved.select.pager().map(to_value).transpose()?,
+        ),
+        ("theme", config.theme.as_ref().map(to_value).transpose()?),
+    ];
+
+    Ok(values
+        .into_iter()
+        .filter_map(|(key, value)| value.map(|value| (key, value, source(key))))
+        .collect())
+}
+
 // ==================== Helper Functions ====================
 
 /// Check if Claude Code CLI is available
@@ -528,6 +738,14 @@ fn render_project_config(out: &mut String) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Handle the config shell status command
+pub fn handle_shell_status() -> anyhow::Result<()> {
+    let mut out = String::new();
+    render_shell_status(&mut out)?;
+    worktrunk::styling::eprint!("{out}");
+    Ok(())
+}
+
 fn render_shell_status(out: &mut String) -> anyhow::Result<()> {
     writeln!(out, "{}", format_heading("SHELL INTEGRATION", None))?;
 
@@ -601,8 +819,8 @@ fn render_shell_status(out: &mut String) -> anyhow::Result<()> {
     for result in &scan_result.configured {
         let shell = result.shell;
         let path = format_path_for_display(&result.path);
-        // Fish has separate completion file; bash/zsh have inline completions
-        let what = if matches!(shell, Shell::Fish) {
+        // Fish has separate completion file; bash/zsh have inline completions; cmd has none
+        let what = if matches!(shell, Shell::Fish | Shell::Cmd) {
             "shell extension"
         } else {
             "shell extension & completions"
@@ -852,6 +1070,34 @@ fn render_shell_status(out: &mut String) -> anyhow::Result<()> {
         }
     }
 
+    // Show integration installed for a different command prefix (e.g., `git-wt` when
+    // running as `wt`). Each prefix creates its own shell function, so only the one
+    // matching the invoked command is used.
+    for detection in &detection_results {
+        for detected in &detection.other_prefix_lines {
+            let Some(other) = &detected.prefix else {
+                continue;
+            };
+            let path = format_path_for_display(&detection.path);
+            let location = format!("{}:{}", path, detected.line_number);
+            writeln!(
+                out,
+                "{}",
+                warning_message(cformat!(
+                    "Found integration for <bold>{other}</> @ {location}; running as <bold>{cmd}</>"
+                ))
+            )?;
+            writeln!(out, "{}", format_bash_with_gutter(detected.content.trim()))?;
+            writeln!(
+                out,
+                "{}",
+                hint_message(cformat!(
+                    "Each command has its own integration; to remove this one, run <bright-black>{other} config shell uninstall</>"
+                ))
+            )?;
+        }
+    }
+
     // Check if any shell has config already (eval line present)
     let has_any_configured = scan_result
         .configured
@@ -942,6 +1188,49 @@ pub(super) fn render_ci_tool_status(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_effective_entries_cover_user_config() {
+        use strum::IntoEnumIterator;
+
+        // Optional settings without a default only show when set, so set them all
+        let config: UserConfig = toml::from_str(
+            r#"
+editor-command = "code ."
+copy-on-create = [".env"]
+templates = { flat = "../{{ branch }}" }
+profiles = { work = { maintenance = true } }
+theme = { addition = "blue" }
+"#,
+        )
+        .unwrap();
+        let entries = effective_entries(&config, None).unwrap();
+
+        // Not settings: per-project overrides, the deprecated `[commit-generation]`
+        // (shown as `commit.generation.*`), first-run prompt state, and hooks
+        let mut skipped = vec![
+            "projects".to_string(),
+            "commit-generation".to_string(),
+            "skip-shell-integration-prompt".to_string(),
+            "skip-commit-generation-prompt".to_string(),
+        ];
+        skipped.extend(worktrunk::HookType::iter().map(|hook| hook.to_string()));
+
+        let schema = schemars::SchemaGenerator::default().into_root_schema_for::<UserConfig>();
+        let props = schema
+            .get("properties")
+            .and_then(|p| p.as_object())
+            .unwrap();
+        for key in props.keys().filter(|key| !skipped.contains(key)) {
+            let prefix = format!("{key}.");
+            assert!(
+                entries
+                    .iter()
+                    .any(|(entry, _, _)| *entry == key.as_str() || entry.starts_with(&prefix)),
+                "`wt config show --effective` is missing `{key}`"
+            );
+        }
+    }
+
     #[test]
     fn test_get_git_version_returns_version() {
         // In a normal environment with git installed, should return a version
@@ -8,7 +8,7 @@ use std::path::PathBuf;
 
 use color_print::cformat;
 use etcetera::base_strategy::{BaseStrategy, choose_base_strategy};
-use worktrunk::git::Repository;
+use worktrunk::git::{ConfigScope, Repository};
 use worktrunk::path::{format_path_for_display, sanitize_for_filename};
 use worktrunk::styling::{
     eprintln, format_heading, format_with_gutter, info_message, println, success_message,
@@ -311,6 +311,7 @@ pub fn handle_state_get(key: &str, branch: Option<String>) -> anyhow::Result<()>
                 return Err(worktrunk::git::GitError::BranchNotFound {
                     branch: branch_name,
                     show_create_hint: true,
+                    suggestion: None,
                 }
                 .into());
             }
@@ -383,7 +384,7 @@ pub fn handle_state_set(key: &str, value: String, branch: Option<String>) -> any
             });
 
             let config_key = format!("worktrunk.state.{branch_name}.marker");
-            repo.run_command(&["config", &config_key, &json.to_string()])?;
+            repo.config_set(&config_key, &json.to_string(), ConfigScope::Local)?;
 
             eprintln!(
                 "{}",
@@ -413,10 +414,7 @@ pub fn handle_state_clear(key: &str, branch: Option<String>, all: bool) -> anyho
             }
         }
         "previous-branch" => {
-            if repo
-                .run_command(&["config", "--unset", "worktrunk.history"])
-                .is_ok()
-            {
+            if repo.config_unset("worktrunk.history", ConfigScope::Local)? {
                 eprintln!("{}", success_message("Cleared previous branch"));
             } else {
                 eprintln!("{}", info_message("No previous branch to clear"));
@@ -443,10 +441,7 @@ pub fn handle_state_clear(key: &str, branch: Option<String>, all: bool) -> anyho
                     None => repo.require_current_branch("clear ci-status for current branch")?,
                 };
                 let config_key = format!("worktrunk.state.{branch_name}.ci-status");
-                if repo
-                    .run_command(&["config", "--unset", &config_key])
-                    .is_ok()
-                {
+                if repo.config_unset(&config_key, ConfigScope::Local)? {
                     eprintln!(
                         "{}",
                         success_message(cformat!("Cleared CI cache for <bold>{branch_name}</>"))
@@ -461,16 +456,13 @@ pub fn handle_state_clear(key: &str, branch: Option<String>, all: bool) -> anyho
         }
         "marker" => {
             if all {
-                let output = repo
-                    .run_command(&["config", "--get-regexp", r"^worktrunk\.state\..+\.marker$"])
-                    .unwrap_or_default();
+                let markers =
+                    repo.config_get_regexp(r"^worktrunk\.state\..+\.marker$", ConfigScope::Local)?;
 
                 let mut cleared_count = 0;
-                for line in output.lines() {
-                    if let Some(config_key) = line.split_whitespace().next() {
-                        repo.run_command(&["config", "--unset", config_key])?;
-                        cleared_count += 1;
-                    }
+                for (config_key, _) in markers {
+                    repo.config_unset(&config_key, ConfigScope::Local)?;
+                    cleared_count += 1;
                 }
 
                 if cleared_count == 0 {
@@ -491,10 +483,7 @@ pub fn handle_state_clear(key: &str, branch: Option<String>, all: bool) -> anyho
                 };
 
                 let config_key = format!("worktrunk.state.{branch_name}.marker");
-                if repo
-                    .run_command(&["config", "--unset", &config_key])
-                    .is_ok()
-                {
+                if repo.config_unset(&config_key, ConfigScope::Local)? {
                     eprintln!(
                         "{}",
                         success_message(cformat!("Cleared marker for <bold>{branch_name}</>"))
@@ -542,22 +531,20 @@ pub fn handle_state_clear_all() -> anyhow::Result<()> {
     }
 
     // Clear previous branch
-    if repo
-        .run_command(&["config", "--unset", "worktrunk.history"])
-        .is_ok()
-    {
+    if matches!(
+        repo.config_unset("worktrunk.history", ConfigScope::Local),
+        Ok(true)
+    ) {
         cleared_any = true;
     }
 
     // Clear all markers
-    let markers_output = repo
-        .run_command(&["config", "--get-regexp", r"^worktrunk\.state\..+\.marker$"])
+    let markers = repo
+        .config_get_regexp(r"^worktrunk\.state\..+\.marker$", ConfigScope::Local)
         .unwrap_or_default();
-    for line in markers_output.lines() {
-        if let Some(config_key) = line.split_whitespace().next() {
-            let _ = repo.run_command(&["config", "--unset", config_key]);
-            cleared_any = true;
-        }
+    for (config_key, _) in markers {
+        let _ = repo.config_unset(&config_key, ConfigScope::Local);
+        cleared_any = true;
     }
 
     // Clear all CI status cache
@@ -810,16 +797,12 @@ pub(super) struct MarkerEntry {
 
 /// Get all branch markers from git config with timestamps
 pub(super) fn get_all_markers(repo: &Repository) -> Vec<MarkerEntry> {
-    let output = repo
-        .run_command(&["config", "--get-regexp", r"^worktrunk\.state\..+\.marker$"])
+    let entries = repo
+        .config_get_regexp(r"^worktrunk\.state\..+\.marker$", ConfigScope::Local)
         .unwrap_or_default();
 
     let mut markers = Vec::new();
-    for line in output.lines() {
-        // Format: "worktrunk.state.<branch>.marker json_value"
-        let Some((key, value)) = line.split_once(' ') else {
-            continue;
-        };
+    for (key, value) in &entries {
         let Some(branch) = key
             .strip_prefix("worktrunk.state.")
             .and_then(|s| s.strip_suffix(".marker"))
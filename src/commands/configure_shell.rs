@@ -739,8 +739,8 @@ pub fn show_uninstall_preview(
                 result.action.description(),
             );
         } else {
-            // Bash/Zsh: inline completions; Fish: separate completion file
-            let what = if matches!(shell, Shell::Fish) {
+            // Bash/Zsh: inline completions; Fish: separate completion file; cmd: none
+            let what = if matches!(shell, Shell::Fish | Shell::Cmd) {
                 "shell extension"
             } else {
                 "shell extension & completions"
@@ -933,8 +933,14 @@ fn scan_for_uninstall(
     dry_run: bool,
     cmd: &str,
 ) -> Result<UninstallScanResult, String> {
-    // For uninstall, always include PowerShell to clean up any existing profiles
-    let default_shells = vec![Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell];
+    // For uninstall, always include PowerShell and cmd to clean up any existing profiles
+    let default_shells = vec![
+        Shell::Bash,
+        Shell::Zsh,
+        Shell::Fish,
+        Shell::PowerShell,
+        Shell::Cmd,
+    ];
 
     let shells = shell_filter.map_or(default_shells, |shell| vec![shell]);
 
@@ -0,0 +1,77 @@
+//! `wt diff` — summarize how much a worktree has changed versus a ref.
+
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository, short_sha};
+use worktrunk::styling::{eprintln, info_message, println};
+
+use super::list::columns::ColumnKind;
+
+/// Print the line-change summary of a worktree versus the default branch.
+///
+/// Defaults to the current worktree. Compares the working tree, including
+/// uncommitted changes, against the fork point (merge-base with `vs`, or the
+/// default branch) and prints `+added -deleted` to stdout, without the diff
+/// itself, so commits that landed on the target since don't count. The fork
+/// point goes to stderr once the worktree has diverged.
+pub fn handle_diff(branch: Option<&str>, vs: Option<&str>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+
+    let (worktree, label) = match branch {
+        Some(name) => {
+            let branch = repo.resolve_worktree_name(name)?;
+            let Some(path) = repo.worktree_for_branch(&branch)? else {
+                return Err(GitError::WorktreeNotFound { branch }.into());
+            };
+            (repo.worktree_at(path), branch)
+        }
+        None => {
+            let worktree = repo.current_worktree();
+            let label = worktree.branch()?.unwrap_or_else(|| "HEAD".to_string());
+            (worktree, label)
+        }
+    };
+
+    let target = match vs {
+        Some(target) => {
+            if !repo.ref_exists(target)? {
+                return Err(GitError::ReferenceNotFound {
+                    reference: target.to_string(),
+                }
+                .into());
+            }
+            target.to_string()
+        }
+        None => repo.require_default_branch()?,
+    };
+
+    let head = worktree.run_command(&["rev-parse", "HEAD"])?;
+    let head = head.trim();
+    let fork_point = repo.merge_base(&target, head)?;
+
+    // Only worth showing once the worktree has commits of its own
+    if let Some(fork_point) = fork_point.as_deref()
+        && fork_point != head
+    {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "<bold>{label}</> forked from <bold>{target}</> at <dim>{}</>",
+                short_sha(fork_point)
+            ))
+        );
+    }
+
+    // Unrelated histories have no fork point; compare against the target itself
+    let diff = worktree.working_tree_diff_vs_ref(fork_point.as_deref().unwrap_or(&target))?;
+    match ColumnKind::WorkingDiff.format_diff_plain(diff.added, diff.deleted) {
+        Some(summary) => println!("{summary}"),
+        None => eprintln!(
+            "{}",
+            info_message(cformat!(
+                "No changes on <bold>{label}</> versus <bold>{target}</>"
+            ))
+        ),
+    }
+
+    Ok(())
+}
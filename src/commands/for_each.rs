@@ -31,7 +31,7 @@ use worktrunk::git::Repository;
 use worktrunk::git::WorktrunkError;
 use worktrunk::shell_exec::ShellConfig;
 use worktrunk::styling::{
-    eprintln, error_message, format_with_gutter, progress_message, success_message, warning_message,
+    eprintln, error_message, format_with_gutter, print_progress, success_message, warning_message,
 };
 
 use crate::commands::command_executor::{CommandContext, build_hook_context};
@@ -61,10 +61,7 @@ pub fn step_for_each(args: Vec<String>) -> anyhow::Result<()> {
 
     for wt in &worktrees {
         let display_name = worktree_display_name(wt, &repo, &config);
-        eprintln!(
-            "{}",
-            progress_message(format!("Running in {display_name}..."))
-        );
+        print_progress(format!("Running in {display_name}..."));
 
         // Build full hook context for this worktree
         // Pass wt.branch directly (not the display string) so detached HEAD maps to None -> "HEAD"
@@ -1,34 +1,70 @@
 //! Switch command handler.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, IsTerminal, Read};
 use std::path::Path;
 
+use ansi_str::AnsiStr;
 use anyhow::Context;
+use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::{UserConfig, expand_template};
 use worktrunk::git::Repository;
-use worktrunk::styling::{eprintln, info_message};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    eprintln, error_message, info_message, print_hint, success_message, suggest_command,
+    warning_message,
+};
 
 use super::command_approval::approve_hooks;
 use super::command_executor::{CommandContext, build_hook_context};
-use super::worktree::{SwitchPlan, SwitchResult, execute_switch, plan_switch};
+use super::worktree::{
+    CreationMethod, SwitchPlan, SwitchPlanOptions, SwitchResult, execute_switch, paths_match,
+    plan_switch,
+};
 use crate::output::{
-    execute_user_command, handle_switch_output, is_shell_integration_active,
-    prompt_shell_integration,
+    change_directory_to_worktree, execute_user_command, handle_switch_output,
+    is_shell_integration_active, prompt_shell_integration,
 };
 
 /// Options for the switch command
 pub struct SwitchOptions<'a> {
     pub branch: &'a str,
     pub create: bool,
+    /// With `create`, switch to an existing worktree for the branch instead of failing (`--reuse`)
+    pub reuse: bool,
+    /// Create a detached-HEAD worktree at `branch` (a commit-ish)
+    pub detach: bool,
     pub base: Option<&'a str>,
+    /// Use the remote's default branch (e.g. `origin/main`) as the base
+    pub base_from_remote_default: bool,
+    /// Fetch from the primary remote before creating (`--fetch`)
+    pub fetch: bool,
+    /// Remote whose same-named branch becomes the upstream (`--track`)
+    pub track: Option<&'a str>,
     pub execute: Option<&'a str>,
     pub execute_args: &'a [String],
+    /// Run `editor-command` in the worktree after switching (`--open`)
+    pub open: bool,
     pub yes: bool,
     pub clobber: bool,
+    /// Named `[templates]` entry to use instead of `worktree-path`
+    pub template: Option<&'a str>,
+    /// Directory name for the new worktree, replacing the template's (`--name`)
+    pub name: Option<&'a str>,
+    /// Copy env files into a newly created worktree (`--copy-env`)
+    pub copy_env: bool,
     /// Whether to change directory after switching (default: true)
     pub change_dir: bool,
+    /// Emit only the cd directive, with no messages (`--cd-only`)
+    pub cd_only: bool,
+    /// Stash the current worktree's changes before switching (`--stash`)
+    pub stash: bool,
     pub verify: bool,
+    /// Output format: `Json` prints the result to stdout for automation
+    pub format: crate::OutputFormat,
+    /// Show the planned path and base, then exit without switching (`--dry-run`)
+    pub dry_run: bool,
 }
 
 /// Approve switch hooks upfront and show "Commands declined" if needed.
@@ -96,17 +132,41 @@ pub(crate) fn switch_extra_vars(result: &SwitchResult) -> Vec<(&str, &str)> {
     }
 }
 
+/// Build the `--format json` object for a switch result.
+///
+/// Derived from the same data as the human-readable success message.
+/// `created` is true only when a new worktree was created.
+pub(crate) fn switch_result_json(result: &SwitchResult, branch: &str) -> serde_json::Value {
+    let (created, base, from_remote) = match result {
+        SwitchResult::Created {
+            base_branch,
+            from_remote,
+            ..
+        } => (true, base_branch.as_deref(), from_remote.as_deref()),
+        SwitchResult::Existing { .. } | SwitchResult::AlreadyAt(_) => (false, None, None),
+    };
+    // Detached worktrees have no branch (`branch` holds the commit-ish)
+    let branch = (!result.is_detached()).then_some(branch);
+    serde_json::json!({
+        "path": result.path(),
+        "branch": branch,
+        "created": created,
+        "base": base,
+        "from_remote": from_remote,
+    })
+}
+
 /// Spawn post-switch (and post-start for creates) background hooks.
 pub(crate) fn spawn_switch_background_hooks(
     repo: &Repository,
     config: &UserConfig,
     result: &SwitchResult,
-    branch: &str,
+    branch: Option<&str>,
     yes: bool,
     extra_vars: &[(&str, &str)],
     hooks_display_path: Option<&Path>,
 ) -> anyhow::Result<()> {
-    let ctx = CommandContext::new(repo, config, Some(branch), result.path(), yes);
+    let ctx = CommandContext::new(repo, config, branch, result.path(), yes);
 
     let mut hooks = super::hooks::prepare_background_hooks(
         &ctx,
@@ -125,6 +185,346 @@ pub(crate) fn spawn_switch_background_hooks(
     super::hooks::spawn_background_hooks(&ctx, hooks)
 }
 
+/// Warn when a new branch's base is more than `threshold` commits behind its upstream.
+///
+/// Compares against the remote-tracking ref as last fetched, so the count is a
+/// lower bound unless `--fetch` ran first. Best-effort: a base without an
+/// upstream, or a failed count, stays silent.
+fn warn_if_stale_base(repo: &Repository, plan: &SwitchPlan, threshold: usize) {
+    let SwitchPlan::Create {
+        branch,
+        method:
+            CreationMethod::Regular {
+                create_branch: true,
+                base_branch: Some(base),
+            },
+        ..
+    } = plan
+    else {
+        return;
+    };
+    let Ok(Some(upstream)) = repo.branch(base).upstream() else {
+        return;
+    };
+    let Ok((_, behind)) = repo.ahead_behind(&upstream, base) else {
+        return;
+    };
+    if behind <= threshold {
+        return;
+    }
+
+    let commits = if behind == 1 { "commit" } else { "commits" };
+    eprintln!(
+        "{}",
+        warning_message(cformat!(
+            "Base <bold>{base}</> is {behind} {commits} behind <bold>{upstream}</>"
+        ))
+    );
+    let switch_cmd = suggest_command(
+        "switch",
+        &[branch.as_str()],
+        &["--create", "--base", upstream.as_str()],
+    );
+    print_hint(cformat!(
+        "To branch from the remote instead, run <bright-black>{switch_cmd}</>"
+    ));
+}
+
+/// Set `<remote>/<branch>` as the upstream of a branch created with `--track`.
+///
+/// Returns the upstream when it was set. If the remote branch doesn't exist yet,
+/// warns and leaves the branch untracked.
+fn track_remote_branch(
+    repo: &Repository,
+    branch: &str,
+    remote: &str,
+) -> anyhow::Result<Option<String>> {
+    let upstream = format!("{remote}/{branch}");
+    if !repo.branch(branch).remotes()?.iter().any(|r| r == remote) {
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Remote branch <bold>{upstream}</> doesn't exist yet; upstream not set"
+            ))
+        );
+        print_hint(cformat!(
+            "To push and track, run <bright-black>git push -u {remote} {branch}</>"
+        ));
+        return Ok(None);
+    }
+
+    repo.set_upstream(branch, &upstream)?;
+    Ok(Some(upstream))
+}
+
+/// Stash the current worktree's changes for `--stash`, recording the stash
+/// so switching back restores it.
+///
+/// Returns the branch and stash commit when something was stashed, so a failed
+/// switch can hand the changes back (see [`unstash_current_worktree`]).
+fn stash_current_worktree(repo: &Repository) -> anyhow::Result<Option<(String, String)>> {
+    let worktree = repo.current_worktree();
+    let Some(branch) = worktree.branch()? else {
+        anyhow::bail!("Cannot use --stash from a detached worktree; commit or stash manually");
+    };
+    if repo.branch_stash(&branch).is_some() {
+        anyhow::bail!(
+            "{branch} already has changes stashed by --stash; switch back to restore them first"
+        );
+    }
+    let message = format!("worktrunk: {branch}");
+    let Some(sha) = repo.stash_push(&worktree.root()?, &message)? else {
+        eprintln!("{}", info_message("No changes to stash"));
+        return Ok(None);
+    };
+    repo.set_branch_stash(&branch, Some(&sha))?;
+    eprintln!(
+        "{}",
+        success_message(cformat!("Stashed changes in <bold>{branch}</>"))
+    );
+    Ok(Some((branch, sha)))
+}
+
+/// Put back changes stashed by `--stash` when the switch failed.
+///
+/// Best-effort, like [`restore_worktree_stash`]: the switch error is what gets
+/// reported, so a failed pop only warns and keeps the stash recorded.
+fn unstash_current_worktree(repo: &Repository, branch: &str, sha: &str) {
+    let restored = repo
+        .current_worktree()
+        .root()
+        .and_then(|root| repo.stash_pop(&root, sha));
+    match restored {
+        Ok(_) => {
+            let _ = repo.set_branch_stash(branch, None);
+        }
+        Err(e) => {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Failed to restore stashed changes in <bold>{branch}</>: {e}"
+                ))
+            );
+            print_hint(cformat!(
+                "To restore manually, run <bright-black>git stash apply {sha}</>"
+            ));
+        }
+    }
+}
+
+/// Restore changes stashed by `--stash` when returning to a worktree.
+///
+/// Best-effort: the switch already succeeded, so failures only warn and keep the
+/// stash recorded for a later attempt.
+fn restore_worktree_stash(repo: &Repository, branch: &str, path: &Path) {
+    let Some(sha) = repo.branch_stash(branch) else {
+        return;
+    };
+    match repo.stash_pop(path, &sha) {
+        Ok(true) => {
+            let _ = repo.set_branch_stash(branch, None);
+            eprintln!(
+                "{}",
+                success_message(cformat!("Restored stashed changes in <bold>{branch}</>"))
+            );
+        }
+        Ok(false) => {
+            // Stash was dropped outside worktrunk; nothing left to restore
+            let _ = repo.set_branch_stash(branch, None);
+        }
+        Err(e) => {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Failed to restore stashed changes in <bold>{branch}</>: {e}"
+                ))
+            );
+            print_hint(cformat!(
+                "To restore manually, run <bright-black>git stash apply {sha}</>"
+            ));
+        }
+    }
+}
+
+/// Read the branch for `wt switch --from-stdin`.
+///
+/// Takes the first line of stdin, trimmed, so a picker's selection can be
+/// piped in (`... | fzf | wt switch --from-stdin`).
+pub fn read_branch_from_stdin() -> anyhow::Result<String> {
+    let stdin = std::io::stdin();
+    if stdin.is_terminal() {
+        anyhow::bail!("--from-stdin expects a branch name piped on stdin");
+    }
+    let mut line = String::new();
+    stdin
+        .lock()
+        .read_line(&mut line)
+        .context("Failed to read branch from stdin")?;
+    let branch = line.trim();
+    if branch.is_empty() {
+        anyhow::bail!("No branch name on stdin");
+    }
+    Ok(branch.to_string())
+}
+
+/// One entry of the `wt switch --json-stdin` manifest.
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ManifestEntry {
+    branch: String,
+    /// Defaults to the default branch
+    #[serde(default)]
+    base: Option<String>,
+}
+
+/// Handle `wt switch --json-stdin`: create a worktree per manifest entry.
+///
+/// Each entry gets a new branch, as with `--create`. Failures are reported and
+/// skipped so one bad entry doesn't abort the batch; the JSON summary on stdout
+/// lists every entry, and the command fails afterwards if any entry did. Hooks
+/// don't run and the shell doesn't change directory.
+pub fn handle_switch_manifest(config: &UserConfig) -> anyhow::Result<()> {
+    let stdin = std::io::stdin();
+    if stdin.is_terminal() {
+        anyhow::bail!("--json-stdin expects a JSON manifest piped on stdin");
+    }
+    let mut input = String::new();
+    stdin
+        .lock()
+        .read_to_string(&mut input)
+        .context("Failed to read manifest from stdin")?;
+    let entries: Vec<ManifestEntry> =
+        serde_json::from_str(&input).context("Failed to parse manifest from stdin")?;
+
+    // Later entries for a branch already listed fail without touching the repo,
+    // rather than with whatever git reports for the existing branch
+    let mut seen = HashSet::new();
+    let duplicates: Vec<bool> = entries
+        .iter()
+        .map(|entry| !seen.insert(entry.branch.as_str()))
+        .collect();
+
+    let repo = Repository::current()?;
+    let mut results = Vec::with_capacity(entries.len());
+    let mut failed = 0;
+    for (entry, duplicate) in entries.iter().zip(duplicates) {
+        let branch = entry.branch.as_str();
+        if duplicate {
+            failed += 1;
+            eprintln!(
+                "{}",
+                error_message(cformat!(
+                    "Failed to create <bold>{branch}</>: duplicate in manifest"
+                ))
+            );
+            results.push(serde_json::json!({
+                "branch": branch,
+                "created": false,
+                "error": "duplicate in manifest",
+            }));
+            continue;
+        }
+        let created = plan_switch(
+            &repo,
+            SwitchPlanOptions {
+                branch,
+                create: true,
+                base: entry.base.as_deref(),
+                ..Default::default()
+            },
+            config,
+        )
+        .and_then(|plan| execute_switch(&repo, plan, config, false, true, false));
+
+        match created {
+            Ok((result, _)) => {
+                let path_display = format_path_for_display(result.path());
+                eprintln!(
+                    "{}",
+                    success_message(cformat!(
+                        "Created worktree for <bold>{branch}</> @ <bold>{path_display}</>"
+                    ))
+                );
+                results.push(switch_result_json(&result, branch));
+            }
+            Err(e) => {
+                failed += 1;
+                eprintln!(
+                    "{}",
+                    error_message(cformat!("Failed to create <bold>{branch}</>: {e:#}"))
+                );
+                results.push(serde_json::json!({
+                    "branch": branch,
+                    "created": false,
+                    "error": format!("{e:#}").ansi_strip(),
+                }));
+            }
+        }
+    }
+
+    let summary = serde_json::json!({
+        "created": entries.len() - failed,
+        "failed": failed,
+        "results": results,
+    });
+    let json = serde_json::to_string_pretty(&summary).context("Failed to serialize to JSON")?;
+    println!("{json}");
+
+    if failed > 0 {
+        anyhow::bail!("{failed} of {} worktrees failed to create", entries.len());
+    }
+    Ok(())
+}
+
+/// Report what `wt switch --dry-run` would do, without touching the repo.
+///
+/// The path goes to stdout (or the JSON object with `--format=json`) so it can
+/// be captured; no `cd` directive is emitted.
+fn show_dry_run(plan: &SwitchPlan, format: crate::OutputFormat) -> anyhow::Result<()> {
+    let branch = plan.branch();
+    let path = plan.worktree_path();
+    let path_display = format_path_for_display(path);
+    let base = match plan {
+        SwitchPlan::Create {
+            method: CreationMethod::Regular { base_branch, .. },
+            ..
+        } => base_branch.as_deref(),
+        _ => None,
+    };
+
+    let message = match (plan, base) {
+        (SwitchPlan::Existing { .. }, _) => {
+            cformat!("Would switch to worktree for <bold>{branch}</> @ <bold>{path_display}</>")
+        }
+        (SwitchPlan::Create { .. }, Some(base)) => cformat!(
+            "Would create worktree for <bold>{branch}</> from <bold>{base}</> @ <bold>{path_display}</>"
+        ),
+        (SwitchPlan::Create { .. }, None) => {
+            cformat!("Would create worktree for <bold>{branch}</> @ <bold>{path_display}</>")
+        }
+    };
+    eprintln!("{}", info_message(message));
+
+    match format {
+        crate::OutputFormat::Json => {
+            let json = serde_json::json!({
+                "path": path,
+                "branch": branch,
+                "created": plan.is_create(),
+                "base": base,
+                "dry_run": true,
+            });
+            let json =
+                serde_json::to_string_pretty(&json).context("Failed to serialize to JSON")?;
+            println!("{json}");
+        }
+        crate::OutputFormat::Table | crate::OutputFormat::ClaudeCode => {
+            println!("{}", path.display())
+        }
+    }
+    Ok(())
+}
+
 /// Handle the switch command.
 pub fn handle_switch(
     opts: SwitchOptions<'_>,
@@ -134,45 +534,207 @@ pub fn handle_switch(
     let SwitchOptions {
         branch,
         create,
+        reuse,
+        detach,
         base,
+        base_from_remote_default,
+        fetch,
+        track,
         execute,
         execute_args,
+        open,
         yes,
         clobber,
+        template,
+        name,
+        copy_env,
         change_dir,
+        cd_only,
+        stash,
         verify,
+        format,
+        dry_run,
     } = opts;
 
     let repo = Repository::current().context("Failed to switch worktree")?;
 
+    if cd_only {
+        // The cd directive is the only output, so there must be somewhere to write it
+        if !is_shell_integration_active() {
+            anyhow::bail!("--cd-only needs shell integration to receive the cd directive");
+        }
+        // Silences progress, hints and hook announcements; warnings still show
+        worktrunk::styling::set_quiet(true);
+    }
+
+    if let Some(remote) = track
+        && repo.remote_url(remote).is_none()
+    {
+        anyhow::bail!("No remote named '{remote}'; check 'git remote -v'");
+    }
+
+    // Resolve before switching, so a missing editor doesn't leave a half-finished switch
+    let editor_command = if open {
+        Some(config.editor_command().ok_or_else(|| {
+            anyhow::anyhow!(
+                "--open needs an editor; set editor-command in user config, $VISUAL or $EDITOR"
+            )
+        })?)
+    } else {
+        None
+    };
+
+    // Refresh remote-tracking refs before resolving the base.
+    // A failed fetch (offline, auth) falls back to the refs we already have.
+    if fetch && let Err(e) = repo.fetch() {
+        eprintln!("{}", warning_message(format!("{e:#}")));
+    }
+
+    // --base-from-remote-default: branch from e.g. origin/main, not a possibly stale main
+    let remote_default_base = if base_from_remote_default {
+        let remote_base = repo.remote_default_branch_ref();
+        if remote_base.is_none() {
+            eprintln!(
+                "{}",
+                warning_message(
+                    "Remote default branch unknown; basing on the local default branch"
+                )
+            );
+            if let Ok(remote) = repo.primary_remote() {
+                print_hint(cformat!(
+                    "To record it, run <bright-black>git remote set-head {remote} --auto</>"
+                ));
+            }
+        }
+        remote_base
+    } else {
+        None
+    };
+    let base = base.or(remote_default_base.as_deref());
+
+    // --reuse: a branch that already has a worktree is switched to, not created
+    let reusing = create
+        && reuse
+        && repo
+            .worktree_for_branch(&repo.resolve_worktree_name(branch)?)?
+            .is_some();
+    let (create, base) = if reusing {
+        (false, None)
+    } else {
+        (create, base)
+    };
+
     // Validate FIRST (before approval) - fails fast if branch doesn't exist, etc.
-    let plan = plan_switch(&repo, branch, create, base, clobber, config)?;
+    let plan = plan_switch(
+        &repo,
+        SwitchPlanOptions {
+            branch,
+            create,
+            detach,
+            base,
+            clobber,
+            template,
+            name,
+        },
+        config,
+    )?;
+
+    if let Some(threshold) = config.stale_base_threshold() {
+        warn_if_stale_base(&repo, &plan, threshold);
+    }
+
+    if dry_run {
+        return show_dry_run(&plan, format);
+    }
 
     // "Approve at the Gate": collect and approve hooks upfront
     // This ensures approval happens once at the command entry point
     // If user declines, skip hooks but continue with worktree operation
     let skip_hooks = !approve_switch_hooks(&repo, config, &plan, yes, verify)?;
 
+    // Stash before executing, so hooks run after it and a failed switch can hand the
+    // changes back. Staying in the current worktree (from its root or any
+    // subdirectory) leaves nothing to stash for.
+    let cwd = std::env::current_dir().context("Failed to get current directory")?;
+    let source_root = repo.current_worktree().root()?;
+    let already_at = matches!(
+        &plan,
+        SwitchPlan::Existing { path, .. } if paths_match(&source_root, path)
+    );
+    let stashed = if stash && !already_at {
+        stash_current_worktree(&repo)?
+    } else {
+        None
+    };
+
     // Execute the validated plan
-    let (result, branch_info) = execute_switch(&repo, plan, config, yes, skip_hooks)?;
+    let (mut result, branch_info) =
+        match execute_switch(&repo, plan, config, yes, skip_hooks, copy_env) {
+            Ok(executed) => executed,
+            Err(e) => {
+                if let Some((branch, sha)) = &stashed {
+                    unstash_current_worktree(&repo, branch, sha);
+                }
+                return Err(e);
+            }
+        };
+
+    if let Some(remote) = track {
+        if let SwitchResult::Created {
+            created_branch: true,
+            from_remote,
+            ..
+        } = &mut result
+        {
+            *from_remote = track_remote_branch(&repo, &branch_info.branch, remote)?;
+        } else {
+            // `--reuse` switched to an existing worktree; its branch keeps its upstream
+            let branch = &branch_info.branch;
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "<bold>{branch}</> already has a worktree; --track ignored"
+                ))
+            );
+            print_hint(cformat!(
+                "To track anyway, run <bright-black>git branch --set-upstream-to={remote}/{branch} {branch}</>"
+            ));
+        }
+    }
 
     // Show success message (temporal locality: immediately after worktree operation)
     // Returns path to display in hooks when user's shell won't be in the worktree
     // Also shows worktree-path hint on first --create (before shell integration warning)
-    let cwd = std::env::current_dir().context("Failed to get current directory")?;
-    let source_root = repo.current_worktree().root()?;
-    let hooks_display_path =
-        handle_switch_output(&result, &branch_info, change_dir, Some(&source_root), &cwd)?;
+    let hooks_display_path = if cd_only {
+        change_directory_to_worktree(result.path(), Some(&source_root), &cwd)?;
+        None
+    } else {
+        handle_switch_output(&result, &branch_info, change_dir, Some(&source_root), &cwd)?
+    };
+
+    if let SwitchResult::Existing { path } = &result {
+        restore_worktree_stash(&repo, &branch_info.branch, path);
+    }
+
+    // Structured result on stdout (human messages above went to stderr)
+    if matches!(format, crate::OutputFormat::Json) {
+        let json = serde_json::to_string_pretty(&switch_result_json(&result, &branch_info.branch))
+            .context("Failed to serialize to JSON")?;
+        println!("{json}");
+    }
 
     // Offer shell integration if not already installed/active
     // (only shows prompt/hint when shell integration isn't working)
     // With --execute: show hints only (don't interrupt with prompt)
     // Best-effort: don't fail switch if offer fails
     if !is_shell_integration_active() {
-        let skip_prompt = execute.is_some();
+        let skip_prompt = execute.is_some() || open;
         let _ = prompt_shell_integration(config, binary_name, skip_prompt);
     }
 
+    // Detached worktrees have no branch; hooks and --execute see `HEAD`
+    let hook_branch = (!result.is_detached()).then_some(branch_info.branch.as_str());
+
     // Build extra vars for base branch context (used by both hooks and --execute)
     // "base" is the branch we branched from when creating a new worktree.
     // For existing worktrees, there's no base concept.
@@ -187,7 +749,7 @@ pub fn handle_switch(
             &repo,
             config,
             &result,
-            &branch_info.branch,
+            hook_branch,
             yes,
             &extra_vars,
             hooks_display_path.as_deref(),
@@ -196,38 +758,62 @@ pub fn handle_switch(
 
     // Execute user command after post-start hooks have been spawned
     // Note: execute_args requires execute via clap's `requires` attribute
-    if let Some(cmd) = execute {
+    if execute.is_some() || open {
         // Build template context for expansion (includes base vars when creating)
-        let ctx = CommandContext::new(&repo, config, Some(&branch_info.branch), result.path(), yes);
+        let ctx = CommandContext::new(&repo, config, hook_branch, result.path(), yes);
         let template_vars = build_hook_context(&ctx, &extra_vars);
         let vars: HashMap<&str, &str> = template_vars
             .iter()
             .map(|(k, v)| (k.as_str(), v.as_str()))
             .collect();
 
-        // Expand template variables in command (shell_escape: true for safety)
-        let expanded_cmd = expand_template(cmd, &vars, true, &repo, "--execute command")
-            .map_err(|e| anyhow::anyhow!("Failed to expand --execute template: {}", e))?;
+        let mut commands = Vec::new();
+        let mut flags = Vec::new();
 
-        // Append any trailing args (after --) to the execute command
-        // Each arg is also expanded, then shell-escaped
-        let full_cmd = if execute_args.is_empty() {
-            expanded_cmd
-        } else {
-            let expanded_args: Result<Vec<_>, _> = execute_args
-                .iter()
-                .map(|arg| {
-                    expand_template(arg, &vars, false, &repo, "--execute argument")
-                        .map_err(|e| anyhow::anyhow!("Failed to expand argument template: {}", e))
-                })
-                .collect();
-            let escaped_args: Vec<_> = expanded_args?
-                .iter()
-                .map(|arg| shlex::try_quote(arg).unwrap_or(arg.into()).into_owned())
-                .collect();
-            format!("{} {}", expanded_cmd, escaped_args.join(" "))
-        };
-        execute_user_command(&full_cmd, hooks_display_path.as_deref())?;
+        if let Some(cmd) = execute {
+            // Expand template variables in command (shell_escape: true for safety)
+            let expanded_cmd = expand_template(cmd, &vars, true, &repo, "--execute command")
+                .map_err(|e| anyhow::anyhow!("Failed to expand --execute template: {}", e))?;
+
+            // Append any trailing args (after --) to the execute command
+            // Each arg is also expanded, then shell-escaped
+            let full_cmd = if execute_args.is_empty() {
+                expanded_cmd
+            } else {
+                let expanded_args: Result<Vec<_>, _> = execute_args
+                    .iter()
+                    .map(|arg| {
+                        expand_template(arg, &vars, false, &repo, "--execute argument").map_err(
+                            |e| anyhow::anyhow!("Failed to expand argument template: {}", e),
+                        )
+                    })
+                    .collect();
+                let escaped_args: Vec<_> = expanded_args?
+                    .iter()
+                    .map(|arg| shlex::try_quote(arg).unwrap_or(arg.into()).into_owned())
+                    .collect();
+                format!("{} {}", expanded_cmd, escaped_args.join(" "))
+            };
+            commands.push(full_cmd);
+            flags.push("--execute");
+        }
+
+        // The editor runs last, after any --execute command
+        if let Some(editor_command) = &editor_command {
+            let editor_cmd = expand_template(editor_command, &vars, true, &repo, "editor-command")
+                .map_err(|e| anyhow::anyhow!("Failed to expand editor-command: {}", e))?;
+            commands.push(editor_cmd);
+            flags.push("--open");
+        }
+
+        // One shell command: without shell integration the command replaces the wt
+        // process, so a second command would never run
+        execute_user_command(
+            &commands.join("; "),
+            &flags.join(", "),
+            hooks_display_path.as_deref(),
+            &ctx.worktree_env(),
+        )?;
     }
 
     Ok(())
@@ -6,7 +6,8 @@ use worktrunk::config::CommandConfig;
 use worktrunk::git::WorktrunkError;
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{
-    eprintln, error_message, format_bash_with_gutter, progress_message, verbosity, warning_message,
+    eprintln, error_message, format_bash_with_gutter, is_quiet, print_progress, progress_message,
+    verbosity, warning_message,
 };
 
 use super::command_executor::{CommandContext, PreparedCommand, prepare_commands};
@@ -56,8 +57,10 @@ impl SourcedCommand {
             }
             None => format!("{full_label}:"),
         };
-        eprintln!("{}", progress_message(message));
-        eprintln!("{}", format_bash_with_gutter(&self.prepared.expanded));
+        if !is_quiet() {
+            eprintln!("{}", progress_message(message));
+            eprintln!("{}", format_bash_with_gutter(&self.prepared.expanded));
+        }
         Ok(())
     }
 }
@@ -189,12 +192,13 @@ pub fn spawn_background_hooks(
             }
             None => format!("Running {}", type_segments.join("; ")),
         };
-        eprintln!("{}", progress_message(message));
+        print_progress(message);
     }
 
     // Track index for unnamed commands to prevent log collisions (per hook type)
     // Use a Vec since HookType doesn't implement Hash
     let mut unnamed_indices: Vec<(HookType, usize)> = Vec::new();
+    let env = ctx.worktree_env();
 
     for cmd in &commands {
         if verbose >= 1 {
@@ -228,6 +232,7 @@ pub fn spawn_background_hooks(
             ctx.branch_or_head(),
             &hook_log,
             Some(&cmd.prepared.context_json),
+            &env,
         ) {
             let err_msg = err.to_string();
             let message = match &cmd.prepared.name {
@@ -301,6 +306,34 @@ pub(crate) fn check_name_filter_matched(
     Ok(())
 }
 
+/// Environment variables describing the merge, exported to post-merge hooks.
+///
+/// `WORKTRUNK_MERGED_BRANCH` is the branch that was merged, `WORKTRUNK_TARGET_BRANCH`
+/// the branch it landed on, and `WORKTRUNK_SQUASHED` is `true` when the branch's
+/// commits were squashed into one.
+fn post_merge_env(
+    ctx: &CommandContext,
+    extra_vars: &[(&str, &str)],
+) -> Vec<(&'static str, String)> {
+    let var = |key: &str| {
+        extra_vars
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.to_string())
+    };
+    vec![
+        ("WORKTRUNK_MERGED_BRANCH", ctx.branch_or_head().to_string()),
+        (
+            "WORKTRUNK_TARGET_BRANCH",
+            var("target").unwrap_or_else(|| ctx.branch_or_head().to_string()),
+        ),
+        (
+            "WORKTRUNK_SQUASHED",
+            var("squashed").unwrap_or_else(|| "false".to_string()),
+        ),
+    ]
+}
+
 /// Run user and project hooks for a given hook type.
 ///
 /// This is the canonical implementation for running hooks from both sources.
@@ -338,6 +371,10 @@ pub fn run_hook_with_filter(
 
     // Track first failure's exit code for Warn strategy (to propagate after all commands run)
     let mut first_failure_exit_code: Option<i32> = None;
+    let mut env = ctx.worktree_env();
+    if hook_type == HookType::PostMerge {
+        env.extend(post_merge_env(ctx, extra_vars));
+    }
 
     for cmd in commands {
         cmd.announce()?;
@@ -346,6 +383,7 @@ pub fn run_hook_with_filter(
             ctx.worktree_path,
             &cmd.prepared.expanded,
             Some(&cmd.prepared.context_json),
+            &env,
         ) {
             // Extract raw message and exit code from error
             let (err_msg, exit_code) = if let Some(wt_err) = err.downcast_ref::<WorktrunkError>() {
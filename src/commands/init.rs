@@ -1,10 +1,11 @@
+use anyhow::Context;
 use clap::CommandFactory;
 use clap_complete::generate;
 use std::io;
 use worktrunk::shell;
 use worktrunk::styling::println;
 
-use crate::cli::Cli;
+use crate::cli::{Cli, OutputFormat};
 
 pub fn handle_init(shell: shell::Shell, cmd: String) -> Result<(), String> {
     let init = shell::ShellInit::with_prefix(shell, cmd);
@@ -19,6 +20,52 @@ pub fn handle_init(shell: shell::Shell, cmd: String) -> Result<(), String> {
     Ok(())
 }
 
+/// Print the files `wt config shell install` would write for a shell, without writing.
+///
+/// This is the handler for `wt config shell init --print-path`. The shell defaults to
+/// the one in `$SHELL`. Table output prints one path per line: the config file
+/// candidates in order of preference, then the completions file (fish only, since
+/// other shells get completions from the init script). JSON output names each value.
+pub fn handle_init_print_path(
+    shell: Option<shell::Shell>,
+    cmd: &str,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    let shell = shell
+        .or_else(shell::current_shell)
+        .context("Could not detect shell from $SHELL; pass one, e.g. `zsh`")?;
+
+    let config_paths = shell
+        .config_paths(cmd)
+        .with_context(|| format!("Failed to get config paths for {shell}"))?;
+    let completion_path = match shell {
+        shell::Shell::Fish => Some(
+            shell
+                .completion_path(cmd)
+                .with_context(|| format!("Failed to get completion path for {shell}"))?,
+        ),
+        _ => None,
+    };
+
+    match format {
+        OutputFormat::Json => {
+            let output = serde_json::json!({
+                "shell": shell.to_string(),
+                "config_paths": config_paths,
+                "completion_path": completion_path,
+            });
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+        OutputFormat::Table | OutputFormat::ClaudeCode => {
+            for path in config_paths.iter().chain(&completion_path) {
+                println!("{}", path.display());
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Generate static shell completions to stdout.
 ///
 /// This is the handler for `wt config shell completions <shell>`. It outputs completion
@@ -68,6 +115,9 @@ pub fn handle_completions(shell: shell::Shell) -> anyhow::Result<()> {
                 &mut stdout,
             );
         }
+        shell::Shell::Cmd => {
+            anyhow::bail!("cmd has no completion support");
+        }
     }
 
     Ok(())
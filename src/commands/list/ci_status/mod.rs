@@ -13,6 +13,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use worktrunk::git::Repository;
 use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::theme;
 use worktrunk::utils::get_now;
 
 /// A parsed branch name for CI status detection.
@@ -276,8 +277,14 @@ impl CiStatus {
 
 impl PrStatus {
     /// Get the style for this PR status (color + optional dimming for stale)
+    ///
+    /// Passed and failed use the `ci-passed`/`ci-failed` theme colors.
     pub fn style(&self) -> Style {
-        let style = Style::new().fg_color(Some(Color::Ansi(self.ci_status.color())));
+        let style = match self.ci_status {
+            CiStatus::Passed => theme().ci_passed,
+            CiStatus::Failed => theme().ci_failed,
+            status => Style::new().fg_color(Some(Color::Ansi(status.color()))),
+        };
         if self.is_stale { style.dimmed() } else { style }
     }
 
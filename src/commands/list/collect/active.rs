@@ -0,0 +1,102 @@
+//! Detection of worktrees that have a running process inside them (`wt list --active-only`).
+//!
+//! A worktree is active when some process's working directory is the worktree or a
+//! directory beneath it. Processes are found via `/proc/<pid>/cwd` on Linux and
+//! `lsof` on other Unix systems; other platforms report an error.
+//!
+//! Only processes visible to the current user count. `wt` itself and its parent
+//! (usually the shell `wt list` was run from) are ignored, since they'd otherwise
+//! mark the current worktree active.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use dunce::canonicalize;
+
+/// Paths (as given) of the worktrees containing a process's working directory.
+///
+/// Each process is attributed to the deepest matching worktree, so a process in a
+/// nested worktree (e.g. `.worktrees/feature`) doesn't mark its parent active too.
+pub(super) fn active_worktree_paths<'a>(
+    worktree_paths: impl IntoIterator<Item = &'a Path>,
+) -> anyhow::Result<HashSet<PathBuf>> {
+    // `/proc` and `lsof` report resolved paths, so compare against canonical ones
+    let worktrees: Vec<(&Path, PathBuf)> = worktree_paths
+        .into_iter()
+        .map(|path| {
+            (
+                path,
+                canonicalize(path).unwrap_or_else(|_| path.to_path_buf()),
+            )
+        })
+        .collect();
+
+    let mut active = HashSet::new();
+    for cwd in process_cwds()? {
+        let deepest = worktrees
+            .iter()
+            .filter(|(_, canonical)| cwd.starts_with(canonical))
+            .max_by_key(|(_, canonical)| canonical.components().count());
+        if let Some((path, _)) = deepest {
+            active.insert(path.to_path_buf());
+        }
+    }
+    Ok(active)
+}
+
+/// Process IDs of `wt` and its parent, which don't count as activity.
+#[cfg(unix)]
+fn ignored_pids() -> [u32; 2] {
+    [std::process::id(), std::os::unix::process::parent_id()]
+}
+
+/// Working directories of all readable processes other than this one and its parent.
+#[cfg(target_os = "linux")]
+fn process_cwds() -> anyhow::Result<Vec<PathBuf>> {
+    use anyhow::Context;
+
+    let ignored = ignored_pids();
+    let entries = std::fs::read_dir("/proc").context("Failed to read /proc")?;
+    Ok(entries
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str()?.parse::<u32>().ok())
+        .filter(|pid| !ignored.contains(pid))
+        // Other users' processes (and ones that exited meanwhile) aren't readable
+        .filter_map(|pid| std::fs::read_link(format!("/proc/{pid}/cwd")).ok())
+        .collect())
+}
+
+/// Working directories of all readable processes other than this one and its parent.
+///
+/// `lsof -d cwd -F pn` prints a `p<pid>` line followed by an `n<path>` line per
+/// process. It exits non-zero when some processes can't be inspected, so the
+/// output is used regardless of status.
+#[cfg(all(unix, not(target_os = "linux")))]
+fn process_cwds() -> anyhow::Result<Vec<PathBuf>> {
+    use anyhow::Context;
+
+    let ignored = ignored_pids();
+    let output = worktrunk::shell_exec::Cmd::new("lsof")
+        .args(["-w", "-d", "cwd", "-F", "pn"])
+        .run()
+        .context("Failed to run lsof")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let mut cwds = Vec::new();
+    let mut is_ignored = false;
+    for line in stdout.lines() {
+        if let Some(pid) = line.strip_prefix('p') {
+            is_ignored = pid.parse().is_ok_and(|pid: u32| ignored.contains(&pid));
+        } else if let Some(path) = line.strip_prefix('n')
+            && !is_ignored
+        {
+            cwds.push(PathBuf::from(path));
+        }
+    }
+    Ok(cwds)
+}
+
+#[cfg(not(unix))]
+fn process_cwds() -> anyhow::Result<Vec<PathBuf>> {
+    anyhow::bail!("--active-only is not supported on this platform")
+}
@@ -91,6 +91,7 @@
 //! (CI status, URL health checks) run last. This ensures the table fills in quickly with local
 //! data while slower network requests complete in the background.
 
+mod active;
 mod execution;
 mod results;
 mod tasks;
@@ -105,14 +106,16 @@ use crossbeam_channel as chan;
 use dunce::canonicalize;
 use once_cell::sync::OnceCell;
 use rayon::prelude::*;
+use unicode_width::UnicodeWidthStr;
 use worktrunk::git::{Repository, WorktreeInfo};
+use worktrunk::path::same_path;
 use worktrunk::styling::{
-    INFO_SYMBOL, eprintln, format_with_gutter, hint_message, warning_message,
+    INFO_SYMBOL, eprintln, format_with_gutter, info_message, is_quiet, print_hint, warning_message,
 };
 
 use crate::commands::is_worktree_at_expected_path;
 
-use super::model::{DisplayFields, ItemKind, ListItem, WorktreeData};
+use super::model::{CommitDetails, DisplayFields, ItemKind, ListItem, StatusGroup, WorktreeData};
 
 // Re-exports for sibling modules (columns.rs, render.rs, layout.rs)
 pub(crate) use tasks::parse_port_from_url;
@@ -185,6 +188,48 @@ pub struct CollectOptions {
     pub stale_branches: std::collections::HashSet<String>,
 }
 
+/// Default cap on concurrent enrichment tasks.
+///
+/// Each task spawns git processes; beyond ~8 at once they mostly contend for
+/// disk I/O rather than finishing sooner.
+const DEFAULT_MAX_PARALLELISM: usize = 8;
+
+/// Number of threads for the enrichment pool, or `None` for the global pool.
+///
+/// `WORKTRUNK_MAX_PARALLELISM=N` overrides the default of `min(cores, 8)`;
+/// `0` removes the cap.
+fn max_parallelism() -> Option<usize> {
+    match std::env::var("WORKTRUNK_MAX_PARALLELISM")
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+    {
+        Some(0) => None,
+        Some(n) => Some(n),
+        None => Some(
+            std::thread::available_parallelism().map_or(DEFAULT_MAX_PARALLELISM, |n| {
+                n.get().min(DEFAULT_MAX_PARALLELISM)
+            }),
+        ),
+    }
+}
+
+/// Run `f` on a thread pool bounded by [`max_parallelism`].
+///
+/// Falls back to the global pool when uncapped or if the pool can't be built.
+fn with_enrichment_pool(f: impl FnOnce() + Send) {
+    let pool = max_parallelism().and_then(|n| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(n)
+            .thread_name(|i| format!("wt-list-{i}"))
+            .build()
+            .ok()
+    });
+    match pool {
+        Some(pool) => pool.install(f),
+        None => f(),
+    }
+}
+
 fn worktree_branch_set(worktrees: &[WorktreeInfo]) -> std::collections::HashSet<&str> {
     worktrees
         .iter()
@@ -192,34 +237,133 @@ fn worktree_branch_set(worktrees: &[WorktreeInfo]) -> std::collections::HashSet<
         .collect()
 }
 
-/// Collect worktree data with optional progressive rendering.
-///
-/// When `show_progress` is true, renders a skeleton immediately and updates as data arrives.
-/// When false, behavior depends on `render_table`:
-/// - If `render_table` is true: renders final table (buffered mode)
-/// - If `render_table` is false: returns data without rendering (JSON mode)
+/// Branch count above which ref enumeration gets noticeably slow on cold caches.
+const MANY_REFS_THRESHOLD: usize = 10_000;
+
+/// Hint name recording that the pack-refs suggestion was shown in this repo.
+const HINT_PACK_REFS: &str = "pack-refs";
+
+/// Suggest packing refs once per repo when `--branches` enumerates a very large ref set.
+fn hint_pack_refs_if_many(repo: &Repository, branch_count: usize) {
+    // Under --quiet the hint isn't printed, so don't record it as shown either
+    if branch_count <= MANY_REFS_THRESHOLD || is_quiet() || repo.has_shown_hint(HINT_PACK_REFS) {
+        return;
+    }
+    print_hint(cformat!(
+        "This repo has {branch_count} branches; to speed up listing, run <bright-black>git pack-refs --all</> or <bright-black>git gc</>"
+    ));
+    let _ = repo.mark_hint_shown(HINT_PACK_REFS);
+}
+
+/// Point a bare repo with no worktrees yet at `wt switch` to create the first one.
 ///
-/// The `command_timeout` parameter, if set, limits how long individual git commands can run.
-/// This is useful for `wt select` to show the TUI faster by skipping slow operations.
+/// `git clone --bare` leaves the default branch without a worktree; a freshly
+/// initialized bare repo has no branches at all, so that case needs `--create`.
+fn hint_first_worktree_in_bare_repo(repo: &Repository) {
+    let branch = repo.default_branch().unwrap_or_else(|| "main".to_string());
+    let command = if repo.branch_exists(&branch, false).unwrap_or(false) {
+        format!("wt switch {branch}")
+    } else {
+        format!("wt switch -c {branch}")
+    };
+    eprintln!("{}", info_message("No worktrees yet"));
+    print_hint(cformat!(
+        "To create the first one, run <bright-black>{command}</>"
+    ));
+}
+
+/// What a single [`collect`] call gathers and how it renders.
 ///
-/// TODO: Now that we skip expensive tasks for stale branches (see `skip_expensive_for_stale`),
-/// the timeout may be unnecessary. Consider removing it if it doesn't provide value.
+/// Built by the callers (`wt list`, `wt select`) from CLI flags and config.
+/// Task-level settings derived during collection live in [`CollectOptions`].
+pub struct CollectArgs<'a> {
+    /// Include branches without worktrees (`--branches`)
+    pub show_branches: bool,
+    /// Include worktrees; false lists branches only
+    pub show_worktrees: bool,
+    /// Include remote branches (`--remotes`)
+    pub show_remotes: bool,
+    /// Tasks not to compute (see [`CollectOptions::skip_tasks`])
+    pub skip_tasks: &'a std::collections::HashSet<TaskKind>,
+    /// Render a skeleton immediately and update rows as data arrives.
+    ///
+    /// When false, `render_table` decides between a buffered table and
+    /// returning data without rendering (JSON).
+    pub show_progress: bool,
+    /// Render the table at all (false for JSON and `wt select`)
+    pub render_table: bool,
+    /// Limit on how long individual git commands can run.
+    ///
+    /// Lets `wt select` show the TUI faster by skipping slow operations.
+    ///
+    /// TODO: Now that we skip expensive tasks for stale branches (see
+    /// `skip_expensive_for_stale`), the timeout may be unnecessary. Consider
+    /// removing it if it doesn't provide value.
+    pub command_timeout: Option<std::time::Duration>,
+    /// Batch-fetch ahead/behind counts and skip expensive merge-base operations
+    /// for branches far behind the default branch.
+    ///
+    /// This dramatically improves performance for repos with many stale branches.
+    pub skip_expensive_for_stale: bool,
+    /// Hide items whose last commit is older than this (`--since`)
+    pub since: Option<std::time::Duration>,
+    /// Mark upstreams not fetched within this long (`--since-fetch`)
+    pub since_fetch: Option<std::time::Duration>,
+    /// Only worktrees whose path matches (`--path-filter`)
+    pub path_filter: Option<&'a glob::Pattern>,
+    /// Only worktrees with running processes (`--active-only`)
+    pub active_only: bool,
+    /// Only rows with conflicts (`--conflicts-only`)
+    pub conflicts_only: bool,
+    pub group_by: crate::cli::ListGroupBy,
+    pub sort: crate::cli::ListSort,
+    /// Path display override; `None` uses config
+    pub path_style: Option<worktrunk::config::PathStyle>,
+    /// Show remote names in the branch column
+    pub remote_names: bool,
+    /// Table width cap; `None` uses the terminal width
+    pub max_width: Option<usize>,
+    /// ASCII-only rows with single-space separators (`wt list --plain`); no
+    /// column is hidden for lack of width.
+    pub plain: bool,
+    /// A GitHub-flavored markdown table, rendered like `plain` but without the
+    /// summary line (`wt list --format=markdown`).
+    pub markdown: bool,
+    /// Always count changes, conflicts and ahead in the summary line (`--stats`)
+    pub stats: bool,
+}
+
+/// Collect worktree data with optional progressive rendering.
 ///
-/// The `skip_expensive_for_stale` parameter enables batch-fetching ahead/behind counts and
-/// skipping expensive merge-base operations for branches far behind the default branch.
-/// This dramatically improves performance for repos with many stale branches.
-#[allow(clippy::too_many_arguments)]
+/// See [`CollectArgs`] for what gets collected and how it's rendered.
 pub fn collect(
     repo: &Repository,
-    show_branches: bool,
-    show_remotes: bool,
-    skip_tasks: &std::collections::HashSet<TaskKind>,
-    show_progress: bool,
-    render_table: bool,
     config: &worktrunk::config::UserConfig,
-    command_timeout: Option<std::time::Duration>,
-    skip_expensive_for_stale: bool,
+    args: CollectArgs<'_>,
 ) -> anyhow::Result<Option<super::model::ListData>> {
+    let CollectArgs {
+        show_branches,
+        show_worktrees,
+        show_remotes,
+        skip_tasks,
+        show_progress,
+        render_table,
+        command_timeout,
+        skip_expensive_for_stale,
+        since,
+        since_fetch,
+        path_filter,
+        active_only,
+        conflicts_only,
+        group_by,
+        sort,
+        path_style,
+        remote_names,
+        max_width,
+        plain,
+        markdown,
+        stats,
+    } = args;
     use super::progressive_table::ProgressiveTable;
     worktrunk::shell_exec::trace_instant("List collect started");
 
@@ -269,11 +413,21 @@ pub fn collect(
     });
 
     // Extract results
-    let worktrees = worktrees_cell
+    let mut worktrees = worktrees_cell
         .into_inner()
         .unwrap()
         .context("Failed to list worktrees")?;
+    // Git doesn't flag locked worktrees as prunable, so a directory deleted out-of-band
+    // would otherwise reach the per-worktree tasks and fail them. Treat it as prunable.
+    for wt in &mut worktrees {
+        if wt.prunable.is_none() && !wt.bare && !wt.path.exists() {
+            wt.prunable = Some("directory missing".to_string());
+        }
+    }
     if worktrees.is_empty() {
+        if repo.is_bare() {
+            hint_first_worktree_in_bare_repo(repo);
+        }
         return Ok(None);
     }
     let default_branch = default_branch_cell.into_inner().unwrap();
@@ -282,6 +436,7 @@ pub fn collect(
     // Filter local branches to those without worktrees (CPU-only, no git commands)
     let branches_without_worktrees = if show_branches {
         let all_local = local_branches_cell.into_inner().unwrap()?;
+        hint_pack_refs_if_many(repo, all_local.len());
         let worktree_branches = worktree_branch_set(&worktrees);
         all_local
             .into_iter()
@@ -299,11 +454,13 @@ pub fn collect(
     // Detect current worktree using git rev-parse --show-toplevel (via WorkingTree::root).
     // This correctly handles worktrees placed inside other worktrees (e.g., .worktrees/ layout)
     // by letting git resolve the actual worktree root rather than using prefix matching.
-    // Canonicalize both paths to handle symlinks (e.g., macOS /var -> /private/var).
+    // `same_path` canonicalizes both paths to handle symlinks (e.g., macOS /var ->
+    // /private/var) and ignores case on case-insensitive filesystems, where the cwd
+    // may be spelled differently from git's worktree list.
     let current_worktree_path = repo.current_worktree().root().ok().and_then(|root| {
         worktrees
             .iter()
-            .find(|wt| canonicalize(&wt.path).map(|p| p == root).unwrap_or(false))
+            .find(|wt| same_path(&wt.path, &root))
             .map(|wt| wt.path.clone())
     });
     // Show warning if user configured a default branch that doesn't exist locally
@@ -312,7 +469,7 @@ pub fn collect(
             cformat!("Configured default branch <bold>{configured}</> does not exist locally");
         eprintln!("{}", warning_message(msg));
         let hint = cformat!("To reset, run <bright-black>wt config state default-branch clear</>");
-        eprintln!("{}", hint_message(hint));
+        print_hint(hint);
     }
 
     // Main worktree is the primary worktree (for sorting and is_main display).
@@ -322,7 +479,7 @@ pub fn collect(
     let primary_path = repo.primary_worktree()?;
     let main_worktree = primary_path
         .as_ref()
-        .and_then(|p| worktrees.iter().find(|wt| wt.path == *p))
+        .and_then(|p| worktrees.iter().find(|wt| same_path(&wt.path, p)))
         .or_else(|| worktrees.iter().find(|wt| !wt.is_prunable()))
         .cloned()
         .ok_or_else(|| anyhow::anyhow!("No worktrees found"))?;
@@ -330,7 +487,9 @@ pub fn collect(
     // Defer previous_branch lookup until after skeleton - set is_previous later
     // (skeleton shows placeholder gutter, actual symbols appear when data loads)
 
-    // Phase 3: Batch fetch timestamps (needs all SHAs from worktrees + branches)
+    // Phase 3: Batch fetch commit metadata (needs all SHAs from worktrees + branches).
+    // One `git show` covers every row's timestamp, subject, and author, replacing
+    // two git commands per item in CommitDetailsTask.
     let all_shas: Vec<&str> = worktrees
         .iter()
         .map(|wt| wt.head.as_str())
@@ -341,44 +500,93 @@ pub fn collect(
         )
         .chain(remote_branches.iter().map(|(_, sha)| sha.as_str()))
         .collect();
-    let timestamps = repo.commit_timestamps(&all_shas).unwrap_or_default();
-
-    // Sort worktrees: current first, main second, then by timestamp descending
-    let sorted_worktrees = sort_worktrees_with_cache(
-        worktrees.clone(),
-        &main_worktree,
-        current_worktree_path.as_ref(),
-        &timestamps,
-    );
+    let commit_metadata = repo.commit_metadata(&all_shas).unwrap_or_default();
+    let timestamps: std::collections::HashMap<String, i64> = commit_metadata
+        .iter()
+        .map(|(sha, meta)| (sha.clone(), meta.timestamp))
+        .collect();
+
+    // Sort worktrees: current first, main second, then by timestamp descending.
+    // With --no-worktrees, drop them here so items, layout, and work items only
+    // see branch rows.
+    let mut sorted_worktrees = if show_worktrees {
+        sort_worktrees_with_cache(
+            worktrees.clone(),
+            &main_worktree,
+            current_worktree_path.as_ref(),
+            &timestamps,
+        )
+    } else {
+        Vec::new()
+    };
 
     // Sort branches by timestamp (most recent first)
-    let branches_without_worktrees =
+    let mut branches_without_worktrees =
         sort_by_timestamp_desc_with_cache(branches_without_worktrees, &timestamps, |(_, sha)| {
             sha.as_str()
         });
-    let remote_branches =
+    let mut remote_branches =
         sort_by_timestamp_desc_with_cache(remote_branches, &timestamps, |(_, sha)| sha.as_str());
 
-    // Pre-canonicalize main_worktree.path for is_main comparison
-    // (paths from git worktree list may differ based on symlinks or working directory)
-    let main_worktree_canonical = canonicalize(&main_worktree.path).ok();
+    // --sort paths: an order that doesn't depend on commit times, for reproducible output
+    if sort == crate::cli::ListSort::Paths {
+        sorted_worktrees.sort_by(|a, b| a.path.cmp(&b.path));
+        branches_without_worktrees.sort_by(|(a, _), (b, _)| a.cmp(b));
+        remote_branches.sort_by(|(a, _), (b, _)| a.cmp(b));
+    }
+
+    // --since: drop stale items before layout so widths fit the visible rows.
+    // The primary and current worktrees always stay; unknown timestamps are kept.
+    if let Some(since) = since {
+        let cutoff = worktrunk::utils::get_now().saturating_sub(since.as_secs()) as i64;
+        let is_recent = |sha: &str| timestamps.get(sha).is_none_or(|&ts| ts >= cutoff);
+        sorted_worktrees.retain(|wt| {
+            same_path(&wt.path, &main_worktree.path)
+                || current_worktree_path
+                    .as_ref()
+                    .is_some_and(|cp| same_path(&wt.path, cp))
+                || is_recent(&wt.head)
+        });
+        branches_without_worktrees.retain(|(_, sha)| is_recent(sha));
+        remote_branches.retain(|(_, sha)| is_recent(sha));
+    }
+
+    // --path-filter: same, keeping worktrees whose path matches the glob. The
+    // canonical path is tried too, since git may report a symlinked location.
+    // Branch rows have no path, so they never match.
+    if let Some(pattern) = path_filter {
+        sorted_worktrees.retain(|wt| {
+            pattern.matches_path(&wt.path)
+                || canonicalize(&wt.path).is_ok_and(|path| pattern.matches_path(&path))
+        });
+        branches_without_worktrees.clear();
+        remote_branches.clear();
+    }
+
+    // --active-only: keep worktrees with a running process inside them. All
+    // worktrees are passed so a process in a nested worktree isn't credited to
+    // its parent.
+    let active_paths = if active_only {
+        let active = active::active_worktree_paths(worktrees.iter().map(|wt| wt.path.as_path()))?;
+        sorted_worktrees.retain(|wt| active.contains(&wt.path));
+        branches_without_worktrees.clear();
+        remote_branches.clear();
+        Some(active)
+    } else {
+        None
+    };
 
     // URL template already fetched in parallel join (layout needs to know if column is needed)
     // Initialize worktree items with identity fields and None for computed fields
     let mut all_items: Vec<ListItem> = sorted_worktrees
         .iter()
         .map(|wt| {
-            // Canonicalize paths for comparison - git worktree list may return different
-            // path representations depending on symlinks or which directory you run from
-            let wt_canonical = canonicalize(&wt.path).ok();
-            let is_main = match (&wt_canonical, &main_worktree_canonical) {
-                (Some(wt_c), Some(main_c)) => wt_c == main_c,
-                // Fallback to direct comparison if canonicalization fails
-                _ => wt.path == main_worktree.path,
-            };
+            // git worktree list may return different path representations depending
+            // on symlinks or which directory you run from
+            let is_main = same_path(&wt.path, &main_worktree.path);
             let is_current = current_worktree_path
                 .as_ref()
-                .is_some_and(|cp| wt_canonical.as_ref() == Some(cp));
+                .is_some_and(|cp| same_path(&wt.path, cp));
             // is_previous set to false initially - computed after skeleton
             let is_previous = false;
 
@@ -388,6 +596,9 @@ pub fn collect(
             let mut worktree_data =
                 WorktreeData::from_worktree(wt, is_main, is_current, is_previous);
             worktree_data.branch_worktree_mismatch = branch_worktree_mismatch;
+            worktree_data.active = active_paths
+                .as_ref()
+                .map(|active| active.contains(&wt.path));
 
             // URL expanded post-skeleton to minimize time-to-skeleton
             ListItem {
@@ -427,6 +638,17 @@ pub fn collect(
             .map(|(name, sha)| ListItem::new_branch(sha.clone(), name.clone())),
     );
 
+    // Fill commit details from the batch; per-item tasks only cover misses
+    for item in &mut all_items {
+        item.commit = commit_metadata.get(&item.head).map(|meta| CommitDetails {
+            timestamp: meta.timestamp,
+            commit_message: meta.subject.clone(),
+            author_name: meta.author_name.clone(),
+            author_email: meta.author_email.clone(),
+        });
+    }
+    let commits_prefetched = all_items.iter().all(|item| item.commit.is_some());
+
     // If no URL template configured, add UrlStatus to skip_tasks
     let mut effective_skip_tasks = skip_tasks.clone();
     if url_template.is_none() {
@@ -434,22 +656,66 @@ pub fn collect(
     }
 
     // Calculate layout from items (worktrees, local branches, and remote branches)
-    let layout = super::layout::calculate_layout_from_basics(
+    let mut list_config = config
+        .list(repo.project_identifier().ok().as_deref())
+        .unwrap_or_default();
+    if path_style.is_some() {
+        list_config.path_style = path_style;
+    }
+    if remote_names {
+        list_config.remote_names = Some(true);
+    }
+
+    // Remote names in the Upstream column: opt-in, or automatic when tracking
+    // could point at more than one remote. Width covers the longest name.
+    let remotes: Vec<String> = repo
+        .all_remote_urls()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    let upstream_remote_width = if list_config.remote_names.unwrap_or(remotes.len() > 1) {
+        remotes.iter().map(|name| name.width()).max().unwrap_or(0)
+    } else {
+        0
+    };
+
+    // Single-line invariant: use safe width to prevent line wrapping.
+    // Plain and markdown rows aren't aligned, so they get room for every column instead.
+    let max_width = if plain || markdown {
+        super::layout::PLAIN_LAYOUT_WIDTH
+    } else {
+        max_width
+            .map(|width| width.max(super::layout::MIN_MAX_WIDTH))
+            .unwrap_or_else(crate::display::get_terminal_width)
+    };
+
+    let mut layout = super::layout::calculate_layout_with_width(
         &all_items,
         &effective_skip_tasks,
+        max_width,
         &main_worktree.path,
         url_template.as_deref(),
+        &list_config,
+        upstream_remote_width,
+        since_fetch,
     );
+    if plain {
+        layout.use_plain_rendering();
+    }
+    if markdown {
+        layout.use_markdown_rendering();
+    }
 
-    // Single-line invariant: use safe width to prevent line wrapping
-    let max_width = crate::display::get_terminal_width();
-
-    // Create collection options from skip set
+    // Create collection options from skip set. CommitDetails is added after the
+    // layout, which would otherwise drop the columns it fills.
     let mut options = CollectOptions {
         skip_tasks: effective_skip_tasks,
         url_template: url_template.clone(),
         ..Default::default()
     };
+    if commits_prefetched {
+        options.skip_tasks.insert(TaskKind::CommitDetails);
+    }
 
     // Track expected results per item - populated as spawns are queued
     let expected_results = std::sync::Arc::new(ExpectedResults::default());
@@ -640,8 +906,19 @@ pub fn collect(
             Vec::new()
         };
 
+    // With --branches, prime the ahead/behind cache with one `for-each-ref` instead of
+    // a `rev-list` per branch. Runs on the worker thread so it doesn't delay the
+    // skeleton; AheadBehindTask reads the cache. Already done above for stale detection.
+    let batch_ahead_behind_base = default_branch
+        .clone()
+        .filter(|_| show_branches && !skip_expensive_for_stale);
+
     worktrunk::shell_exec::trace_instant("Spawning worker thread");
     std::thread::spawn(move || {
+        if let Some(base) = batch_ahead_behind_base {
+            repo_clone.batch_ahead_behind(&base);
+        }
+
         // Phase 1: Generate all work items (sequential, fast)
         // Work items are collected upfront so we can process them all in a single par_iter.
         let mut all_work_items = Vec::new();
@@ -674,12 +951,15 @@ pub fn collect(
         // Sort work items: network tasks last to avoid blocking local operations
         all_work_items.sort_by_key(|item| item.kind.is_network());
 
-        // Phase 2: Execute all work items in parallel
+        // Phase 2: Execute all work items in parallel, on a bounded pool so many
+        // branches don't spawn one git process per core at once
         worktrunk::shell_exec::trace_instant("Parallel execution started");
-        all_work_items.into_par_iter().for_each(|item| {
-            worktrunk::shell_exec::set_command_timeout(command_timeout);
-            let result = item.execute();
-            let _ = tx_worker.send(result);
+        with_enrichment_pool(|| {
+            all_work_items.into_par_iter().for_each(|item| {
+                worktrunk::shell_exec::set_command_timeout(command_timeout);
+                let result = item.execute();
+                let _ = tx_worker.send(result);
+            });
         });
     });
 
@@ -783,7 +1063,7 @@ pub fn collect(
         eprintln!("{}", warning_message(&diag));
 
         // Show issue reporting hint (free function - doesn't collect diagnostic data)
-        eprintln!("{}", hint_message(crate::diagnostic::issue_hint()));
+        print_hint(crate::diagnostic::issue_hint());
     }
 
     // Compute status symbols for prunable worktrees (skipped during task spawning).
@@ -801,6 +1081,33 @@ pub fn collect(
         }
     }
 
+    // --conflicts-only: keep rows that conflict now or would conflict with the default branch
+    if conflicts_only {
+        let keep: Vec<bool> = all_items
+            .iter()
+            .map(|item| {
+                item.status_symbols
+                    .as_ref()
+                    .is_some_and(|symbols| symbols.group() == StatusGroup::Conflicts)
+            })
+            .collect();
+        // Errors index into all_items: drop those for hidden rows, remap the rest
+        let new_index: Vec<usize> = keep
+            .iter()
+            .scan(0, |next, &kept| {
+                let idx = *next;
+                *next += usize::from(kept);
+                Some(idx)
+            })
+            .collect();
+        errors.retain(|error| keep[error.item_idx]);
+        for error in &mut errors {
+            error.item_idx = new_index[error.item_idx];
+        }
+        let mut kept = keep.into_iter();
+        all_items.retain(|_| kept.next().unwrap_or(false));
+    }
+
     // Count errors for summary
     let error_count = errors.len();
     let timed_out_count = errors.iter().filter(|e| e.is_timeout()).count();
@@ -812,6 +1119,7 @@ pub fn collect(
             &all_items,
             show_branches || show_remotes,
             layout.hidden_column_count,
+            stats,
             error_count,
             timed_out_count,
         );
@@ -826,29 +1134,27 @@ pub fn collect(
         } else {
             // Non-TTY: output to stdout (same as buffered mode)
             // Progressive skeleton was suppressed; now output the final table
-            println!("{}", layout.format_header_line());
-            for item in &all_items {
-                println!("{}", layout.format_list_item_line(item));
-            }
+            print_table_rows(&layout, &all_items, group_by);
             println!();
-            println!("{}", final_msg);
+            println!("{}", layout.format_summary_line(&final_msg));
         }
+    } else if render_table && markdown {
+        // Just the table, for pasting elsewhere
+        print_table_rows(&layout, &all_items, group_by);
     } else if render_table {
         // Buffered mode: render final table
         let final_msg = super::format_summary_message(
             &all_items,
             show_branches || show_remotes,
             layout.hidden_column_count,
+            stats,
             error_count,
             timed_out_count,
         );
 
-        println!("{}", layout.format_header_line());
-        for item in &all_items {
-            println!("{}", layout.format_list_item_line(item));
-        }
+        print_table_rows(&layout, &all_items, group_by);
         println!();
-        println!("{}", final_msg);
+        println!("{}", layout.format_summary_line(&final_msg));
     }
 
     // Status symbols are now computed during data collection (both modes), no fallback needed
@@ -890,7 +1196,7 @@ pub fn collect(
         eprintln!("{}", warning_message(&warning));
 
         // Show issue reporting hint (free function - doesn't collect diagnostic data)
-        eprintln!("{}", hint_message(crate::diagnostic::issue_hint()));
+        print_hint(crate::diagnostic::issue_hint());
     }
 
     // Populate display fields for all items (used by JSON output and statusline)
@@ -914,6 +1220,60 @@ pub fn collect(
     }))
 }
 
+/// Print the column header and item rows, under a bold header per group when grouping.
+///
+/// The layout is computed once over all items, so columns line up across groups.
+/// Markdown can't put a heading inside a table, so each group gets its own table.
+fn print_table_rows(
+    layout: &super::layout::LayoutConfig,
+    items: &[ListItem],
+    group_by: crate::cli::ListGroupBy,
+) {
+    if group_by == crate::cli::ListGroupBy::None {
+        println!("{}", layout.format_header_line());
+        for item in items {
+            println!("{}", layout.format_list_item_line(item));
+        }
+        return;
+    }
+
+    // Stable sort keeps the existing order within each group
+    let mut grouped: Vec<_> = items
+        .iter()
+        .map(|item| {
+            let group = item
+                .status_symbols
+                .as_ref()
+                .map_or(StatusGroup::Clean, |symbols| symbols.group());
+            (group, item)
+        })
+        .collect();
+    grouped.sort_by_key(|(group, _)| *group);
+
+    if !layout.markdown {
+        println!("{}", layout.format_header_line());
+    }
+    let mut current = None;
+    for (group, item) in grouped {
+        if current != Some(group) {
+            if current.is_some() {
+                println!();
+            }
+            if layout.markdown {
+                println!("**{}**", group.label());
+                println!();
+                println!("{}", layout.format_header_line());
+            } else if layout.plain {
+                println!("{}", group.label());
+            } else {
+                println!("{}", cformat!("<bold>{}</>", group.label()));
+            }
+            current = Some(group);
+        }
+        println!("{}", layout.format_list_item_line(item));
+    }
+}
+
 // ============================================================================
 // Sorting Helpers
 // ============================================================================
@@ -104,7 +104,7 @@ pub trait Task: Send + Sync + 'static {
 // Task Implementations
 // ============================================================================
 
-/// Task 1: Commit details (timestamp, message)
+/// Task 1: Commit details (timestamp, message, author)
 pub struct CommitDetailsTask;
 
 impl Task for CommitDetailsTask {
@@ -112,14 +112,16 @@ impl Task for CommitDetailsTask {
 
     fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
         let repo = &ctx.repo;
-        let (timestamp, commit_message) = repo
+        let details = repo
             .commit_details(&ctx.branch_ref.commit_sha)
             .map_err(|e| ctx.error(Self::KIND, &e))?;
         Ok(TaskResult::CommitDetails {
             item_idx: ctx.item_idx,
             commit: CommitDetails {
-                timestamp,
-                commit_message,
+                timestamp: details.timestamp,
+                commit_message: details.subject,
+                author_name: details.author_name,
+                author_email: details.author_email,
             },
         })
     }
@@ -355,7 +357,7 @@ impl Task for BranchDiffTask {
 
 /// Task 5 (worktree only): Working tree diff + status flags
 ///
-/// Runs `git status --porcelain` to get working tree status and computes diff stats.
+/// Runs `git status --porcelain=v2` to get working tree status and computes diff stats.
 pub struct WorkingTreeDiffTask;
 
 impl Task for WorkingTreeDiffTask {
@@ -370,8 +372,9 @@ impl Task for WorkingTreeDiffTask {
 
         // Use --no-optional-locks to avoid index lock contention with WorkingTreeConflictsTask's
         // `git stash create` which needs the index lock.
+        // Porcelain v2 reports submodule state per entry (v1 shows it as a plain `M`)
         let status_output = wt
-            .run_command(&["--no-optional-locks", "status", "--porcelain"])
+            .run_command(&["--no-optional-locks", "status", "--porcelain=v2"])
             .map_err(|e| ctx.error(Self::KIND, &e))?;
 
         let (working_tree_status, is_dirty, has_conflicts) =
@@ -503,7 +506,7 @@ impl Task for WorkingTreeConflictsTask {
     }
 }
 
-/// Task 7 (worktree only): Git operation state detection (rebase/merge)
+/// Task 7 (worktree only): Git operation state detection (rebase/merge/cherry-pick/bisect)
 pub struct GitOperationTask;
 
 impl Task for GitOperationTask {
@@ -579,6 +582,7 @@ impl Task for UpstreamTask {
                 remote,
                 ahead,
                 behind,
+                fetched_at: repo.remote_ref_fetched_at(&upstream_branch),
             },
         })
     }
@@ -659,7 +663,7 @@ impl Task for UrlStatusTask {
 // Helper Functions
 // ============================================================================
 
-/// Detect if a worktree is in the middle of a git operation (rebase/merge).
+/// Detect if a worktree is in the middle of a git operation (rebase/merge/cherry-pick/bisect).
 pub(crate) fn detect_active_git_operation(
     wt: &worktrunk::git::WorkingTree<'_>,
 ) -> ActiveGitOperation {
@@ -667,6 +671,10 @@ pub(crate) fn detect_active_git_operation(
         ActiveGitOperation::Rebase
     } else if wt.is_merging().unwrap_or(false) {
         ActiveGitOperation::Merge
+    } else if wt.is_cherry_picking().unwrap_or(false) {
+        ActiveGitOperation::CherryPick
+    } else if wt.is_bisecting().unwrap_or(false) {
+        ActiveGitOperation::Bisect
     } else {
         ActiveGitOperation::None
     }
@@ -684,32 +692,50 @@ pub(crate) fn parse_port_from_url(url: &str) -> Option<u16> {
     port_str.parse().ok()
 }
 
-/// Parse git status output to extract working tree status and conflict state.
+/// Parse `git status --porcelain=v2` output to extract working tree status and conflict state.
 /// Returns (WorkingTreeStatus, is_dirty, has_conflicts).
+///
+/// Changed entries are `1 XY sub ...` (ordinary), `2 XY sub ...` (renamed/copied) and
+/// `u XY sub ...` (unmerged); untracked files are `? path`. `XY` uses the same codes as
+/// porcelain v1, with `.` for unchanged. `sub` is `N...` for regular paths and
+/// `S<c><m><u>` for submodules.
 pub(super) fn parse_working_tree_status(status_output: &str) -> (WorkingTreeStatus, bool, bool) {
     let mut has_untracked = false;
     let mut has_modified = false;
     let mut has_staged = false;
     let mut has_renamed = false;
     let mut has_deleted = false;
+    let mut has_submodule = false;
     let mut has_conflicts = false;
 
     for line in status_output.lines() {
-        if line.len() < 2 {
-            continue;
+        let mut fields = line.split(' ');
+        match fields.next() {
+            Some("?") => {
+                has_untracked = true;
+                continue;
+            }
+            Some("1" | "2" | "u") => {}
+            // Ignored files (`!`) and headers (`#`)
+            _ => continue,
         }
+        let Some(xy) = fields.next().filter(|xy| xy.len() == 2) else {
+            continue;
+        };
+        let is_submodule = fields.next().is_some_and(|sub| sub.starts_with('S'));
 
-        let bytes = line.as_bytes();
+        let bytes = xy.as_bytes();
         let index_status = bytes[0] as char;
         let worktree_status = bytes[1] as char;
 
-        if index_status == '?' && worktree_status == '?' {
-            has_untracked = true;
-        }
-
         // Worktree changes: M = modified, A = intent-to-add (git add -N), T = type change (file↔symlink)
+        // A submodule's worktree `M` means new commits, modified content, or untracked files inside it
         if matches!(worktree_status, 'M' | 'A' | 'T') {
-            has_modified = true;
+            if is_submodule {
+                has_submodule = true;
+            } else {
+                has_modified = true;
+            }
         }
 
         // Index changes: A = added, M = modified, C = copied, T = type change (file↔symlink)
@@ -737,13 +763,16 @@ pub(super) fn parse_working_tree_status(status_output: &str) -> (WorkingTreeStat
         }
     }
 
-    let working_tree_status = WorkingTreeStatus::new(
-        has_staged,
-        has_modified,
-        has_untracked,
-        has_renamed,
-        has_deleted,
-    );
+    let working_tree_status = WorkingTreeStatus {
+        submodule: has_submodule,
+        ..WorkingTreeStatus::new(
+            has_staged,
+            has_modified,
+            has_untracked,
+            has_renamed,
+            has_deleted,
+        )
+    };
 
     let is_dirty = working_tree_status.is_dirty();
 
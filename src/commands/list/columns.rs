@@ -1,3 +1,5 @@
+use worktrunk::config::ListColumn;
+
 use super::collect::TaskKind;
 
 /// Logical identifier for each column rendered by `wt list`.
@@ -15,6 +17,7 @@ pub enum ColumnKind {
     CiStatus,
     Commit,
     Time,
+    Author, // Initials of the last commit's author (opt-in via `[list] show-author`)
     Message,
 }
 
@@ -33,6 +36,7 @@ impl ColumnKind {
             ColumnKind::Time => "Age",
             ColumnKind::CiStatus => "CI",
             ColumnKind::Commit => "Commit",
+            ColumnKind::Author => "Author",
             ColumnKind::Message => "Message",
         }
     }
@@ -48,6 +52,39 @@ impl ColumnKind {
             .map(|spec| spec.base_priority)
             .unwrap_or(u8::MAX)
     }
+
+    /// Whether cells hold user text (names, paths, messages) rather than
+    /// worktrunk's own symbols, so `--plain` leaves them as they are.
+    pub const fn is_user_text(self) -> bool {
+        matches!(
+            self,
+            ColumnKind::Branch
+                | ColumnKind::Path
+                | ColumnKind::Url
+                | ColumnKind::Author
+                | ColumnKind::Message
+        )
+    }
+}
+
+impl From<ListColumn> for ColumnKind {
+    fn from(column: ListColumn) -> Self {
+        match column {
+            ListColumn::Branch => ColumnKind::Branch,
+            ListColumn::Status => ColumnKind::Status,
+            ListColumn::WorkingDiff => ColumnKind::WorkingDiff,
+            ListColumn::AheadBehind => ColumnKind::AheadBehind,
+            ListColumn::BranchDiff => ColumnKind::BranchDiff,
+            ListColumn::Path => ColumnKind::Path,
+            ListColumn::Upstream => ColumnKind::Upstream,
+            ListColumn::Url => ColumnKind::Url,
+            ListColumn::CiStatus => ColumnKind::CiStatus,
+            ListColumn::Commit => ColumnKind::Commit,
+            ListColumn::Time => ColumnKind::Time,
+            ListColumn::Author => ColumnKind::Author,
+            ListColumn::Message => ColumnKind::Message,
+        }
+    }
 }
 
 /// Differentiates between diff-style columns with plus/minus symbols and those with arrows.
@@ -91,11 +128,12 @@ pub const COLUMN_SPECS: &[ColumnSpec] = &[
     ColumnSpec::new(ColumnKind::AheadBehind, 4, None),
     ColumnSpec::new(ColumnKind::BranchDiff, 6, Some(TaskKind::BranchDiff)),
     ColumnSpec::new(ColumnKind::Path, 7, None),
-    ColumnSpec::new(ColumnKind::Upstream, 8, None),
+    ColumnSpec::new(ColumnKind::Upstream, 8, Some(TaskKind::Upstream)),
     ColumnSpec::new(ColumnKind::Url, 9, Some(TaskKind::UrlStatus)),
     ColumnSpec::new(ColumnKind::CiStatus, 5, Some(TaskKind::CiStatus)),
     ColumnSpec::new(ColumnKind::Commit, 10, None),
     ColumnSpec::new(ColumnKind::Time, 11, None),
+    ColumnSpec::new(ColumnKind::Author, 13, None),
     ColumnSpec::new(ColumnKind::Message, 12, None),
 ];
 
@@ -127,6 +165,7 @@ mod tests {
             ColumnKind::CiStatus,
             ColumnKind::Commit,
             ColumnKind::Time,
+            ColumnKind::Author,
             ColumnKind::Message,
         ];
         assert_eq!(kinds, expected, "column order should match display layout");
@@ -152,11 +191,18 @@ mod tests {
             .unwrap();
         assert_eq!(ci_status.requires_task, Some(TaskKind::CiStatus));
 
+        let upstream = COLUMN_SPECS
+            .iter()
+            .find(|c| c.kind == ColumnKind::Upstream)
+            .unwrap();
+        assert_eq!(upstream.requires_task, Some(TaskKind::Upstream));
+
         // All other columns should not require a background task to render
         for spec in COLUMN_SPECS {
             if spec.kind != ColumnKind::BranchDiff
                 && spec.kind != ColumnKind::Url
                 && spec.kind != ColumnKind::CiStatus
+                && spec.kind != ColumnKind::Upstream
             {
                 assert!(
                     spec.requires_task.is_none(),
@@ -225,6 +271,7 @@ mod tests {
             ColumnKind::CiStatus,
             ColumnKind::Commit,
             ColumnKind::Time,
+            ColumnKind::Author,
             ColumnKind::Message,
         ];
 
@@ -10,7 +10,7 @@
 //! Fields are organized by concept, matching the status display subcolumns:
 //! - `working_tree`: staged/modified/untracked changes
 //! - `main_state`: relationship to the default branch (would_conflict, same_commit, integrated, diverged, ahead, behind)
-//! - `operation_state`: git operations in progress (conflicts, rebase, merge)
+//! - `operation_state`: git operations in progress (conflicts, rebase, merge, cherry_pick, bisect)
 //! - `main`: relationship to the default branch (ahead/behind/diff counts)
 //! - `remote`: relationship to tracking branch
 //! - `worktree`: worktree-specific state (locked, prunable, etc.)
@@ -19,7 +19,7 @@ use std::path::PathBuf;
 
 use schemars::JsonSchema;
 use serde::Serialize;
-use worktrunk::git::LineDiff;
+use worktrunk::git::{LineDiff, short_sha};
 
 use super::ci_status::{CiSource, PrStatus};
 use super::model::{ItemKind, ListItem, UpstreamStatus};
@@ -54,7 +54,7 @@ pub struct JsonItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub integration_reason: Option<&'static str>,
 
-    /// Git operation in progress: conflicts, rebase, merge (null when none)
+    /// Git operation in progress: conflicts, rebase, merge, cherry_pick, bisect (null when none)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub operation_state: Option<&'static str>,
 
@@ -91,6 +91,11 @@ pub struct JsonItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url_active: Option<bool>,
 
+    /// Whether a running process has its working directory inside the worktree
+    /// (only checked with `--active-only`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+
     /// Pre-formatted statusline for statusline tools (tmux, starship)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub statusline: Option<String>,
@@ -114,6 +119,12 @@ pub struct JsonCommit {
 
     /// Unix timestamp of commit
     pub timestamp: i64,
+
+    /// Commit author name
+    pub author_name: String,
+
+    /// Commit author email
+    pub author_email: String,
 }
 
 /// Working tree state
@@ -134,6 +145,9 @@ pub struct JsonWorkingTree {
     /// Has deleted files (✘)
     pub deleted: bool,
 
+    /// Has submodule changes (§)
+    pub submodule: bool,
+
     /// Lines added/deleted in working tree vs HEAD
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diff: Option<JsonDiff>,
@@ -231,11 +245,7 @@ impl JsonItem {
 
         // Commit info
         let sha = item.head.clone();
-        let short_sha = if sha.len() >= 7 {
-            sha[..7].to_string()
-        } else {
-            sha.clone()
-        };
+        let short_sha = short_sha(&sha).to_string();
         let commit = JsonCommit {
             sha,
             short_sha,
@@ -245,6 +255,16 @@ impl JsonItem {
                 .map(|c| c.commit_message.clone())
                 .unwrap_or_default(),
             timestamp: item.commit.as_ref().map(|c| c.timestamp).unwrap_or(0),
+            author_name: item
+                .commit
+                .as_ref()
+                .map(|c| c.author_name.clone())
+                .unwrap_or_default(),
+            author_email: item
+                .commit
+                .as_ref()
+                .map(|c| c.author_email.clone())
+                .unwrap_or_default(),
         };
 
         // Working tree (only for worktrees with status symbols)
@@ -257,6 +277,7 @@ impl JsonItem {
                     untracked: wt.untracked,
                     renamed: wt.renamed,
                     deleted: wt.deleted,
+                    submodule: wt.submodule,
                     diff: data.working_tree_diff.map(JsonDiff::from),
                 }
             })
@@ -267,8 +288,8 @@ impl JsonItem {
             .status_symbols
             .as_ref()
             .map(|symbols| {
-                let state = symbols.main_state.as_json_str();
-                let reason = symbols.main_state.integration_reason().map(|r| r.into());
+                let state = symbols.main_state().as_json_str();
+                let reason = symbols.main_state().integration_reason().map(|r| r.into());
                 (state, reason)
             })
             .unwrap_or((None, None));
@@ -277,7 +298,7 @@ impl JsonItem {
         let operation_state = item
             .status_symbols
             .as_ref()
-            .and_then(|symbols| symbols.operation_state.as_json_str());
+            .and_then(|symbols| symbols.operation_state().as_json_str());
 
         // Main relationship (absent when is_main)
         let main = if is_main {
@@ -342,6 +363,7 @@ impl JsonItem {
             ci,
             url: item.url.clone(),
             url_active: item.url_active,
+            active: worktree_data.and_then(|d| d.active),
             statusline,
             symbols,
         }
@@ -371,7 +393,7 @@ fn worktree_state_to_json(
 
     // Check status symbols for worktree state
     if let Some(symbols) = status_symbols {
-        match symbols.worktree_state {
+        match symbols.worktree_state() {
             WorktreeState::None => {}
             WorktreeState::Branch => return (Some("no_worktree"), None),
             WorktreeState::BranchWorktreeMismatch => {
@@ -410,36 +432,36 @@ fn format_raw_symbols(symbols: &super::model::StatusSymbols) -> String {
     let mut result = String::new();
 
     // Working tree symbols
-    let wt_symbols = symbols.working_tree.to_symbols();
+    let wt_symbols = symbols.working_tree().to_symbols();
     if !wt_symbols.is_empty() {
         result.push_str(&wt_symbols);
     }
 
     // Main state (merged: ^✗_⊂↕↑↓)
-    let main_state = symbols.main_state.to_string();
+    let main_state = symbols.main_state().to_string();
     if !main_state.is_empty() {
         result.push_str(&main_state);
     }
 
     // Upstream divergence
-    let upstream_div = symbols.upstream_divergence.symbol();
+    let upstream_div = symbols.upstream_divergence().symbol();
     if !upstream_div.is_empty() {
         result.push_str(upstream_div);
     }
 
-    // Worktree state (operations ✘⤴⤵ take priority over location /⚑⊟⊞)
-    let op_state = symbols.operation_state.to_string();
+    // Worktree state (operations ✘⤴⤵⤷÷ take priority over location /⚑⊟⊞)
+    let op_state = symbols.operation_state().to_string();
     if !op_state.is_empty() {
         result.push_str(&op_state);
     } else {
-        let wt_state = symbols.worktree_state.to_string();
+        let wt_state = symbols.worktree_state().to_string();
         if !wt_state.is_empty() {
             result.push_str(&wt_state);
         }
     }
 
     // User marker
-    if let Some(ref marker) = symbols.user_marker {
+    if let Some(marker) = symbols.user_marker() {
         result.push_str(marker);
     }
 
@@ -451,6 +473,119 @@ pub fn to_json_items(items: &[ListItem]) -> Vec<JsonItem> {
     items.iter().map(JsonItem::from_list_item).collect()
 }
 
+/// Parse and validate `--fields` into dotted paths.
+///
+/// Fields are serde names of [`JsonItem`]; nested fields use dots
+/// (`main.ahead`, `remote.behind`). Names are validated against the JSON
+/// schema, so they can't drift from the structs. Called before collection so
+/// a typo fails fast instead of after every worktree has been inspected.
+pub fn parse_fields(fields: &[String]) -> anyhow::Result<Vec<Vec<&str>>> {
+    let schema = schemars::SchemaGenerator::default().into_root_schema_for::<JsonItem>();
+    let schema = schema.as_value();
+    fields
+        .iter()
+        .map(|field| {
+            let path: Vec<&str> = field.split('.').collect();
+            validate_field_path(schema, &path).map(|()| path)
+        })
+        .collect()
+}
+
+/// Serialize items keeping only the fields parsed by [`parse_fields`].
+///
+/// Requested fields that an item doesn't have (e.g. `path` for a branch) are
+/// omitted, as in the full output.
+pub fn project_fields(
+    items: &[JsonItem],
+    paths: &[Vec<&str>],
+) -> anyhow::Result<Vec<serde_json::Value>> {
+    items
+        .iter()
+        .map(|item| Ok(prune_value(&serde_json::to_value(item)?, paths)))
+        .collect()
+}
+
+/// Check a dotted field path against the `JsonItem` schema.
+fn validate_field_path(root: &serde_json::Value, path: &[&str]) -> anyhow::Result<()> {
+    let mut properties = object_properties(root, root);
+    for (depth, segment) in path.iter().enumerate() {
+        let parent = path[..depth].join(".");
+        let Some(props) = properties else {
+            anyhow::bail!(
+                "Unknown field '{}': '{parent}' has no subfields",
+                path.join(".")
+            );
+        };
+        let Some(schema) = props.get(*segment) else {
+            let mut valid: Vec<String> = props
+                .keys()
+                .map(|key| {
+                    if parent.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{parent}.{key}")
+                    }
+                })
+                .collect();
+            valid.sort();
+            anyhow::bail!(
+                "Unknown field '{}'. Valid: {}",
+                path.join("."),
+                valid.join(", ")
+            );
+        };
+        properties = object_properties(schema, root);
+    }
+    Ok(())
+}
+
+/// Properties of an object schema, following `$ref` and `Option` wrappers.
+fn object_properties<'a>(
+    schema: &'a serde_json::Value,
+    root: &'a serde_json::Value,
+) -> Option<&'a serde_json::Map<String, serde_json::Value>> {
+    if let Some(props) = schema.get("properties").and_then(|p| p.as_object()) {
+        return Some(props);
+    }
+    if let Some(reference) = schema.get("$ref").and_then(|r| r.as_str()) {
+        let name = reference.rsplit('/').next()?;
+        return object_properties(root.get("$defs")?.get(name)?, root);
+    }
+    ["anyOf", "oneOf", "allOf"].iter().find_map(|key| {
+        schema
+            .get(key)?
+            .as_array()?
+            .iter()
+            .find_map(|sub| object_properties(sub, root))
+    })
+}
+
+/// Keep only the keys named by `paths`, preserving nesting.
+fn prune_value(value: &serde_json::Value, paths: &[Vec<&str>]) -> serde_json::Value {
+    let Some(object) = value.as_object() else {
+        return value.clone();
+    };
+    let mut out = serde_json::Map::new();
+    for (key, child) in object {
+        let tails: Vec<Vec<&str>> = paths
+            .iter()
+            .filter(|path| path.first() == Some(&key.as_str()))
+            .map(|path| path[1..].to_vec())
+            .collect();
+        if tails.is_empty() {
+            continue;
+        }
+        // A bare key selects the whole value; dotted paths select within it
+        let pruned = if tails.iter().any(|tail| tail.is_empty()) {
+            child.clone()
+        } else {
+            prune_value(child, &tails)
+        };
+        out.insert(key.clone(), pruned);
+    }
+    serde_json::Value::Object(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -581,6 +716,7 @@ mod tests {
             remote: Some("origin".to_string()),
             ahead: 3,
             behind: 2,
+            ..Default::default()
         };
         let branch = Some("feature".to_string());
         let json = upstream_to_json(&upstream, &branch);
@@ -598,6 +734,7 @@ mod tests {
             remote: None,
             ahead: 0,
             behind: 0,
+            ..Default::default()
         };
         let branch = Some("feature".to_string());
         let json = upstream_to_json(&upstream, &branch);
@@ -610,6 +747,7 @@ mod tests {
             remote: Some("origin".to_string()),
             ahead: 1,
             behind: 0,
+            ..Default::default()
         };
         let branch = None;
         let json = upstream_to_json(&upstream, &branch);
@@ -634,6 +772,7 @@ mod tests {
             working_tree_diff: None,
             git_operation: ActiveGitOperation::None,
             branch_worktree_mismatch: false,
+            active: None,
             working_diff_display: None,
         }
     }
@@ -814,11 +953,15 @@ mod tests {
             short_sha: "abc123d".to_string(),
             message: "Fix bug".to_string(),
             timestamp: 1700000000,
+            author_name: "Jane Doe".to_string(),
+            author_email: "jane@example.com".to_string(),
         };
         let json = serde_json::to_string(&commit).unwrap();
         assert!(json.contains("abc123def456"));
         assert!(json.contains("Fix bug"));
         assert!(json.contains("1700000000"));
+        assert!(json.contains(r#""author_name":"Jane Doe""#));
+        assert!(json.contains(r#""author_email":"jane@example.com""#));
     }
 
     #[test]
@@ -892,4 +1035,41 @@ mod tests {
         assert!(json.contains("\"status\":\"passed\""));
         assert!(json.contains("\"source\":\"pr\""));
     }
+
+    // ============================================================================
+    // Field projection (--fields)
+    // ============================================================================
+
+    #[test]
+    fn test_prune_value_keeps_requested_paths() {
+        let value = serde_json::json!({
+            "branch": "feature",
+            "path": "/repo.feature",
+            "main": {"ahead": 2, "behind": 1, "diff": {"added": 3}},
+            "remote": {"ahead": 0},
+        });
+        let pruned = prune_value(&value, &[vec!["branch"], vec!["main", "ahead"]]);
+        assert_eq!(
+            pruned,
+            serde_json::json!({"branch": "feature", "main": {"ahead": 2}})
+        );
+    }
+
+    #[test]
+    fn test_validate_field_path() {
+        let schema = schemars::SchemaGenerator::default().into_root_schema_for::<JsonItem>();
+        let schema = schema.as_value();
+
+        assert!(validate_field_path(schema, &["branch"]).is_ok());
+        // Optional nested structs are reachable through their Option wrapper
+        assert!(validate_field_path(schema, &["main", "ahead"]).is_ok());
+        assert!(validate_field_path(schema, &["remote", "behind"]).is_ok());
+
+        let err = validate_field_path(schema, &["brnach"]).unwrap_err();
+        assert!(err.to_string().contains("Valid: "), "{err}");
+        let err = validate_field_path(schema, &["main", "nope"]).unwrap_err();
+        assert!(err.to_string().contains("main.ahead"), "{err}");
+        let err = validate_field_path(schema, &["branch", "name"]).unwrap_err();
+        assert!(err.to_string().contains("has no subfields"), "{err}");
+    }
 }
@@ -175,9 +175,10 @@ use std::path::{Path, PathBuf};
 
 use anstyle::Style;
 use unicode_width::UnicodeWidthStr;
-use worktrunk::styling::{ADDITION, DELETION, Stream, supports_hyperlinks};
+use worktrunk::config::{ListColumn, ListConfig, PathStyle, TimeFormat};
+use worktrunk::styling::{Stream, supports_hyperlinks, theme};
 
-use crate::display::{get_terminal_width, shorten_path};
+use crate::display::{format_list_path, get_terminal_width, relative_time_max_width};
 
 use super::collect::{TaskKind, parse_port_from_url};
 use super::columns::{COLUMN_SPECS, ColumnKind, ColumnSpec, column_display_index};
@@ -188,6 +189,16 @@ pub use super::columns::DiffVariant;
 /// Width of short commit hash display (first 8 hex characters)
 const COMMIT_HASH_WIDTH: usize = 8;
 
+/// Smallest `--max-width` honored, so the Branch column is always partly visible
+pub const MIN_MAX_WIDTH: usize = 20;
+
+/// Glyph after the Upstream arrows when the remote-tracking ref is stale (`--since-fetch`)
+pub const FETCH_STALE_GLYPH: &str = "◷";
+
+/// Layout width for `--plain` and `--format=markdown`, wide enough that no column is
+/// hidden or truncated
+pub const PLAIN_LAYOUT_WIDTH: usize = 10_000;
+
 /// Ensures a column width is at least as wide as its header.
 ///
 /// This is the general solution for preventing header overflow: pass the header
@@ -240,6 +251,7 @@ pub struct ColumnWidths {
     pub branch: usize,
     pub status: usize, // Includes both git status symbols and user-defined status
     pub time: usize,
+    pub author: usize, // 0 when the Author column is disabled
     pub url: usize,
     pub ci_status: usize,
     pub message: usize,
@@ -268,6 +280,8 @@ pub struct LayoutMetadata {
     pub widths: ColumnWidths,
     pub data_flags: ColumnDataFlags,
     pub status_position_mask: super::model::PositionMask,
+    /// Width reserved for the remote name in the Upstream column (0 = arrows only)
+    pub upstream_remote_width: usize,
 }
 
 const EMPTY_PENALTY: u8 = 10;
@@ -369,23 +383,24 @@ impl DiffVariant {
 
 impl ColumnKind {
     pub fn diff_display_config(self) -> Option<DiffDisplayConfig> {
+        let theme = theme();
         match self {
             ColumnKind::WorkingDiff | ColumnKind::BranchDiff => Some(DiffDisplayConfig {
                 variant: DiffVariant::Signs,
-                positive_style: ADDITION,
-                negative_style: DELETION,
+                positive_style: theme.addition,
+                negative_style: theme.deletion,
                 always_show_zeros: false,
             }),
             ColumnKind::AheadBehind => Some(DiffDisplayConfig {
                 variant: DiffVariant::Arrows,
-                positive_style: ADDITION,
-                negative_style: DELETION.dimmed(),
+                positive_style: theme.addition,
+                negative_style: theme.deletion.dimmed(),
                 always_show_zeros: false,
             }),
             ColumnKind::Upstream => Some(DiffDisplayConfig {
                 variant: DiffVariant::UpstreamArrows,
-                positive_style: ADDITION,
-                negative_style: DELETION.dimmed(),
+                positive_style: theme.addition,
+                negative_style: theme.deletion.dimmed(),
                 always_show_zeros: false, // 0/0 case handled specially with | symbol
             }),
             _ => None,
@@ -412,6 +427,7 @@ impl ColumnKind {
             ColumnKind::Time => true,
             ColumnKind::CiStatus => flags.ci_status,
             ColumnKind::Commit => true,
+            ColumnKind::Author => true,
             ColumnKind::Message => true,
         }
     }
@@ -449,6 +465,7 @@ impl ColumnKind {
             ColumnKind::Url => text(widths.url),
             ColumnKind::CiStatus => text(widths.ci_status),
             ColumnKind::Commit => text(commit_width),
+            ColumnKind::Author => text(widths.author),
             ColumnKind::Message => None,
             ColumnKind::WorkingDiff => diff(widths.working_diff),
             ColumnKind::AheadBehind => diff(widths.ahead_behind),
@@ -484,9 +501,51 @@ pub struct ColumnLayout {
 pub struct LayoutConfig {
     pub columns: Vec<ColumnLayout>,
     pub main_worktree_path: PathBuf,
+    /// How the Path column renders paths (`--path-style` / `[list] path-style`)
+    pub path_style: PathStyle,
+    /// How the Age column renders commit ages (`[list] time-format`)
+    pub time_format: TimeFormat,
+    /// Width of the remote-name prefix in the Upstream column (0 = arrows only)
+    pub upstream_remote_width: usize,
+    /// Mark remote-tracking refs not refreshed within this long (`--since-fetch`)
+    pub since_fetch: Option<std::time::Duration>,
+    /// Gray out rows with no unique work (`[list] dim-removable`)
+    pub dim_removable: bool,
     pub max_message_len: usize,
     pub hidden_column_count: usize,
     pub status_position_mask: super::model::PositionMask,
+    /// Width rows are clipped to (terminal width, or `--max-width`)
+    pub max_width: usize,
+    /// Unaligned rows with ASCII symbols and single-space separators (`--plain`)
+    pub plain: bool,
+    /// GitHub-flavored markdown table rows (`--format=markdown`)
+    pub markdown: bool,
+}
+
+impl LayoutConfig {
+    /// Switch to `--plain` rendering: no alignment, and no truncation of text cells.
+    pub fn use_plain_rendering(&mut self) {
+        self.plain = true;
+        self.expand_text_columns();
+    }
+
+    /// Switch to `--format=markdown` rendering: pipe-delimited cells, no truncation.
+    pub fn use_markdown_rendering(&mut self) {
+        self.markdown = true;
+        self.expand_text_columns();
+    }
+
+    fn expand_text_columns(&mut self) {
+        self.max_message_len = PLAIN_LAYOUT_WIDTH;
+        for column in &mut self.columns {
+            if matches!(
+                column.kind,
+                ColumnKind::Branch | ColumnKind::Path | ColumnKind::Url
+            ) {
+                column.width = PLAIN_LAYOUT_WIDTH;
+            }
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -541,6 +600,10 @@ fn build_estimated_widths(
     skip_tasks: &HashSet<TaskKind>,
     has_branch_worktree_mismatch: bool,
     url_width: usize,
+    show_author: bool,
+    upstream_remote_width: usize,
+    fetch_indicator: bool,
+    time_format: TimeFormat,
 ) -> LayoutMetadata {
     // Fixed widths for slow columns (require expensive git operations)
     // Values exceeding these widths use compact notation (K suffix)
@@ -551,9 +614,33 @@ fn build_estimated_widths(
     let working_diff_fixed = fit_header(ColumnKind::WorkingDiff.header(), 9); // "+999 -999"
     let ahead_behind_fixed = fit_header(ColumnKind::AheadBehind.header(), 7); // "↑99 ↓99"
     let branch_diff_fixed = fit_header(ColumnKind::BranchDiff.header(), 9); // "+999 -999"
-    let upstream_fixed = fit_header(ColumnKind::Upstream.header(), 7); // "↑99 ↓99"
-    let age_estimate = 4; // "11mo" (short format)
+    // "↑99 ↓99", optionally prefixed by "origin "
+    let upstream_prefix = if upstream_remote_width > 0 {
+        upstream_remote_width + 1
+    } else {
+        0
+    };
+    // " ◷" after the arrows when `--since-fetch` marks stale remote-tracking refs
+    let upstream_suffix = if fetch_indicator {
+        FETCH_STALE_GLYPH.width() + 1
+    } else {
+        0
+    };
+    let upstream_fixed = fit_header(
+        ColumnKind::Upstream.header(),
+        upstream_prefix + 7 + upstream_suffix,
+    );
+    // "11mo" (compact) or "11 months ago" (long)
+    let age_estimate = fit_header(
+        ColumnKind::Time.header(),
+        relative_time_max_width(time_format),
+    );
     let ci_estimate = fit_header(ColumnKind::CiStatus.header(), 1); // Single indicator symbol
+    let author_estimate = if show_author {
+        fit_header(ColumnKind::Author.header(), 2) // Initials ("JD")
+    } else {
+        0
+    };
 
     // Assume columns will have data (better to show and hide than to not show).
     // This is a limitation of progressive mode - we can't know which columns have data
@@ -561,13 +648,13 @@ fn build_estimated_widths(
     //
     // Exceptions that we can compute instantly from items:
     // - path: true only if any worktree has branch_worktree_mismatch
-    // - branch_diff/ci_status: false if their required task is skipped
+    // - branch_diff/upstream/ci_status: false if their required task is skipped
     let data_flags = ColumnDataFlags {
         status: true,
         working_diff: true,
         ahead_behind: true,
         branch_diff: !skip_tasks.contains(&TaskKind::BranchDiff),
-        upstream: true,
+        upstream: !skip_tasks.contains(&TaskKind::Upstream),
         url: !skip_tasks.contains(&TaskKind::UrlStatus),
         ci_status: !skip_tasks.contains(&TaskKind::CiStatus),
         path: has_branch_worktree_mismatch,
@@ -585,6 +672,7 @@ fn build_estimated_widths(
         branch: max_branch,
         status: status_fixed,
         time: age_estimate,
+        author: author_estimate,
         url: url_estimate,
         ci_status: ci_estimate,
         message: 50, // Will be flexible during allocation
@@ -617,6 +705,7 @@ fn build_estimated_widths(
         widths,
         data_flags,
         status_position_mask: super::model::PositionMask::FULL,
+        upstream_remote_width,
     }
 }
 
@@ -624,25 +713,45 @@ fn build_estimated_widths(
 ///
 /// This is the core allocation algorithm used by `calculate_layout_from_basics()`
 /// with pre-allocated width estimates for expensive-to-compute columns.
+///
+/// When `list_config.columns` is set, only those columns (plus the gutter) are candidates,
+/// and they are displayed in the user's order rather than `COLUMN_SPECS` order.
 fn allocate_columns_with_priority(
     metadata: &LayoutMetadata,
     skip_tasks: &HashSet<TaskKind>,
+    list_config: &ListConfig,
     max_path_width: usize,
     commit_width: usize,
     terminal_width: usize,
     main_worktree_path: PathBuf,
 ) -> LayoutConfig {
+    let user_columns = list_config.columns.as_deref();
     let spacing = 2;
     let mut remaining = terminal_width;
 
+    // Display position within the user's column list (gutter always comes first)
+    let user_display_index = |kind: ColumnKind| -> Option<usize> {
+        if kind == ColumnKind::Gutter {
+            return Some(0);
+        }
+        user_columns?
+            .iter()
+            .position(|&c| ColumnKind::from(c) == kind)
+            .map(|i| i + 1)
+    };
+
     // Build candidates with priorities
-    // Filter out columns whose required task is being skipped
+    // Filter out columns whose required task is being skipped, and columns the user
+    // left out of `[list] columns`
     let mut candidates: Vec<ColumnCandidate> = COLUMN_SPECS
         .iter()
         .filter(|spec| {
             spec.requires_task
                 .is_none_or(|task| !skip_tasks.contains(&task))
         })
+        .filter(|spec| user_columns.is_none() || user_display_index(spec.kind).is_some())
+        // Author is opt-in (`[list] show-author`); a zero width means disabled
+        .filter(|spec| spec.kind != ColumnKind::Author || metadata.widths.author > 0)
         .map(|spec| ColumnCandidate {
             spec,
             priority: if spec.kind.has_data(&metadata.data_flags) {
@@ -745,7 +854,11 @@ fn allocate_columns_with_priority(
     }
 
     // Sort by display order to maintain correct visual order
-    pending.sort_by_key(|col| column_display_index(col.spec.kind));
+    if user_columns.is_some() {
+        pending.sort_by_key(|col| user_display_index(col.spec.kind));
+    } else {
+        pending.sort_by_key(|col| column_display_index(col.spec.kind));
+    }
 
     // Build final column layouts with positions
     let gap = 2;
@@ -790,9 +903,17 @@ fn allocate_columns_with_priority(
     LayoutConfig {
         columns,
         main_worktree_path,
+        path_style: list_config.path_style(),
+        time_format: list_config.time_format(),
         max_message_len,
         hidden_column_count,
+        upstream_remote_width: metadata.upstream_remote_width,
+        since_fetch: None,
+        dim_removable: list_config.dim_removable(),
         status_position_mask: metadata.status_position_mask,
+        max_width: terminal_width,
+        plain: false,
+        markdown: false,
     }
 }
 
@@ -812,15 +933,29 @@ fn allocate_columns_with_priority(
 /// - Ahead/behind: 7 chars ("↑99 ↓99")
 /// - Branch diff: 9 chars ("+999 -999")
 /// - Upstream: 7 chars ("↑99 ↓99")
-/// - Age: 4 chars ("11mo" short format)
+/// - Age: 4 chars ("11mo"), or 13 ("11 months ago") with `time-format = "long"`
 /// - CI: 1 char (indicator symbol)
+/// - Author: initials, only when `show-author` is enabled
 /// - Message: flexible (20-100 chars)
 /// - URL: estimated from template + longest branch
+///
+/// `list_config` supplies user layout settings: the Branch column is capped at
+/// `branch-width` (longer names are truncated with `…` when rendered), `columns`
+/// restricts and orders the visible columns, `show-author` enables the Author column,
+/// `path-style` picks how the Path column renders paths, and `time-format` how the
+/// Age column renders commit ages.
+///
+/// `upstream_remote_width` reserves room for a remote-name prefix in the Upstream
+/// column (`origin ⇡2 ⇣1`); 0 shows arrows only. `since_fetch` reserves room for
+/// the staleness glyph after the arrows (`⇡2 ⇣1 ◷`).
 pub fn calculate_layout_from_basics(
     items: &[super::model::ListItem],
     skip_tasks: &HashSet<TaskKind>,
     main_worktree_path: &Path,
     url_template: Option<&str>,
+    list_config: &ListConfig,
+    upstream_remote_width: usize,
+    since_fetch: Option<std::time::Duration>,
 ) -> LayoutConfig {
     calculate_layout_with_width(
         items,
@@ -828,6 +963,9 @@ pub fn calculate_layout_from_basics(
         get_terminal_width(),
         main_worktree_path,
         url_template,
+        list_config,
+        upstream_remote_width,
+        since_fetch,
     )
 }
 
@@ -838,6 +976,9 @@ pub fn calculate_layout_with_width(
     terminal_width: usize,
     main_worktree_path: &Path,
     url_template: Option<&str>,
+    list_config: &ListConfig,
+    upstream_remote_width: usize,
+    since_fetch: Option<std::time::Duration>,
 ) -> LayoutConfig {
     // Calculate actual widths for things we know
     // Include branch names from both worktrees and standalone branches
@@ -847,22 +988,27 @@ pub fn calculate_layout_with_width(
         .max_by_key(|b| b.width());
 
     let max_branch = longest_branch.map(|b| b.width()).unwrap_or(0);
+    let max_branch = max_branch.min(list_config.branch_width());
     let max_branch = fit_header(ColumnKind::Branch.header(), max_branch);
 
     let path_data_width = items
         .iter()
-        .filter_map(|item| item.worktree_path())
-        .map(|path| shorten_path(path.as_path(), main_worktree_path).width())
+        .filter_map(|item| item.worktree_data())
+        .map(|data| {
+            let path_width =
+                format_list_path(&data.path, main_worktree_path, list_config.path_style()).width();
+            path_width + data.missing_marker().map_or(0, |m| m.width() + 1)
+        })
         .max()
         .unwrap_or(0);
     let max_path_width = fit_header(ColumnKind::Path.header(), path_data_width);
 
-    // Check if any worktree has a branch-worktree mismatch.
-    // Path column is only useful when there's a mismatch; otherwise it's redundant with branch.
+    // Check if any worktree has a branch-worktree mismatch or a missing directory.
+    // Path column is only useful then; otherwise it's redundant with branch.
     let has_branch_worktree_mismatch = items
         .iter()
         .filter_map(|item| item.worktree_data())
-        .any(|data| data.branch_worktree_mismatch);
+        .any(|data| data.branch_worktree_mismatch || data.is_prunable());
 
     // Estimate URL width from template (heuristic, no expansion needed)
     let url_width = estimate_url_width(url_template, supports_hyperlinks(Stream::Stdout));
@@ -873,18 +1019,25 @@ pub fn calculate_layout_with_width(
         skip_tasks,
         has_branch_worktree_mismatch,
         url_width,
+        list_config.show_author(),
+        upstream_remote_width,
+        since_fetch.is_some(),
+        list_config.time_format(),
     );
 
     let commit_width = fit_header(ColumnKind::Commit.header(), COMMIT_HASH_WIDTH);
 
-    allocate_columns_with_priority(
+    let mut layout = allocate_columns_with_priority(
         &metadata,
         skip_tasks,
+        list_config,
         max_path_width,
         commit_width,
         terminal_width,
         main_worktree_path.to_path_buf(),
-    )
+    );
+    layout.since_fetch = since_fetch;
+    layout
 }
 
 #[cfg(test)]
@@ -1014,6 +1167,7 @@ mod tests {
             branch: 15,
             status: 8,
             time: 4,
+            author: 6,
             url: 0,
             ci_status: 2,
             message: 50,
@@ -1081,6 +1235,7 @@ mod tests {
             branch: 0,
             status: 0,
             time: 0,
+            author: 0,
             url: 0,
             ci_status: 0,
             message: 0,
@@ -1115,7 +1270,16 @@ mod tests {
         // Empty skip set means all tasks are computed (equivalent to --full)
         // has_branch_worktree_mismatch=true to test the path flag is passed through
         // url_width=0 since we're not testing URL column here
-        let metadata = build_estimated_widths(20, &HashSet::new(), true, 0);
+        let metadata = build_estimated_widths(
+            20,
+            &HashSet::new(),
+            true,
+            0,
+            false,
+            0,
+            false,
+            TimeFormat::Compact,
+        );
         let widths = metadata.widths;
 
         // Line diffs (Signs variant: +/-) allocate 3 digits for 100-999 range
@@ -1179,6 +1343,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_upstream_width_includes_remote_name() {
+        // "origin ↑99 ↓99" = 6 + 1 + 7
+        let metadata = build_estimated_widths(
+            20,
+            &HashSet::new(),
+            true,
+            0,
+            false,
+            6,
+            false,
+            TimeFormat::Compact,
+        );
+        assert_eq!(metadata.widths.upstream.total, 14);
+        assert_eq!(metadata.upstream_remote_width, 6);
+    }
+
+    #[test]
+    fn test_upstream_width_includes_fetch_indicator() {
+        // "↑99 ↓99 ◷" = 7 + 1 + 1
+        let metadata = build_estimated_widths(
+            20,
+            &HashSet::new(),
+            true,
+            0,
+            false,
+            0,
+            true,
+            TimeFormat::Compact,
+        );
+        assert_eq!(metadata.widths.upstream.total, 9);
+    }
+
     #[test]
     fn test_visible_columns_follow_gap_rule() {
         use crate::commands::list::model::{
@@ -1193,6 +1390,7 @@ mod tests {
             commit: Some(CommitDetails {
                 timestamp: 1234567890,
                 commit_message: "Test commit message".to_string(),
+                ..Default::default()
             }),
             counts: Some(AheadBehind {
                 ahead: 5,
@@ -1210,6 +1408,7 @@ mod tests {
                 remote: Some("origin".to_string()),
                 ahead: 4,
                 behind: 2,
+                ..Default::default()
             }),
             pr_status: None,
             url: None,
@@ -1227,6 +1426,7 @@ mod tests {
                 is_current: false,
                 is_previous: false,
                 branch_worktree_mismatch: false,
+                active: None,
                 working_diff_display: None,
             })),
         };
@@ -1236,7 +1436,15 @@ mod tests {
             .into_iter()
             .collect();
         let main_worktree_path = PathBuf::from("/test");
-        let layout = calculate_layout_from_basics(&items, &skip_tasks, &main_worktree_path, None);
+        let layout = calculate_layout_from_basics(
+            &items,
+            &skip_tasks,
+            &main_worktree_path,
+            None,
+            &ListConfig::default(),
+            0,
+            None,
+        );
 
         assert!(
             !layout.columns.is_empty(),
@@ -1295,6 +1503,7 @@ mod tests {
             commit: Some(CommitDetails {
                 timestamp: 1234567890,
                 commit_message: "Test".to_string(),
+                ..Default::default()
             }),
             counts: Some(AheadBehind {
                 ahead: 0,
@@ -1325,6 +1534,7 @@ mod tests {
                 is_current: false,
                 is_previous: false,
                 branch_worktree_mismatch: false,
+                active: None,
                 working_diff_display: None,
             })),
         };
@@ -1334,7 +1544,15 @@ mod tests {
             .into_iter()
             .collect();
         let main_worktree_path = PathBuf::from("/home/user/project");
-        let layout = calculate_layout_from_basics(&items, &skip_tasks, &main_worktree_path, None);
+        let layout = calculate_layout_from_basics(
+            &items,
+            &skip_tasks,
+            &main_worktree_path,
+            None,
+            &ListConfig::default(),
+            0,
+            None,
+        );
 
         assert!(
             layout
@@ -1401,4 +1619,121 @@ mod tests {
         // With hyperlinks: has ":{{" pattern, compact display = 6
         assert_eq!(estimate_url_width(Some(template), true), 6);
     }
+
+    #[test]
+    fn test_branch_column_capped_at_branch_width() {
+        use crate::commands::list::model::ListItem;
+
+        let long_branch = "feature-日本語-".repeat(5); // 75 visual columns
+        let items = vec![ListItem::new_branch("abc12345".to_string(), long_branch)];
+        let skip_tasks = HashSet::new();
+        let main_worktree_path = PathBuf::from("/test");
+
+        let branch_width = |branch_width| {
+            let list_config = ListConfig {
+                branch_width,
+                ..Default::default()
+            };
+            calculate_layout_with_width(
+                &items,
+                &skip_tasks,
+                200,
+                &main_worktree_path,
+                None,
+                &list_config,
+                0,
+                None,
+            )
+            .columns
+            .iter()
+            .find(|c| c.kind == ColumnKind::Branch)
+            .map(|c| c.width)
+        };
+
+        assert_eq!(branch_width(None), Some(40));
+        assert_eq!(branch_width(Some(0)), Some(75));
+    }
+
+    #[test]
+    fn test_user_columns_filter_and_order() {
+        use crate::commands::list::model::ListItem;
+
+        let items = vec![ListItem::new_branch(
+            "abc12345".to_string(),
+            "feature".to_string(),
+        )];
+        let skip_tasks = HashSet::new();
+        let main_worktree_path = PathBuf::from("/test");
+        let list_config = ListConfig {
+            columns: Some(vec![
+                ListColumn::Commit,
+                ListColumn::Branch,
+                ListColumn::Status,
+            ]),
+            ..Default::default()
+        };
+
+        let layout = calculate_layout_with_width(
+            &items,
+            &skip_tasks,
+            200,
+            &main_worktree_path,
+            None,
+            &list_config,
+            0,
+            None,
+        );
+
+        let kinds: Vec<ColumnKind> = layout.columns.iter().map(|c| c.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                ColumnKind::Gutter,
+                ColumnKind::Commit,
+                ColumnKind::Branch,
+                ColumnKind::Status
+            ]
+        );
+        assert_eq!(layout.hidden_column_count, 0);
+    }
+
+    #[test]
+    fn test_author_column_opt_in() {
+        use crate::commands::list::model::ListItem;
+
+        let items = vec![ListItem::new_branch(
+            "abc12345".to_string(),
+            "feature".to_string(),
+        )];
+        let skip_tasks = HashSet::new();
+        let main_worktree_path = PathBuf::from("/test");
+
+        let author_width = |show_author, terminal_width| {
+            let list_config = ListConfig {
+                show_author,
+                ..Default::default()
+            };
+            calculate_layout_with_width(
+                &items,
+                &skip_tasks,
+                terminal_width,
+                &main_worktree_path,
+                None,
+                &list_config,
+                0,
+                None,
+            )
+            .columns
+            .iter()
+            .find(|c| c.kind == ColumnKind::Author)
+            .map(|c| c.width)
+        };
+
+        // Off by default
+        assert_eq!(author_width(None, 200), None);
+        // Header-width column when enabled
+        assert_eq!(author_width(Some(true), 200), Some(6));
+        // Lowest priority: dropped first in narrow terminals
+        assert_eq!(author_width(Some(true), 40), None);
+    }
 }
@@ -139,7 +139,7 @@ use anyhow::Context;
 use model::{ListData, ListItem};
 use progressive::RenderMode;
 use worktrunk::git::Repository;
-use worktrunk::styling::INFO_SYMBOL;
+use worktrunk::styling::{INFO_SYMBOL, eprintln, print_progress, warning_message};
 
 use collect::TaskKind;
 
@@ -147,19 +147,91 @@ use collect::TaskKind;
 pub use collect::{CollectOptions, build_worktree_item, populate_item};
 pub use model::StatuslineSegment;
 
+/// Options for the list command
+///
+/// Flags that also have a `[list]` config setting arrive already resolved
+/// against it.
+pub struct ListOptions<'a> {
+    pub format: crate::cli::ListFormat,
+    pub show_branches: bool,
+    pub show_worktrees: bool,
+    pub show_remotes: bool,
+    pub show_full: bool,
+    /// Fetch from the primary remote before listing (`--fetch`)
+    pub fetch: bool,
+    pub show_upstream: bool,
+    /// Print the status symbol key after the table (`--legend`)
+    pub legend: bool,
+    /// Always show change, conflict and ahead counts in the summary (`--stats`)
+    pub stats: bool,
+    /// Hide items whose last commit is older than this (`--since`)
+    pub since: Option<std::time::Duration>,
+    /// Mark upstreams not refreshed within this window (`--since-fetch`)
+    pub since_fetch: Option<std::time::Duration>,
+    pub path_filter: Option<&'a glob::Pattern>,
+    /// Only worktrees with a running process (`--active-only`)
+    pub active_only: bool,
+    /// Only items that conflict with the default branch (`--conflicts-only`)
+    pub conflicts_only: bool,
+    pub group_by: crate::cli::ListGroupBy,
+    pub sort: crate::cli::ListSort,
+    pub path_style: Option<worktrunk::config::PathStyle>,
+    /// Show remote names in the Upstream column (`--remote-names`)
+    pub remote_names: bool,
+    pub max_width: Option<usize>,
+    /// Unaligned table with ASCII symbols (`--plain`)
+    pub plain: bool,
+    /// JSON fields to keep (`--fields`)
+    pub fields: &'a [String],
+    pub render_mode: RenderMode,
+}
+
 pub fn handle_list(
-    format: crate::OutputFormat,
-    show_branches: bool,
-    show_remotes: bool,
-    show_full: bool,
-    render_mode: RenderMode,
+    opts: ListOptions<'_>,
     config: &worktrunk::config::UserConfig,
 ) -> anyhow::Result<()> {
+    let ListOptions {
+        format,
+        show_branches,
+        show_worktrees,
+        show_remotes,
+        show_full,
+        fetch,
+        show_upstream,
+        legend,
+        stats,
+        since,
+        since_fetch,
+        path_filter,
+        active_only,
+        conflicts_only,
+        group_by,
+        sort,
+        path_style,
+        remote_names,
+        max_width,
+        plain,
+        fields,
+        render_mode,
+    } = opts;
+
     let repo = Repository::current()?;
+    worktrunk::styling::set_theme(config.theme());
+    let field_paths = json_output::parse_fields(fields)?;
+
+    // Refresh remote-tracking refs once, before any per-item work reads them.
+    // A failed fetch (offline, auth) shouldn't prevent listing local state.
+    if fetch && let Err(e) = repo.fetch() {
+        eprintln!("{}", warning_message(format!("{e:#}")));
+    }
+
+    if config.maintenance {
+        spawn_commit_graph_write(&repo);
+    }
 
     // Build skip set based on flags
     // Without --full: skip expensive operations (BranchDiff, CiStatus, WorkingTreeConflicts)
-    let skip_tasks: HashSet<TaskKind> = if show_full {
+    let mut skip_tasks: HashSet<TaskKind> = if show_full {
         HashSet::new() // Compute everything
     } else {
         [
@@ -170,19 +242,33 @@ pub fn handle_list(
         .into_iter()
         .collect()
     };
+    // --no-upstream (or `list.upstream = false`): skip upstream lookup + ahead/behind
+    if !show_upstream {
+        skip_tasks.insert(TaskKind::Upstream);
+    }
+    if conflicts_only {
+        skip_tasks.remove(&TaskKind::WorkingTreeConflicts);
+        print_progress("Checking for conflicts with the default branch...");
+    }
 
-    // Progressive rendering only for table format with Progressive mode
+    // Progressive rendering only for table format with Progressive mode.
+    // Grouping and --conflicts-only need final status for every row, and --plain
+    // and markdown rows can't be redrawn in place, so they render buffered.
     let show_progress = match format {
-        crate::OutputFormat::Table | crate::OutputFormat::ClaudeCode => {
+        crate::cli::ListFormat::Table => {
             render_mode == RenderMode::Progressive
+                && group_by == crate::cli::ListGroupBy::None
+                && !conflicts_only
+                && !plain
         }
-        crate::OutputFormat::Json => false, // JSON never shows progress
+        crate::cli::ListFormat::Markdown => false,
+        crate::cli::ListFormat::Json | crate::cli::ListFormat::Jsonl => false, // JSON never shows progress
     };
 
     // Render table in collect() for all table modes (progressive + buffered)
     let render_table = matches!(
         format,
-        crate::OutputFormat::Table | crate::OutputFormat::ClaudeCode
+        crate::cli::ListFormat::Table | crate::cli::ListFormat::Markdown
     );
 
     // For testing: allow enabling skip_expensive_for_stale via env var
@@ -203,14 +289,30 @@ pub fn handle_list(
 
     let list_data = collect::collect(
         &repo,
-        show_branches,
-        show_remotes,
-        &skip_tasks,
-        show_progress,
-        render_table,
         config,
-        command_timeout,
-        skip_expensive_for_stale,
+        collect::CollectArgs {
+            show_branches,
+            show_worktrees,
+            show_remotes,
+            skip_tasks: &skip_tasks,
+            show_progress,
+            render_table,
+            command_timeout,
+            skip_expensive_for_stale,
+            since,
+            since_fetch,
+            path_filter,
+            active_only,
+            conflicts_only,
+            group_by,
+            sort,
+            path_style,
+            remote_names,
+            max_width,
+            plain,
+            markdown: matches!(format, crate::cli::ListFormat::Markdown),
+            stats,
+        },
     )?;
 
     let Some(ListData { items, .. }) = list_data else {
@@ -218,28 +320,75 @@ pub fn handle_list(
     };
 
     match format {
-        crate::OutputFormat::Json => {
+        crate::cli::ListFormat::Json => {
             // Convert to new JSON structure
             let json_items = json_output::to_json_items(&items);
-            let json =
-                serde_json::to_string_pretty(&json_items).context("Failed to serialize to JSON")?;
+            let json = if fields.is_empty() {
+                serde_json::to_string_pretty(&json_items)
+            } else {
+                serde_json::to_string_pretty(&json_output::project_fields(
+                    &json_items,
+                    &field_paths,
+                )?)
+            }
+            .context("Failed to serialize to JSON")?;
             println!("{}", json);
         }
-        crate::OutputFormat::Table | crate::OutputFormat::ClaudeCode => {
+        crate::cli::ListFormat::Jsonl => {
+            // Same objects as the JSON array, one per line
+            let json_items = json_output::to_json_items(&items);
+            let values = if fields.is_empty() {
+                json_items
+                    .iter()
+                    .map(serde_json::to_value)
+                    .collect::<Result<Vec<_>, _>>()
+                    .context("Failed to serialize to JSON")?
+            } else {
+                json_output::project_fields(&json_items, &field_paths)?
+            };
+            for value in values {
+                println!("{value}");
+            }
+        }
+        // Markdown table already rendered in collect(); the legend is terminal-only
+        crate::cli::ListFormat::Markdown => {}
+        crate::cli::ListFormat::Table => {
             // Table and summary already rendered in collect() for all modes
-            // Nothing to do here - collect() handles the complete table rendering
+            if legend {
+                println!();
+                println!("{}", format_status_legend());
+            }
         }
     }
 
     Ok(())
 }
 
+/// Format the status symbol key for `wt list --legend`, one line per position category.
+fn format_status_legend() -> String {
+    let groups = model::status_legend();
+    let name_width = groups.iter().map(|g| g.name.len()).max().unwrap_or(0);
+    groups
+        .iter()
+        .map(|group| {
+            let entries: Vec<String> = group
+                .entries
+                .iter()
+                .map(|(symbol, meaning)| color_print::cformat!("{symbol} <dim>{meaning}</>"))
+                .collect();
+            format!("{:name_width$}  {}", group.name, entries.join("  "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 #[derive(Default)]
 pub(super) struct SummaryMetrics {
     worktrees: usize,
     local_branches: usize,
     remote_branches: usize,
     dirty_worktrees: usize,
+    conflicted_worktrees: usize,
     ahead_items: usize,
 }
 
@@ -260,10 +409,19 @@ impl SummaryMetrics {
             if item
                 .status_symbols
                 .as_ref()
-                .is_some_and(|s| s.working_tree.is_dirty())
+                .is_some_and(|s| s.working_tree().is_dirty())
             {
                 self.dirty_worktrees += 1;
             }
+            // Same classification as `--group-by status`, so merge-tree conflicts
+            // (shown without `--full`) count along with in-progress ones
+            if item
+                .status_symbols
+                .as_ref()
+                .is_some_and(|s| s.group() == model::StatusGroup::Conflicts)
+            {
+                self.conflicted_worktrees += 1;
+            }
         } else {
             // Distinguish local vs remote branches by presence of '/' in name
             // Remote branches are like "origin/feature", local are like "feature"
@@ -279,10 +437,14 @@ impl SummaryMetrics {
         }
     }
 
+    /// Summary line parts. With `stats` (`wt list --stats`), the change,
+    /// conflict and ahead counts are always shown; otherwise only nonzero
+    /// change and ahead counts are.
     pub(super) fn summary_parts(
         &self,
         include_branches: bool,
         hidden_columns: usize,
+        stats: bool,
     ) -> Vec<String> {
         let mut parts = Vec::new();
 
@@ -299,11 +461,15 @@ impl SummaryMetrics {
             parts.push(format!("{} worktree{}", self.worktrees, plural));
         }
 
-        if self.dirty_worktrees > 0 {
+        if stats || self.dirty_worktrees > 0 {
             parts.push(format!("{} with changes", self.dirty_worktrees));
         }
 
-        if self.ahead_items > 0 {
+        if stats {
+            parts.push(format!("{} with conflicts", self.conflicted_worktrees));
+        }
+
+        if stats || self.ahead_items > 0 {
             parts.push(format!("{} ahead", self.ahead_items));
         }
 
@@ -325,13 +491,14 @@ pub(crate) fn format_summary_message(
     items: &[ListItem],
     show_branches: bool,
     hidden_column_count: usize,
+    stats: bool,
     error_count: usize,
     timed_out_count: usize,
 ) -> String {
     let metrics = SummaryMetrics::from_items(items);
     let dim = Style::new().dimmed();
     let summary = metrics
-        .summary_parts(show_branches, hidden_column_count)
+        .summary_parts(show_branches, hidden_column_count, stats)
         .join(", ");
 
     if error_count > 0 {
@@ -365,6 +532,7 @@ mod tests {
         assert_eq!(metrics.local_branches, 0);
         assert_eq!(metrics.remote_branches, 0);
         assert_eq!(metrics.dirty_worktrees, 0);
+        assert_eq!(metrics.conflicted_worktrees, 0);
         assert_eq!(metrics.ahead_items, 0);
     }
 
@@ -375,9 +543,10 @@ mod tests {
             local_branches: 0,
             remote_branches: 0,
             dirty_worktrees: 0,
+            conflicted_worktrees: 0,
             ahead_items: 0,
         };
-        let parts = metrics.summary_parts(false, 0);
+        let parts = metrics.summary_parts(false, 0, false);
         assert_eq!(parts, vec!["1 worktree"]);
     }
 
@@ -388,9 +557,10 @@ mod tests {
             local_branches: 0,
             remote_branches: 0,
             dirty_worktrees: 0,
+            conflicted_worktrees: 0,
             ahead_items: 0,
         };
-        let parts = metrics.summary_parts(false, 0);
+        let parts = metrics.summary_parts(false, 0, false);
         assert_eq!(parts, vec!["3 worktrees"]);
     }
 
@@ -401,9 +571,10 @@ mod tests {
             local_branches: 5,
             remote_branches: 10,
             dirty_worktrees: 0,
+            conflicted_worktrees: 0,
             ahead_items: 0,
         };
-        let parts = metrics.summary_parts(true, 0);
+        let parts = metrics.summary_parts(true, 0, false);
         assert_eq!(
             parts,
             vec!["2 worktrees", "5 branches", "10 remote branches"]
@@ -417,9 +588,10 @@ mod tests {
             local_branches: 0,
             remote_branches: 0,
             dirty_worktrees: 2,
+            conflicted_worktrees: 0,
             ahead_items: 0,
         };
-        let parts = metrics.summary_parts(false, 0);
+        let parts = metrics.summary_parts(false, 0, false);
         assert_eq!(parts, vec!["3 worktrees", "2 with changes"]);
     }
 
@@ -430,9 +602,10 @@ mod tests {
             local_branches: 0,
             remote_branches: 0,
             dirty_worktrees: 0,
+            conflicted_worktrees: 0,
             ahead_items: 1,
         };
-        let parts = metrics.summary_parts(false, 0);
+        let parts = metrics.summary_parts(false, 0, false);
         assert_eq!(parts, vec!["2 worktrees", "1 ahead"]);
     }
 
@@ -443,12 +616,13 @@ mod tests {
             local_branches: 0,
             remote_branches: 0,
             dirty_worktrees: 0,
+            conflicted_worktrees: 0,
             ahead_items: 0,
         };
-        let parts = metrics.summary_parts(false, 1);
+        let parts = metrics.summary_parts(false, 1, false);
         assert_eq!(parts, vec!["1 worktree", "1 column hidden"]);
 
-        let parts = metrics.summary_parts(false, 3);
+        let parts = metrics.summary_parts(false, 3, false);
         assert_eq!(parts, vec!["1 worktree", "3 columns hidden"]);
     }
 
@@ -459,9 +633,10 @@ mod tests {
             local_branches: 0,
             remote_branches: 5,
             dirty_worktrees: 0,
+            conflicted_worktrees: 0,
             ahead_items: 0,
         };
-        let parts = metrics.summary_parts(true, 0);
+        let parts = metrics.summary_parts(true, 0, false);
         assert_eq!(parts, vec!["2 worktrees", "5 remote branches"]);
     }
 
@@ -472,9 +647,10 @@ mod tests {
             local_branches: 3,
             remote_branches: 8,
             dirty_worktrees: 2,
+            conflicted_worktrees: 1,
             ahead_items: 4,
         };
-        let parts = metrics.summary_parts(true, 2);
+        let parts = metrics.summary_parts(true, 2, false);
         assert_eq!(
             parts,
             vec![
@@ -488,9 +664,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_summary_metrics_summary_parts_stats() {
+        let metrics = SummaryMetrics {
+            worktrees: 3,
+            local_branches: 0,
+            remote_branches: 0,
+            dirty_worktrees: 0,
+            conflicted_worktrees: 1,
+            ahead_items: 2,
+        };
+        // Without --stats, conflicts aren't counted and zero counts are skipped
+        let parts = metrics.summary_parts(false, 0, false);
+        assert_eq!(parts, vec!["3 worktrees", "2 ahead"]);
+        let parts = metrics.summary_parts(false, 0, true);
+        assert_eq!(
+            parts,
+            vec![
+                "3 worktrees",
+                "0 with changes",
+                "1 with conflicts",
+                "2 ahead"
+            ]
+        );
+    }
+
     #[test]
     fn test_format_summary_message_no_errors() {
-        let msg = format_summary_message(&[], false, 0, 0, 0);
+        let msg = format_summary_message(&[], false, 0, false, 0, 0);
         assert!(msg.contains("Showing 0 worktrees"));
         assert!(!msg.contains("failed"));
         assert!(!msg.contains("timed out"));
@@ -499,7 +700,7 @@ mod tests {
     #[test]
     fn test_format_summary_message_all_timeouts() {
         // 3 errors, all timeouts
-        let msg = format_summary_message(&[], false, 0, 3, 3);
+        let msg = format_summary_message(&[], false, 0, false, 3, 3);
         assert!(msg.contains("3 tasks timed out"));
         assert!(!msg.contains("failed"));
     }
@@ -507,27 +708,56 @@ mod tests {
     #[test]
     fn test_format_summary_message_mixed_errors() {
         // 5 errors, 3 are timeouts
-        let msg = format_summary_message(&[], false, 0, 5, 3);
+        let msg = format_summary_message(&[], false, 0, false, 5, 3);
         assert!(msg.contains("5 tasks failed (3 timed out)"));
     }
 
     #[test]
     fn test_format_summary_message_no_timeouts() {
         // 2 errors, none are timeouts
-        let msg = format_summary_message(&[], false, 0, 2, 0);
+        let msg = format_summary_message(&[], false, 0, false, 2, 0);
         assert!(msg.contains("2 tasks failed"));
         assert!(!msg.contains("timed out"));
     }
 
     #[test]
     fn test_format_summary_message_single_error() {
-        let msg = format_summary_message(&[], false, 0, 1, 0);
+        let msg = format_summary_message(&[], false, 0, false, 1, 0);
         assert!(msg.contains("1 task failed"));
     }
 
     #[test]
     fn test_format_summary_message_single_timeout() {
-        let msg = format_summary_message(&[], false, 0, 1, 1);
+        let msg = format_summary_message(&[], false, 0, false, 1, 1);
         assert!(msg.contains("1 task timed out"));
     }
 }
+
+/// Write git's commit-graph in the background if the repo doesn't have one.
+///
+/// Runs at most once per repo (recorded in git config), so a repo where the
+/// write fails or gets deleted isn't retried on every `wt list`. Best-effort:
+/// failures are logged and never block listing.
+fn spawn_commit_graph_write(repo: &Repository) {
+    if repo.has_commit_graph() || repo.commit_graph_maintenance_recorded() {
+        return;
+    }
+    let branch = repo
+        .current_branch()
+        .unwrap_or_else(|| "detached".to_string());
+    let result = crate::commands::process::spawn_detached(
+        repo,
+        repo.repo_path(),
+        "git commit-graph write --reachable",
+        &branch,
+        &crate::commands::process::HookLog::internal(
+            crate::commands::process::InternalOp::CommitGraph,
+        ),
+        None,
+        &[],
+    )
+    .and_then(|_| repo.record_commit_graph_maintenance());
+    if let Err(e) = result {
+        log::debug!("Background commit-graph write failed to start: {e:#}");
+    }
+}
@@ -92,6 +92,10 @@ pub struct WorktreeData {
     /// Only true when: has branch name, not main worktree, and path differs from template.
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     pub branch_worktree_mismatch: bool,
+    /// Whether a running process has its working directory inside the worktree.
+    /// Only checked with `--active-only`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub working_diff_display: Option<String>,
 }
@@ -102,6 +106,11 @@ impl WorktreeData {
         self.prunable.is_some()
     }
 
+    /// Marker appended to the Path column when the worktree directory is missing.
+    pub fn missing_marker(&self) -> Option<&'static str> {
+        self.is_prunable().then_some("⚠ (missing)")
+    }
+
     /// Create WorktreeData from a WorktreeInfo, with all computed fields set to None.
     pub(crate) fn from_worktree(
         wt: &worktrunk::git::WorktreeInfo,
@@ -299,7 +308,7 @@ impl ListItem {
     ///    don't diverge from the default branch.
     pub(crate) fn is_potentially_removable(&self) -> Option<bool> {
         // Use already-computed status_symbols if available
-        let main_state = self.status_symbols.as_ref()?.main_state;
+        let main_state = self.status_symbols.as_ref()?.main_state();
         // SameCommit excluded: has uncommitted work that would be lost
         Some(matches!(
             main_state,
@@ -477,15 +486,17 @@ impl ListItem {
                     WorktreeState::None
                 };
 
-                // Operation state - priority: conflicts > rebase > merge
+                // Operation state - priority: conflicts > rebase > merge > cherry-pick > bisect
                 let operation_state = if has_conflicts {
                     OperationState::Conflicts
-                } else if data.git_operation == ActiveGitOperation::Rebase {
-                    OperationState::Rebase
-                } else if data.git_operation == ActiveGitOperation::Merge {
-                    OperationState::Merge
                 } else {
-                    OperationState::None
+                    match data.git_operation {
+                        ActiveGitOperation::Rebase => OperationState::Rebase,
+                        ActiveGitOperation::Merge => OperationState::Merge,
+                        ActiveGitOperation::CherryPick => OperationState::CherryPick,
+                        ActiveGitOperation::Bisect => OperationState::Bisect,
+                        ActiveGitOperation::None => OperationState::None,
+                    }
                 };
 
                 // Check if content is integrated into main (safe to delete)
@@ -28,6 +28,8 @@ pub use state::{ActiveGitOperation, Divergence, MainState, OperationState, Workt
 #[allow(unused_imports)]
 pub use stats::{ActiveUpstream, AheadBehind, BranchDiffTotals, CommitDetails, UpstreamStatus};
 #[allow(unused_imports)]
-pub use status_symbols::{PositionMask, StatusSymbols, WorkingTreeStatus};
+pub use status_symbols::{
+    PositionMask, StatusGroup, StatusSymbols, WorkingTreeStatus, status_legend,
+};
 #[allow(unused_imports)]
 pub use statusline_segment::StatuslineSegment;
@@ -257,7 +257,7 @@ impl serde::Serialize for MainState {
 /// Represents blocking git operations in progress that require resolution.
 /// These take priority over all other states in the Worktree column.
 ///
-/// Priority: Conflicts (✘) > Rebase (⤴) > Merge (⤵)
+/// Priority: Conflicts (✘) > Rebase (⤴) > Merge (⤵) > CherryPick (⤷) > Bisect (÷)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum::IntoStaticStr)]
 #[strum(serialize_all = "snake_case")]
 pub enum OperationState {
@@ -271,6 +271,10 @@ pub enum OperationState {
     Rebase,
     /// Merge in progress
     Merge,
+    /// Cherry-pick in progress
+    CherryPick,
+    /// Bisect in progress
+    Bisect,
 }
 
 impl std::fmt::Display for OperationState {
@@ -280,6 +284,8 @@ impl std::fmt::Display for OperationState {
             Self::Conflicts => write!(f, "✘"),
             Self::Rebase => write!(f, "⤴"),
             Self::Merge => write!(f, "⤵"),
+            Self::CherryPick => write!(f, "⤷"),
+            Self::Bisect => write!(f, "÷"),
         }
     }
 }
@@ -289,13 +295,15 @@ impl OperationState {
     ///
     /// Color semantics:
     /// - ERROR (red): Conflicts - blocking problems
-    /// - WARNING (yellow): Rebase, Merge - active/stuck states
+    /// - WARNING (yellow): Rebase, Merge, CherryPick, Bisect - active/stuck states
     pub fn styled(&self) -> Option<String> {
         use color_print::cformat;
         match self {
             Self::None => None,
             Self::Conflicts => Some(cformat!("<red>{self}</>")),
-            Self::Rebase | Self::Merge => Some(cformat!("<yellow>{self}</>")),
+            Self::Rebase | Self::Merge | Self::CherryPick | Self::Bisect => {
+                Some(cformat!("<yellow>{self}</>"))
+            }
         }
     }
 
@@ -332,6 +340,10 @@ pub enum ActiveGitOperation {
     Rebase,
     /// Merge in progress (MERGE_HEAD exists)
     Merge,
+    /// Cherry-pick in progress (CHERRY_PICK_HEAD exists)
+    CherryPick,
+    /// Bisect in progress (BISECT_LOG exists)
+    Bisect,
 }
 
 impl ActiveGitOperation {
@@ -635,6 +647,8 @@ mod tests {
         assert_eq!(format!("{}", OperationState::Conflicts), "✘");
         assert_eq!(format!("{}", OperationState::Rebase), "⤴");
         assert_eq!(format!("{}", OperationState::Merge), "⤵");
+        assert_eq!(format!("{}", OperationState::CherryPick), "⤷");
+        assert_eq!(format!("{}", OperationState::Bisect), "÷");
     }
 
     #[test]
@@ -646,12 +660,18 @@ mod tests {
         let styled = OperationState::Conflicts.styled().unwrap();
         assert!(styled.contains("✘"));
 
-        // Rebase and Merge are yellow
+        // Rebase, Merge, CherryPick, and Bisect are yellow
         let styled = OperationState::Rebase.styled().unwrap();
         assert!(styled.contains("⤴"));
 
         let styled = OperationState::Merge.styled().unwrap();
         assert!(styled.contains("⤵"));
+
+        let styled = OperationState::CherryPick.styled().unwrap();
+        assert!(styled.contains("⤷"));
+
+        let styled = OperationState::Bisect.styled().unwrap();
+        assert!(styled.contains("÷"));
     }
 
     #[test]
@@ -669,6 +689,11 @@ mod tests {
         assert_eq!(OperationState::Conflicts.as_json_str(), Some("conflicts"));
         assert_eq!(OperationState::Rebase.as_json_str(), Some("rebase"));
         assert_eq!(OperationState::Merge.as_json_str(), Some("merge"));
+        assert_eq!(
+            OperationState::CherryPick.as_json_str(),
+            Some("cherry_pick")
+        );
+        assert_eq!(OperationState::Bisect.as_json_str(), Some("bisect"));
     }
 
     // ============================================================================
@@ -686,5 +711,7 @@ mod tests {
         assert!(ActiveGitOperation::None.is_none());
         assert!(!ActiveGitOperation::Rebase.is_none());
         assert!(!ActiveGitOperation::Merge.is_none());
+        assert!(!ActiveGitOperation::CherryPick.is_none());
+        assert!(!ActiveGitOperation::Bisect.is_none());
     }
 }
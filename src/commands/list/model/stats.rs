@@ -10,6 +10,22 @@ use worktrunk::git::LineDiff;
 pub struct CommitDetails {
     pub timestamp: i64,
     pub commit_message: String,
+    pub author_name: String,
+    pub author_email: String,
+}
+
+impl CommitDetails {
+    /// Author initials for the Author column (e.g., "Jane Q. Doe" → "JD").
+    pub fn author_initials(&self) -> String {
+        let mut words = self.author_name.split_whitespace();
+        let first = words.next().and_then(|w| w.chars().next());
+        let last = words.next_back().and_then(|w| w.chars().next());
+        first
+            .into_iter()
+            .chain(last)
+            .flat_map(char::to_uppercase)
+            .collect()
+    }
 }
 
 /// Ahead/behind counts relative to a base branch.
@@ -35,6 +51,9 @@ pub struct UpstreamStatus {
     pub(crate) ahead: usize,
     #[serde(rename = "upstream_behind")]
     pub(crate) behind: usize,
+    /// When the remote-tracking ref was last refreshed (epoch seconds), for `--since-fetch`
+    #[serde(skip)]
+    pub(crate) fetched_at: Option<u64>,
 }
 
 /// Active upstream tracking information (when a remote is configured).
@@ -53,6 +72,15 @@ impl UpstreamStatus {
             behind: self.behind,
         })
     }
+
+    /// Whether the remote-tracking ref was last refreshed more than `threshold` ago.
+    ///
+    /// Unknown refresh times never count as stale.
+    pub fn fetched_before(&self, threshold: std::time::Duration) -> bool {
+        self.fetched_at.is_some_and(|fetched_at| {
+            worktrunk::utils::get_now().saturating_sub(fetched_at) > threshold.as_secs()
+        })
+    }
 }
 
 #[cfg(test)]
@@ -77,6 +105,22 @@ mod tests {
         let details = CommitDetails::default();
         assert_eq!(details.timestamp, 0);
         assert_eq!(details.commit_message, "");
+        assert_eq!(details.author_initials(), "");
+    }
+
+    #[test]
+    fn test_commit_details_author_initials() {
+        let initials = |name: &str| {
+            CommitDetails {
+                author_name: name.to_string(),
+                ..Default::default()
+            }
+            .author_initials()
+        };
+        assert_eq!(initials("Test User"), "TU");
+        assert_eq!(initials("Jane Q. Doe"), "JD");
+        assert_eq!(initials("bot"), "B");
+        assert_eq!(initials("  "), "");
     }
 
     #[test]
@@ -85,6 +129,7 @@ mod tests {
             remote: Some("origin".to_string()),
             ahead: 3,
             behind: 2,
+            ..Default::default()
         };
         let active = status.active().unwrap();
         assert_eq!(active.remote, "origin");
@@ -98,7 +143,22 @@ mod tests {
             remote: None,
             ahead: 0,
             behind: 0,
+            ..Default::default()
         };
         assert!(status.active().is_none());
     }
+
+    #[test]
+    fn test_upstream_status_fetched_before() {
+        let day = std::time::Duration::from_secs(86_400);
+        let now = worktrunk::utils::get_now();
+        let fetched = |fetched_at| UpstreamStatus {
+            remote: Some("origin".to_string()),
+            fetched_at,
+            ..Default::default()
+        };
+        assert!(fetched(Some(now - 2 * 86_400)).fetched_before(day));
+        assert!(!fetched(Some(now - 60)).fetched_before(day));
+        assert!(!fetched(None).fetched_before(day));
+    }
 }
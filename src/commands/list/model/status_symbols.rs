@@ -25,9 +25,9 @@ impl PositionMask {
     // Render order indices (0-6) - symbols appear in this order left-to-right
     // Working tree split into 3 fixed positions for vertical alignment
     pub(crate) const STAGED: usize = 0; // + (staged changes)
-    pub(crate) const MODIFIED: usize = 1; // ! (modified files)
+    pub(crate) const MODIFIED: usize = 1; // ! (modified files), § (submodule changes)
     pub(crate) const UNTRACKED: usize = 2; // ? (untracked files)
-    pub(crate) const WORKTREE_STATE: usize = 3; // Worktree: ✘⤴⤵/⚑⊟⊞
+    pub(crate) const WORKTREE_STATE: usize = 3; // Worktree: ✘⤴⤵⤷÷/⚑⊟⊞
     pub(crate) const MAIN_STATE: usize = 4; // Main relationship: ^✗_⊂↕↑↓
     pub(crate) const UPSTREAM_DIVERGENCE: usize = 5; // Remote: |⇅⇡⇣
     pub(crate) const USER_MARKER: usize = 6;
@@ -39,7 +39,7 @@ impl PositionMask {
             1, // STAGED: + (1 char)
             1, // MODIFIED: ! (1 char)
             1, // UNTRACKED: ? (1 char)
-            1, // WORKTREE_STATE: ✘⤴⤵/⚑⊟⊞ (1 char, priority: conflicts > rebase > merge > branch_worktree_mismatch > prunable > locked > branch)
+            1, // WORKTREE_STATE: ✘⤴⤵⤷÷/⚑⊟⊞ (1 char, priority: conflicts > rebase > merge > cherry_pick > bisect > branch_worktree_mismatch > prunable > locked > branch)
             1, // MAIN_STATE: ^✗_–⊂↕↑↓ (1 char, priority: is_main > would_conflict > empty > same_commit > integrated > diverged > ahead > behind)
             1, // UPSTREAM_DIVERGENCE: |⇡⇣⇅ (1 char)
             2, // USER_MARKER: single emoji or two chars (allocate 2)
@@ -62,9 +62,18 @@ pub struct WorkingTreeStatus {
     pub untracked: bool,
     pub renamed: bool,
     pub deleted: bool,
+    /// Submodules with new commits, modified content, or untracked files
+    pub submodule: bool,
 }
 
 impl WorkingTreeStatus {
+    pub(crate) const STAGED_SYMBOL: char = '+';
+    pub(crate) const MODIFIED_SYMBOL: char = '!';
+    pub(crate) const UNTRACKED_SYMBOL: char = '?';
+    pub(crate) const SUBMODULE_SYMBOL: char = '§';
+    pub(crate) const RENAMED_SYMBOL: char = '»';
+    pub(crate) const DELETED_SYMBOL: char = '✘';
+
     /// Create from git status parsing results
     pub fn new(
         staged: bool,
@@ -79,12 +88,18 @@ impl WorkingTreeStatus {
             untracked,
             renamed,
             deleted,
+            submodule: false,
         }
     }
 
     /// Returns true if any changes are present
     pub fn is_dirty(&self) -> bool {
-        self.staged || self.modified || self.untracked || self.renamed || self.deleted
+        self.staged
+            || self.modified
+            || self.untracked
+            || self.renamed
+            || self.deleted
+            || self.submodule
     }
 
     /// Format as display string for JSON serialization and raw output (e.g., "+!?").
@@ -93,30 +108,135 @@ impl WorkingTreeStatus {
     pub fn to_symbols(self) -> String {
         let mut s = String::with_capacity(5);
         if self.staged {
-            s.push('+');
+            s.push(Self::STAGED_SYMBOL);
         }
         if self.modified {
-            s.push('!');
+            s.push(Self::MODIFIED_SYMBOL);
+        }
+        if self.submodule {
+            s.push(Self::SUBMODULE_SYMBOL);
         }
         if self.untracked {
-            s.push('?');
+            s.push(Self::UNTRACKED_SYMBOL);
         }
         if self.renamed {
-            s.push('»');
+            s.push(Self::RENAMED_SYMBOL);
         }
         if self.deleted {
-            s.push('✘');
+            s.push(Self::DELETED_SYMBOL);
         }
         s
     }
 }
 
+/// One position category in the status legend, with symbols in priority order.
+pub struct LegendGroup {
+    pub name: &'static str,
+    pub entries: Vec<(String, &'static str)>,
+}
+
+/// Status symbol key for `wt list --legend`.
+///
+/// Symbols come from the same `Display` impls and constants used by
+/// [`StatusSymbols::render_with_mask`], so the legend can't drift from the table.
+pub fn status_legend() -> Vec<LegendGroup> {
+    fn entry(symbol: impl ToString, meaning: &'static str) -> (String, &'static str) {
+        (symbol.to_string(), meaning)
+    }
+
+    vec![
+        LegendGroup {
+            name: "Working tree",
+            entries: vec![
+                entry(WorkingTreeStatus::STAGED_SYMBOL, "staged"),
+                entry(WorkingTreeStatus::MODIFIED_SYMBOL, "modified"),
+                entry(WorkingTreeStatus::SUBMODULE_SYMBOL, "submodule changes"),
+                entry(WorkingTreeStatus::UNTRACKED_SYMBOL, "untracked"),
+                entry(WorkingTreeStatus::RENAMED_SYMBOL, "renamed (JSON only)"),
+                entry(WorkingTreeStatus::DELETED_SYMBOL, "deleted (JSON only)"),
+            ],
+        },
+        LegendGroup {
+            name: "Worktree",
+            entries: vec![
+                entry(OperationState::Conflicts, "conflicts"),
+                entry(OperationState::Rebase, "rebase"),
+                entry(OperationState::Merge, "merge"),
+                entry(OperationState::CherryPick, "cherry-pick"),
+                entry(OperationState::Bisect, "bisect"),
+                entry(WorktreeState::BranchWorktreeMismatch, "path mismatch"),
+                entry(WorktreeState::Prunable, "prunable"),
+                entry(WorktreeState::Locked, "locked"),
+                entry(WorktreeState::Branch, "no worktree"),
+            ],
+        },
+        LegendGroup {
+            name: "Default branch",
+            entries: vec![
+                entry(MainState::IsMain, "is default"),
+                entry(MainState::Orphan, "orphan"),
+                entry(MainState::WouldConflict, "would conflict"),
+                entry(MainState::Empty, "same commit"),
+                entry(MainState::SameCommit, "same commit, dirty"),
+                entry(
+                    MainState::Integrated(worktrunk::git::IntegrationReason::Ancestor),
+                    "integrated",
+                ),
+                entry(MainState::Diverged, "diverged"),
+                entry(MainState::Ahead, "ahead"),
+                entry(MainState::Behind, "behind"),
+            ],
+        },
+        LegendGroup {
+            name: "Remote",
+            entries: vec![
+                entry(Divergence::InSync.symbol(), "in sync"),
+                entry(Divergence::Diverged.symbol(), "diverged"),
+                entry(Divergence::Ahead.symbol(), "ahead"),
+                entry(Divergence::Behind.symbol(), "behind"),
+            ],
+        },
+        LegendGroup {
+            name: "User",
+            // Markers are free text; show the example from `wt config state marker --help`
+            entries: vec![entry("🚧", "marker (wt config state marker)")],
+        },
+    ]
+}
+
+/// Dominant status of an item, for `wt list --group-by status`.
+///
+/// Variants are in display order: the groups needing attention come first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StatusGroup {
+    /// Conflicts in the working tree, or would conflict with the default branch
+    Conflicts,
+    /// Uncommitted changes
+    Dirty,
+    /// Missing commits from the default branch (behind or diverged)
+    Behind,
+    /// Everything else
+    Clean,
+}
+
+impl StatusGroup {
+    /// Header shown above the group's rows.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Conflicts => "Conflicts",
+            Self::Dirty => "Dirty",
+            Self::Behind => "Behind main",
+            Self::Clean => "Clean",
+        }
+    }
+}
+
 /// Structured status symbols for aligned rendering
 ///
 /// Symbols are categorized to enable vertical alignment in table output.
 /// Display order (left to right):
-/// - Working tree: +, !, ? (staged, modified, untracked - NOT mutually exclusive)
-/// - Worktree state: ✘, ⤴, ⤵, /, ⚑, ⊟, ⊞ (operations + location)
+/// - Working tree: +, ! or §, ? (staged, modified or submodule changes, untracked - NOT mutually exclusive)
+/// - Worktree state: ✘, ⤴, ⤵, ⤷, ÷, /, ⚑, ⊟, ⊞ (operations + location)
 /// - Main state: ^, ✗, _, ⊂, ↕, ↑, ↓ (relationship to default branch - single-stroke vertical arrows)
 /// - Upstream divergence: |, ⇅, ⇡, ⇣ (relationship to remote - vertical arrows)
 /// - User marker: custom labels, emoji
@@ -124,10 +244,12 @@ impl WorkingTreeStatus {
 /// ## Mutual Exclusivity
 ///
 /// **Worktree state (operations take priority over location):**
-/// Priority: ✘ > ⤴ > ⤵ > ⚑ > ⊟ > ⊞ > /
+/// Priority: ✘ > ⤴ > ⤵ > ⤷ > ÷ > ⚑ > ⊟ > ⊞ > /
 /// - ✘: Actual conflicts (must resolve)
 /// - ⤴: Rebase in progress
 /// - ⤵: Merge in progress
+/// - ⤷: Cherry-pick in progress
+/// - ÷: Bisect in progress
 /// - ⚑: Branch-worktree mismatch
 /// - ⊟: Prunable (directory missing)
 /// - ⊞: Locked worktree
@@ -159,7 +281,7 @@ pub struct StatusSymbols {
     pub(crate) main_state: MainState,
 
     /// Worktree operation and location state (single position)
-    /// Operations (✘⤴⤵) take priority over location states (/⚑⊟⊞)
+    /// Operations (✘⤴⤵⤷÷) take priority over location states (/⚑⊟⊞)
     pub(crate) operation_state: OperationState,
 
     /// Worktree location state: / for branches, ⚑⊟⊞ for worktrees
@@ -216,6 +338,19 @@ impl StatusSymbols {
         result
     }
 
+    /// Dominant status, for grouping. Conflicts outrank dirty, which outranks behind.
+    pub fn group(&self) -> StatusGroup {
+        if self.has_conflicts() || self.main_state == MainState::WouldConflict {
+            StatusGroup::Conflicts
+        } else if self.working_tree.is_dirty() {
+            StatusGroup::Dirty
+        } else if matches!(self.main_state, MainState::Behind | MainState::Diverged) {
+            StatusGroup::Behind
+        } else {
+            StatusGroup::Clean
+        }
+    }
+
     /// Check if symbols are empty
     pub fn is_empty(&self) -> bool {
         self.main_state == MainState::None
@@ -226,6 +361,41 @@ impl StatusSymbols {
             && self.user_marker.is_none()
     }
 
+    /// Relationship to the default branch (`^✗_–⊂↕↑↓`)
+    pub fn main_state(&self) -> MainState {
+        self.main_state
+    }
+
+    /// Blocking git operation in progress (`✘⤴⤵⤷÷`)
+    pub fn operation_state(&self) -> OperationState {
+        self.operation_state
+    }
+
+    /// Whether the working tree has unresolved merge conflicts
+    pub fn has_conflicts(&self) -> bool {
+        self.operation_state == OperationState::Conflicts
+    }
+
+    /// Worktree location state (`/⚑⊟⊞`)
+    pub fn worktree_state(&self) -> WorktreeState {
+        self.worktree_state
+    }
+
+    /// Divergence from the upstream branch (`|⇅⇡⇣`)
+    pub fn upstream_divergence(&self) -> Divergence {
+        self.upstream_divergence
+    }
+
+    /// Uncommitted working tree changes (`+!§?`)
+    pub fn working_tree(&self) -> WorkingTreeStatus {
+        self.working_tree
+    }
+
+    /// User-defined status marker, if any
+    pub fn user_marker(&self) -> Option<&str> {
+        self.user_marker.as_deref()
+    }
+
     /// Render status symbols in compact form for statusline (no grid alignment).
     ///
     /// Uses the same styled symbols as `render_with_mask()`, just without padding.
@@ -258,9 +428,21 @@ impl StatusSymbols {
                 (String::new(), false)
             }
         };
-        let (staged_str, has_staged) = style_working(self.working_tree.staged, '+');
-        let (modified_str, has_modified) = style_working(self.working_tree.modified, '!');
-        let (untracked_str, has_untracked) = style_working(self.working_tree.untracked, '?');
+        let (staged_str, has_staged) =
+            style_working(self.working_tree.staged, WorkingTreeStatus::STAGED_SYMBOL);
+        // Submodule changes share the modified position; modified files take priority
+        let (modified_str, has_modified) = if self.working_tree.modified {
+            style_working(true, WorkingTreeStatus::MODIFIED_SYMBOL)
+        } else {
+            style_working(
+                self.working_tree.submodule,
+                WorkingTreeStatus::SUBMODULE_SYMBOL,
+            )
+        };
+        let (untracked_str, has_untracked) = style_working(
+            self.working_tree.untracked,
+            WorkingTreeStatus::UNTRACKED_SYMBOL,
+        );
 
         // Main state (merged column: ^✗_⊂↕↑↓)
         let (main_state_str, has_main_state) = self
@@ -274,7 +456,7 @@ impl StatusSymbols {
             .styled()
             .map_or((String::new(), false), |s| (s, true));
 
-        // Worktree state: operations (✘⤴⤵) take priority over location (/⚑⊟⊞)
+        // Worktree state: operations (✘⤴⤵⤷÷) take priority over location (/⚑⊟⊞)
         let (worktree_str, has_worktree) = if self.operation_state != OperationState::None {
             // Operation state takes priority
             (self.operation_state.styled().unwrap_or_default(), true)
@@ -337,6 +519,34 @@ mod tests {
         assert!(WorkingTreeStatus::new(true, true, true, true, true).is_dirty());
     }
 
+    #[test]
+    fn test_working_tree_status_submodule() {
+        let submodule_only = WorkingTreeStatus {
+            submodule: true,
+            ..Default::default()
+        };
+        assert!(submodule_only.is_dirty());
+        assert_eq!(submodule_only.to_symbols(), "§");
+
+        // Shown in the modified position, unless files are modified too
+        let symbols = StatusSymbols {
+            working_tree: submodule_only,
+            ..Default::default()
+        };
+        assert!(symbols.format_compact().contains('§'));
+        let symbols = StatusSymbols {
+            working_tree: WorkingTreeStatus {
+                modified: true,
+                submodule: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let compact = symbols.format_compact();
+        assert!(compact.contains('!'));
+        assert!(!compact.contains('§'));
+    }
+
     #[test]
     fn test_working_tree_status_to_symbols() {
         // Empty
@@ -379,6 +589,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_status_legend_symbols_unique_within_groups() {
+        for group in status_legend() {
+            let symbols: std::collections::HashSet<&str> =
+                group.entries.iter().map(|(s, _)| s.as_str()).collect();
+            assert_eq!(
+                symbols.len(),
+                group.entries.len(),
+                "duplicate symbol in {}",
+                group.name
+            );
+            assert!(symbols.iter().all(|s| !s.is_empty()));
+        }
+    }
+
+    #[test]
+    fn test_status_legend_covers_working_tree_symbols() {
+        let all = WorkingTreeStatus {
+            submodule: true,
+            ..WorkingTreeStatus::new(true, true, true, true, true)
+        };
+        let legend = status_legend();
+        let working_tree = &legend[0];
+        for symbol in all.to_symbols().chars() {
+            assert!(
+                working_tree
+                    .entries
+                    .iter()
+                    .any(|(s, _)| *s == symbol.to_string()),
+                "legend missing {symbol}"
+            );
+        }
+        assert!(
+            legend.iter().any(|g| g.name == "User"),
+            "legend missing the user marker"
+        );
+    }
+
     #[test]
     fn test_status_symbols_is_empty() {
         let symbols = StatusSymbols::default();
@@ -459,6 +707,28 @@ mod tests {
         assert!(rendered.contains("↑"));
     }
 
+    #[test]
+    fn test_status_symbols_accessors() {
+        let symbols = StatusSymbols {
+            main_state: MainState::Behind,
+            operation_state: OperationState::Conflicts,
+            upstream_divergence: Divergence::Ahead,
+            working_tree: WorkingTreeStatus {
+                modified: true,
+                ..Default::default()
+            },
+            user_marker: Some("🤖".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(symbols.main_state(), MainState::Behind);
+        assert_eq!(symbols.operation_state(), OperationState::Conflicts);
+        assert!(symbols.has_conflicts());
+        assert_eq!(symbols.worktree_state(), WorktreeState::None);
+        assert_eq!(symbols.upstream_divergence(), Divergence::Ahead);
+        assert!(symbols.working_tree().modified);
+        assert_eq!(symbols.user_marker(), Some("🤖"));
+    }
+
     #[test]
     fn test_position_mask_width() {
         let mask = PositionMask::FULL;
@@ -1,13 +1,18 @@
-use crate::display::{format_relative_time_short, shorten_path, truncate_to_width};
+use crate::display::{format_list_path, format_relative_time, truncate_to_width};
+use ansi_str::AnsiStr;
 use anstyle::Style;
-use std::path::Path;
 use unicode_width::UnicodeWidthStr;
-use worktrunk::styling::{Stream, StyledLine, hyperlink_stdout, supports_hyperlinks};
+use worktrunk::styling::{
+    INFO_SYMBOL, Stream, StyledLine, hyperlink_stdout, strip_osc8_hyperlinks, supports_hyperlinks,
+    theme,
+};
 
 use super::collect::parse_port_from_url;
 use super::columns::{ColumnKind, DiffVariant};
-use super::layout::{ColumnFormat, ColumnLayout, DiffColumnConfig, LayoutConfig};
-use super::model::{ListItem, PositionMask};
+use super::layout::{
+    ColumnFormat, ColumnLayout, DiffColumnConfig, FETCH_STALE_GLYPH, LayoutConfig,
+};
+use super::model::ListItem;
 
 impl DiffColumnConfig {
     /// Check if a value exceeds the allocated digit width
@@ -161,6 +166,48 @@ impl DiffColumnConfig {
     }
 }
 
+/// ASCII stand-in for a glyph used in the table, for `--plain` output.
+fn ascii_glyph(c: char) -> Option<&'static str> {
+    Some(match c {
+        '↑' | '⇡' => "^",
+        '↓' | '⇣' => "v",
+        '↕' | '⇅' => "~",
+        '±' => "+-",
+        '✗' => "x",
+        '✘' => "X",
+        '⤴' => "R",
+        '⤵' => "M",
+        '⤷' => "C",
+        '÷' => "B",
+        '⊂' => "c",
+        '∅' => "0",
+        '–' => "-",
+        '⚑' => "W",
+        '⊟' => "P",
+        '⊞' => "L",
+        '§' => "S",
+        '⋯' | '…' => "...",
+        '·' => ".",
+        '◷' => "T",
+        _ => return None,
+    })
+}
+
+/// Strip styling and hyperlinks, and replace worktrunk's glyphs, for `--plain` output.
+///
+/// Other characters (e.g. an emoji user marker) pass through unchanged.
+pub(crate) fn to_plain_ascii(rendered: &str) -> String {
+    let text = strip_osc8_hyperlinks(rendered);
+    let mut out = String::with_capacity(text.len());
+    for c in text.ansi_strip().chars() {
+        match ascii_glyph(c) {
+            Some(glyph) => out.push_str(glyph),
+            None => out.push(c),
+        }
+    }
+    out
+}
+
 impl LayoutConfig {
     fn render_line<F>(&self, mut render_cell: F) -> StyledLine
     where
@@ -171,6 +218,57 @@ impl LayoutConfig {
             return line;
         }
 
+        if self.markdown {
+            let cells: Vec<String> = self
+                .columns
+                .iter()
+                .map(|column| {
+                    let text = strip_osc8_hyperlinks(&render_cell(column).render());
+                    let separator = if column.kind == ColumnKind::Status {
+                        ""
+                    } else {
+                        " "
+                    };
+                    text.ansi_strip()
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(separator)
+                        .replace('|', "\\|")
+                })
+                .collect();
+            line.push_raw(format!("| {} |", cells.join(" | ")));
+            return line;
+        }
+
+        if self.plain {
+            let cells: Vec<String> = self
+                .columns
+                .iter()
+                .map(|column| {
+                    let rendered = render_cell(column).render();
+                    let text = if column.kind.is_user_text() {
+                        strip_osc8_hyperlinks(&rendered).ansi_strip().into_owned()
+                    } else {
+                        to_plain_ascii(&rendered)
+                    };
+                    // Status symbols keep their meaning without position padding
+                    let separator = if column.kind == ColumnKind::Status {
+                        ""
+                    } else {
+                        " "
+                    };
+                    let text = text.split_whitespace().collect::<Vec<_>>().join(separator);
+                    if text.is_empty() {
+                        "-".to_string()
+                    } else {
+                        text
+                    }
+                })
+                .collect();
+            line.push_raw(cells.join(" "));
+            return line;
+        }
+
         let last_index = self.columns.len() - 1;
 
         for (index, column) in self.columns.iter().enumerate() {
@@ -204,7 +302,20 @@ impl LayoutConfig {
     }
 
     pub fn format_header_line(&self) -> String {
-        self.render_header_line().render()
+        let header = self.render_header_line().render();
+        if !self.markdown {
+            return header;
+        }
+        // Diff columns are right-aligned in the table view, so keep that in markdown
+        let delimiters: Vec<&str> = self
+            .columns
+            .iter()
+            .map(|column| match column.format {
+                ColumnFormat::Diff(_) => "---:",
+                _ => "---",
+            })
+            .collect();
+        format!("{header}\n| {} |", delimiters.join(" | "))
     }
 
     /// Render header line as StyledLine (for extracting both plain and styled text)
@@ -232,19 +343,29 @@ impl LayoutConfig {
     }
 
     pub fn format_list_item_line(&self, item: &ListItem) -> String {
-        self.render_list_item_line(item).render()
+        if self.plain || self.markdown {
+            return self.render_list_item_line(item).render();
+        }
+        // Layout fits columns to the terminal, but a single cell wider than its
+        // budget can still push the row past the edge; clip as a last resort.
+        self.render_list_item_line(item)
+            .truncate_to_width(self.max_width)
+            .render()
+    }
+
+    /// Format the summary shown below the table, without the info symbol for `--plain`.
+    pub fn format_summary_line(&self, summary: &str) -> String {
+        if !self.plain {
+            return summary.to_string();
+        }
+        let summary = summary.ansi_strip();
+        let symbol = INFO_SYMBOL.ansi_strip();
+        to_plain_ascii(summary.trim_start_matches(&*symbol).trim_start())
     }
 
     /// Render list item line as StyledLine (for extracting both plain and styled text)
     pub fn render_list_item_line(&self, item: &ListItem) -> StyledLine {
-        self.render_line(|column| {
-            column.render_cell(
-                item,
-                &self.status_position_mask,
-                &self.main_worktree_path,
-                self.max_message_len,
-            )
-        })
+        self.render_line(|column| column.render_cell(item, self))
     }
 
     /// Render a skeleton row showing known data (branch, path) with placeholders for other columns.
@@ -256,10 +377,10 @@ impl LayoutConfig {
         let wt_data = item.worktree_data();
         let shortened_path = item
             .worktree_path()
-            .map(|p| shorten_path(p, &self.main_worktree_path))
+            .map(|p| format_list_path(p, &self.main_worktree_path, self.path_style))
             .unwrap_or_default();
 
-        let dim = Style::new().dimmed();
+        let dim = theme().dimmed;
         let spinner = "⋯"; // Placeholder character
 
         self.render_line(|col| {
@@ -278,12 +399,16 @@ impl LayoutConfig {
                 }
                 ColumnKind::Branch => {
                     // Show actual branch name (no dim - start normal, gray out later if removable)
-                    cell.push_raw(branch.to_string());
+                    cell.push_raw(truncate_to_width(branch, col.width));
                     cell.pad_to(col.width);
                 }
                 ColumnKind::Path => {
                     // Show actual path (no dim - start normal, gray out later if removable)
                     cell.push_raw(&shortened_path);
+                    if let Some(marker) = wt_data.and_then(|d| d.missing_marker()) {
+                        cell.push_raw(" ");
+                        cell.push_styled(marker, dim);
+                    }
                     cell.pad_to(col.width);
                 }
                 ColumnKind::Commit => {
@@ -312,7 +437,7 @@ impl ColumnLayout {
             let padding = self.width.saturating_sub(symbol.width());
             cell.push_raw(" ".repeat(padding));
         }
-        cell.push_styled(symbol, Style::new().dimmed());
+        cell.push_styled(symbol, theme().dimmed);
         cell
     }
 
@@ -337,33 +462,34 @@ impl ColumnLayout {
         config.render_segment(positive, negative)
     }
 
-    fn render_cell(
-        &self,
-        item: &ListItem,
-        status_mask: &PositionMask,
-        main_worktree_path: &Path,
-        max_message_len: usize,
-    ) -> StyledLine {
+    fn render_cell(&self, item: &ListItem, layout: &LayoutConfig) -> StyledLine {
+        let status_mask = &layout.status_position_mask;
+        let main_worktree_path = layout.main_worktree_path.as_path();
+        let path_style = layout.path_style;
+        let time_format = layout.time_format;
+        let upstream_remote_width = layout.upstream_remote_width;
+        let max_message_len = layout.max_message_len;
         // Compute derived values inline (avoids separate context struct)
         let worktree_data = item.worktree_data();
-        let text_style = item.should_dim().then(|| Style::new().dimmed());
+        let text_style = (layout.dim_removable && item.should_dim()).then(|| theme().dimmed);
 
         match self.kind {
             ColumnKind::Gutter => {
                 let mut cell = StyledLine::new();
-                let symbol = if let Some(data) = worktree_data {
+                let (symbol, style) = if let Some(data) = worktree_data {
                     // Priority: @ (current) > ^ (main) > + (regular, including previous)
                     if data.is_current {
-                        "@ " // Current worktree
+                        ("@", theme().current) // Current worktree
                     } else if data.is_main {
-                        "^ " // Main worktree
+                        ("^", theme().primary) // Main worktree
                     } else {
-                        "+ " // Regular worktree (including previous)
+                        ("+", Style::new()) // Regular worktree (including previous)
                     }
                 } else {
-                    "  " // Branch without worktree (two spaces to match width)
+                    (" ", Style::new()) // Branch without worktree (two spaces to match width)
                 };
-                cell.push_raw(symbol.to_string());
+                cell.push_styled(symbol, style);
+                cell.push_raw(" ");
                 cell
             }
             ColumnKind::Branch => {
@@ -410,26 +536,64 @@ impl ColumnLayout {
                 let Some(data) = worktree_data else {
                     return StyledLine::new();
                 };
-                let path_str = shorten_path(&data.path, main_worktree_path);
-                self.render_text_cell(&path_str, text_style)
+                let path_str = format_list_path(&data.path, main_worktree_path, path_style);
+                let Some(marker) = data.missing_marker() else {
+                    return self.render_text_cell(&path_str, text_style);
+                };
+                let mut cell = self.render_text_cell(&path_str, text_style);
+                cell.push_raw(" ");
+                cell.push_styled(marker, theme().dimmed);
+                cell.truncate_to_width(self.width)
             }
             ColumnKind::Upstream => {
                 let upstream = item.upstream();
                 let Some(active) = upstream.active() else {
                     return StyledLine::new();
                 };
+                // `--since-fetch` reserves room for the staleness glyph after the arrows
+                let suffix_width = if layout.since_fetch.is_some() {
+                    FETCH_STALE_GLYPH.width() + 1
+                } else {
+                    0
+                };
+                // Remote name prefix (`origin ⇡2 ⇣1`), left-aligned in its own subcolumn
+                let mut cell = StyledLine::new();
+                let mut arrows_width = self.width.saturating_sub(suffix_width);
+                if upstream_remote_width > 0 {
+                    let name = truncate_to_width(active.remote, upstream_remote_width);
+                    cell.push_styled(name.clone(), theme().dimmed);
+                    cell.push_raw(
+                        " ".repeat(upstream_remote_width.saturating_sub(name.width()) + 1),
+                    );
+                    arrows_width = arrows_width.saturating_sub(upstream_remote_width + 1);
+                }
                 // Show centered | when in sync instead of ⇡0  ⇣0
                 // Note: This duplicates the InSync check from Divergence::Special, but
                 // checking counts directly is simpler than threading the enum through.
                 if active.ahead == 0 && active.behind == 0 {
-                    let mut cell = StyledLine::new();
                     // Center the symbol in the column width
-                    let padding_left = (self.width.saturating_sub(1)) / 2;
+                    let padding_left = (arrows_width.saturating_sub(1)) / 2;
                     cell.push_raw(" ".repeat(padding_left));
-                    cell.push_styled("|", Style::new().dimmed());
-                    return cell;
+                    cell.push_styled("|", theme().dimmed);
+                } else if upstream_remote_width == 0 && suffix_width == 0 {
+                    return self.render_diff_cell(active.ahead, active.behind);
+                } else {
+                    let ColumnFormat::Diff(config) = self.format else {
+                        return cell;
+                    };
+                    let arrows = DiffColumnConfig {
+                        total_width: arrows_width,
+                        ..config
+                    };
+                    cell.extend(arrows.render_segment(active.ahead, active.behind));
+                }
+                if let Some(threshold) = layout.since_fetch
+                    && upstream.fetched_before(threshold)
+                {
+                    cell.pad_to(self.width.saturating_sub(suffix_width) + 1);
+                    cell.push_styled(FETCH_STALE_GLYPH, theme().dimmed);
                 }
-                self.render_diff_cell(active.ahead, active.behind)
+                cell
             }
             ColumnKind::Time => {
                 let Some(ref commit) = item.commit else {
@@ -437,8 +601,8 @@ impl ColumnLayout {
                 };
                 let mut cell = StyledLine::new();
                 cell.push_styled(
-                    format_relative_time_short(commit.timestamp),
-                    Style::new().dimmed(),
+                    format_relative_time(commit.timestamp, time_format),
+                    theme().dimmed,
                 );
                 cell
             }
@@ -451,12 +615,16 @@ impl ColumnLayout {
                     return StyledLine::new();
                 };
                 let mut cell = StyledLine::new();
-                let formatted = format_url_cell(url);
+                let formatted = if layout.plain {
+                    url.clone()
+                } else {
+                    format_url_cell(url)
+                };
                 if item.url_active == Some(true) {
                     cell.push_raw(formatted);
                 } else {
                     // Not active or unknown: dim styling
-                    cell.push_styled(formatted, Style::new().dimmed());
+                    cell.push_styled(formatted, theme().dimmed);
                 }
                 cell.truncate_to_width(self.width)
             }
@@ -477,6 +645,13 @@ impl ColumnLayout {
                 match &item.pr_status {
                     None => self.placeholder_cell("⋯"), // Not loaded yet
                     Some(None) => StyledLine::new(),    // Loaded, no CI
+                    // The indicator's meaning is in its color, so spell it out
+                    Some(Some(pr_status)) if layout.plain => {
+                        let status: &'static str = pr_status.ci_status.into();
+                        let mut cell = StyledLine::new();
+                        cell.push_raw(status);
+                        cell
+                    }
                     Some(Some(pr_status)) => {
                         let mut cell = StyledLine::new();
                         cell.push_raw(
@@ -489,7 +664,13 @@ impl ColumnLayout {
             ColumnKind::Commit => {
                 let head = item.head();
                 let short_head = &head[..8.min(head.len())];
-                self.render_text_cell(short_head, Some(Style::new().dimmed()))
+                self.render_text_cell(short_head, Some(theme().dimmed))
+            }
+            ColumnKind::Author => {
+                let Some(ref commit) = item.commit else {
+                    return self.placeholder_cell("⋯");
+                };
+                self.render_text_cell(&commit.author_initials(), Some(theme().dimmed))
             }
             ColumnKind::Message => {
                 let Some(ref commit) = item.commit else {
@@ -497,7 +678,7 @@ impl ColumnLayout {
                 };
                 let mut cell = StyledLine::new();
                 let msg = truncate_to_width(&commit.commit_message, max_message_len);
-                cell.push_styled(msg, Style::new().dimmed());
+                cell.push_styled(msg, theme().dimmed);
                 cell
             }
         }
@@ -534,6 +715,21 @@ mod tests {
         config.render_segment(positive, negative)
     }
 
+    #[test]
+    fn test_to_plain_ascii_replaces_glyphs_and_styles() {
+        let styled = format!(
+            "{}↑3{} ↓1",
+            Style::new().bold(),
+            Style::new().bold().render_reset()
+        );
+        assert_eq!(to_plain_ascii(&styled), "^3 v1");
+        assert_eq!(to_plain_ascii("!⤵⇡"), "!M^");
+        assert_eq!(to_plain_ascii("HEAD±"), "HEAD+-");
+        assert_eq!(to_plain_ascii("🤖"), "🤖");
+        assert_eq!(to_plain_ascii("café"), "café");
+        assert!(to_plain_ascii("✗⊂∅–⚑§⋯").is_ascii());
+    }
+
     #[test]
     #[cfg(unix)] // format_aligned is unix-only
     fn test_format_aligned_produces_fixed_width_output() {
@@ -0,0 +1,76 @@
+//! `wt log` — list the commits a branch has beyond the default branch.
+
+use anstyle::Style;
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository, short_sha};
+use worktrunk::styling::{eprintln, info_message, print_hint, println};
+
+/// Print the commits on `branch` that aren't on the default branch.
+///
+/// Defaults to the current worktree's branch. Commits go to stdout as
+/// `<sha> <subject>`, newest first, capped at `limit`. The fork point from
+/// the default branch goes to stderr, so piping stdout stays clean.
+pub fn handle_log(branch: Option<&str>, limit: usize) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+
+    let branch = match branch {
+        Some(name) => repo.resolve_worktree_name(name)?,
+        None => repo.require_current_branch("show log")?,
+    };
+    if !repo.branch_exists(&branch, false)? {
+        return Err(GitError::BranchNotFound {
+            branch,
+            show_create_hint: false,
+            suggestion: None,
+        }
+        .into());
+    }
+
+    let base = repo.require_default_branch()?;
+    if branch == base {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "<bold>{branch}</> is the default branch; nothing to compare"
+            ))
+        );
+        return Ok(());
+    }
+
+    let commits = repo.rev_list_commits(&base, &branch, limit)?;
+    if commits.is_empty() {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "No commits on <bold>{branch}</> beyond <bold>{base}</>"
+            ))
+        );
+        return Ok(());
+    }
+
+    if let Some(fork_point) = repo.merge_base(&base, &branch)? {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "<bold>{branch}</> forked from <bold>{base}</> at <dim>{}</>",
+                short_sha(&fork_point)
+            ))
+        );
+    }
+
+    let dim = Style::new().dimmed();
+    for (sha, subject) in &commits {
+        println!("{dim}{sha}{dim:#} {subject}");
+    }
+
+    if commits.len() == limit {
+        let total = repo.count_commits(&base, &branch)?;
+        if total > limit {
+            print_hint(cformat!(
+                "Showing {limit} of {total} commits; to see more, run <bright-black>wt log {branch} --limit {total}</>"
+            ));
+        }
+    }
+
+    Ok(())
+}
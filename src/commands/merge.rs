@@ -1,18 +1,25 @@
+use std::path::Path;
+
 use anyhow::Context;
+use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::UserConfig;
-use worktrunk::git::Repository;
-use worktrunk::styling::{eprintln, info_message};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    eprintln, format_with_gutter, info_message, print_progress, println, success_message,
+    warning_message,
+};
 
 use super::command_approval::approve_command_batch;
 use super::command_executor::CommandContext;
-use super::commit::CommitOptions;
+use super::commit::{CommitGenerator, CommitOptions};
 use super::context::CommandEnv;
 use super::hooks::{HookFailureStrategy, execute_hook};
 use super::project_config::{HookCommand, collect_commands_for_hooks};
 use super::repository_ext::RepositoryCliExt;
 use super::worktree::{
-    BranchDeletionMode, MergeOperations, RemoveResult, get_path_mismatch, handle_push,
+    BranchDeletionMode, MergeOperations, RemoveResult, confirm, get_path_mismatch, handle_push,
 };
 
 /// Options for the merge command
@@ -32,8 +39,31 @@ pub struct MergeOptions<'a> {
     /// CLI override for verify. None = use effective config default.
     pub verify: Option<bool>,
     pub yes: bool,
+    /// Ask before removing the worktree (`--confirm`)
+    pub confirm: bool,
     /// CLI override for stage mode. None = use effective config default.
     pub stage: Option<super::commit::StageMode>,
+    /// Always create a merge commit (`--no-ff`); implies no squash
+    pub no_ff: bool,
+    pub format: crate::OutputFormat,
+}
+
+/// Outcome of `wt merge`, shared by the human messages and `--format json`.
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct MergeSummary {
+    /// Branch that was fast-forwarded
+    pub target: String,
+    /// Whether branch commits were squashed into one
+    pub squashed: bool,
+    /// Whether the merged branch is kept (not deleted with the worktree)
+    pub kept: bool,
+    /// Target's HEAD after the merge
+    pub merge_commit: Option<String>,
+    /// Whether the worktree was removed
+    pub removed_worktree: bool,
+    /// Why the worktree was preserved (human output only)
+    #[serde(skip)]
+    pub preserved_reason: Option<&'static str>,
 }
 
 /// Collect all commands that will be executed during merge.
@@ -76,6 +106,25 @@ fn collect_merge_commands(
     Ok((all_commands, project_id))
 }
 
+/// Ask whether to remove the worktree after merging (`--confirm`).
+///
+/// Returns false when declined. Without a terminal the choice must be made
+/// explicit with `--yes` or `--no-remove`.
+fn confirm_merge_removal(branch: &str, worktree_path: &Path) -> anyhow::Result<bool> {
+    let question = cformat!(
+        "Remove <bold>{branch}</> worktree at <bold>{}</> after merging?",
+        format_path_for_display(worktree_path)
+    );
+    confirm(&question)?.ok_or_else(|| {
+        GitError::Other {
+            message: cformat!(
+                "Cannot confirm removal of <bold>{branch}</> without a terminal; pass <bright-black>--yes</> or <bright-black>--no-remove</>"
+            ),
+        }
+        .into()
+    })
+}
+
 pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     let MergeOptions {
         target,
@@ -85,7 +134,10 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         remove: remove_opt,
         verify: verify_opt,
         yes,
+        confirm,
         stage,
+        no_ff,
+        format,
     } = opts;
 
     // Load config once, run LLM setup prompt if committing, then reuse config
@@ -125,8 +177,8 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         .into());
     }
 
-    // --no-commit implies --no-squash
-    let squash_enabled = squash && commit;
+    // --no-commit and --no-ff imply --no-squash
+    let squash_enabled = squash && commit && !no_ff;
 
     // Get and validate target branch (must be a branch since we're updating it)
     let target_branch = repo.require_target_branch(target)?;
@@ -138,6 +190,13 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     let on_target = current_branch == target_branch;
     let remove_effective = remove && !on_target && !in_main;
 
+    // --confirm asks before anything runs; declining keeps the worktree but still merges
+    let declined_removal = remove_effective
+        && confirm
+        && !yes
+        && !confirm_merge_removal(&current_branch, &current_wt.root()?)?;
+    let remove_effective = remove_effective && !declined_removal;
+
     // Collect and approve all commands upfront for batch permission request
     let (all_commands, project_id) =
         collect_merge_commands(repo, commit, verify, remove_effective, squash_enabled)?;
@@ -218,17 +277,55 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         )?;
     }
 
+    // --no-ff: wrap the (rebased) branch in a merge commit; the target fast-forwards to it
+    let no_ff_commit = if no_ff {
+        create_merge_commit(
+            repo,
+            &current_branch,
+            &target_branch,
+            &resolved.commit_generation,
+        )?
+    } else {
+        None
+    };
+
     // Fast-forward push to target branch with commit/squash/rebase info for consolidated message
     handle_push(
         Some(&target_branch),
+        no_ff_commit.as_deref(),
         "Merged to",
         Some(MergeOperations {
             committed,
             squashed,
             rebased,
         }),
+        false,
     )?;
 
+    // Worktree preserved reason (priority: main worktree > on target > --no-remove flag)
+    let preserved_reason = if remove_effective {
+        None
+    } else if in_main {
+        Some("main worktree")
+    } else if on_target {
+        Some("already on target branch")
+    } else if declined_removal {
+        Some("removal declined")
+    } else {
+        Some("--no-remove")
+    };
+    let mut summary = MergeSummary {
+        target: target_branch.clone(),
+        squashed,
+        kept: true,
+        merge_commit: repo
+            .run_command(&["rev-parse", &target_branch])
+            .ok()
+            .map(|s| s.trim().to_string()),
+        removed_worktree: preserved_reason.is_none(),
+        preserved_reason,
+    };
+
     // Destination: prefer the target branch's worktree; fall back to home path.
     let destination_path = match target_worktree_path {
         Some(path) => path,
@@ -236,7 +333,9 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     };
 
     // Finish worktree unless --no-remove was specified
-    if remove_effective {
+    if let Some(reason) = summary.preserved_reason {
+        eprintln!("{}", info_message(format!("Worktree preserved ({reason})")));
+    } else {
         // STEP 1: Check for uncommitted changes before attempting cleanup
         // This prevents showing "Cleaning up worktree..." before failing
         current_wt.ensure_clean("remove worktree after merge", Some(&current_branch), false)?;
@@ -245,6 +344,8 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         let worktree_root = current_wt.root()?;
         // After a successful merge, get integration reason
         let (_, integration_reason) = repo.integration_reason(&current_branch, &target_branch)?;
+        // Removal runs in the background; safe deletion removes the branch iff integrated
+        summary.kept = integration_reason.is_none();
         // Compute expected_path for path mismatch detection
         let expected_path = get_path_mismatch(repo, &current_branch, &worktree_root, config);
         // Capture commit SHA before removal for post-remove hook template variables
@@ -269,16 +370,6 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         // Run hooks during merge removal (pass through verify flag)
         // Approval was handled at the gate (collect_merge_commands)
         crate::output::handle_remove_output(&remove_result, true, verify)?;
-    } else {
-        // Worktree preserved - show reason (priority: main worktree > on target > --no-remove flag)
-        let message = if in_main {
-            "Worktree preserved (main worktree)"
-        } else if on_target {
-            "Worktree preserved (already on target branch)"
-        } else {
-            "Worktree preserved (--no-remove)"
-        };
-        eprintln!("{}", info_message(message));
     }
 
     if verify {
@@ -297,12 +388,218 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         execute_hook(
             &ctx,
             HookType::PostMerge,
-            &[("target", target_branch.as_str())],
+            &[
+                ("target", target_branch.as_str()),
+                ("squashed", if squashed { "true" } else { "false" }),
+            ],
             HookFailureStrategy::Warn,
             None,
             display_path,
         )?;
     }
 
+    // Structured result on stdout (human messages above went to stderr)
+    if matches!(format, crate::OutputFormat::Json) {
+        let json = serde_json::to_string_pretty(&summary).context("Failed to serialize to JSON")?;
+        println!("{json}");
+    }
+
+    Ok(())
+}
+
+/// Create the `--no-ff` merge commit of `branch` onto `target_branch`.
+///
+/// The branch is already rebased onto the target, so HEAD's tree is the merge
+/// result and no real merge is needed — the commit just records both parents,
+/// target first. Returns `None` when the branch has nothing beyond the target.
+fn create_merge_commit(
+    repo: &Repository,
+    branch: &str,
+    target_branch: &str,
+    generation_config: &worktrunk::config::CommitGenerationConfig,
+) -> anyhow::Result<Option<String>> {
+    if repo.count_commits(target_branch, "HEAD")? == 0 {
+        return Ok(None);
+    }
+
+    let merge_base = repo
+        .merge_base("HEAD", target_branch)?
+        .context("Cannot merge: no common ancestor with target branch")?;
+    let subjects = repo.commit_subjects(&format!("{target_branch}..HEAD"))?;
+
+    let generator = CommitGenerator::new(generation_config);
+    print_progress("Generating merge commit message...");
+    generator.emit_hint_if_needed();
+
+    let repo_root = repo.current_worktree().root()?;
+    let repo_name = repo_root
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("repo");
+    let message = crate::llm::generate_merge_message(
+        target_branch,
+        &merge_base,
+        &subjects,
+        branch,
+        repo_name,
+        generation_config,
+    )?;
+    eprintln!(
+        "{}",
+        format_with_gutter(&generator.format_message_for_display(&message), None)
+    );
+
+    let sha = repo
+        .run_command(&[
+            "commit-tree",
+            "HEAD^{tree}",
+            "-p",
+            target_branch,
+            "-p",
+            "HEAD",
+            "-m",
+            &message,
+        ])
+        .context("Failed to create merge commit")?;
+    Ok(Some(sha.trim().to_string()))
+}
+
+/// Merge the target branch into the current branch, in place (`wt merge --into-current`).
+///
+/// The inverse of `wt merge`: brings the target's latest commits into the current
+/// worktree without removing anything or running hooks. On conflicts, git's merge
+/// is left in progress so the user can resolve and run `git merge --continue`.
+pub fn handle_merge_into_current(target: Option<&str>) -> anyhow::Result<()> {
+    let env = CommandEnv::for_action(
+        "merge",
+        UserConfig::load().context("Failed to load config")?,
+    )?;
+    let repo = &env.repo;
+    let current_branch = env.require_branch("merge into current")?.to_string();
+
+    let target_branch = repo.require_target_ref(target)?;
+    if target_branch == current_branch {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "Already on <bold>{target_branch}</>; nothing to merge"
+            ))
+        );
+        return Ok(());
+    }
+
+    repo.current_worktree()
+        .ensure_clean("merge into current", Some(&current_branch), false)?;
+
+    if repo.is_ancestor(&target_branch, "HEAD")? {
+        eprintln!(
+            "{}",
+            info_message(cformat!("Already up to date with <bold>{target_branch}</>"))
+        );
+        return Ok(());
+    }
+
+    if repo.has_merge_conflicts("HEAD", &target_branch)? {
+        eprintln!(
+            "{}",
+            warning_message(cformat!(
+                "Merging <bold>{target_branch}</> into <bold>{current_branch}</> will conflict; the merge will stop for you to resolve"
+            ))
+        );
+    }
+
+    print_progress(cformat!(
+        "Merging <bold>{target_branch}</> into <bold>{current_branch}</>..."
+    ));
+
+    if let Err(e) = repo.run_command(&["merge", "--no-edit", &target_branch]) {
+        if repo.worktree_state()?.as_deref() == Some("MERGING") {
+            return Err(GitError::MergeConflict {
+                target_branch,
+                git_output: e.to_string(),
+            }
+            .into());
+        }
+        return Err(GitError::Other {
+            message: cformat!("Failed to merge <bold>{target_branch}</>: {e}"),
+        }
+        .into());
+    }
+
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "Merged <bold>{target_branch}</> into <bold>{current_branch}</>"
+        ))
+    );
+
+    Ok(())
+}
+
+/// The git operation a stopped `wt merge` left in progress in this worktree.
+///
+/// `wt merge` stops in a rebase; `wt merge --into-current` stops in a merge.
+/// Anything else wasn't started by worktrunk, so `--abort`/`--continue` refuse it.
+fn stopped_merge_operation(repo: &Repository, action: &str) -> anyhow::Result<&'static str> {
+    match repo.worktree_state()?.as_deref() {
+        Some(state) if state.starts_with("REBASING") => Ok("rebase"),
+        Some("MERGING") => Ok("merge"),
+        Some(state) => {
+            anyhow::bail!("Can't {action}: this worktree is {state}, which wt merge doesn't start")
+        }
+        None => anyhow::bail!("No merge in progress in this worktree; nothing to {action}"),
+    }
+}
+
+/// Abort a merge that stopped on conflicts (`wt merge --abort`).
+///
+/// Runs `git rebase --abort` or `git merge --abort`, which returns the branch
+/// to where it was before that step. A squash made earlier in the same
+/// `wt merge` stays; the pre-squash state is in `refs/wt-backup/<branch>`.
+pub fn handle_merge_abort() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let operation = stopped_merge_operation(&repo, "abort")?;
+
+    repo.run_command(&[operation, "--abort"])
+        .with_context(|| format!("Failed to abort the {operation}"))?;
+
+    let message = match (operation, repo.current_worktree().branch()?) {
+        ("rebase", Some(branch)) => cformat!("Aborted the rebase of <bold>{branch}</>"),
+        ("merge", Some(branch)) => cformat!("Aborted the merge into <bold>{branch}</>"),
+        _ => format!("Aborted the {operation}"),
+    };
+    eprintln!("{}", success_message(message));
     Ok(())
 }
+
+/// Resume a merge that stopped on conflicts (`wt merge --continue`).
+///
+/// Continues the in-progress rebase (or `--into-current` merge) with the
+/// conflicts the user resolved. After a rebase, runs `wt merge` with the same
+/// options: squash and rebase are then no-ops, and the remaining steps (hooks,
+/// updating the target, removal) run as they would have.
+pub fn handle_merge_continue(opts: MergeOptions<'_>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let operation = stopped_merge_operation(&repo, "continue")?;
+
+    // Accept git's default commit message instead of opening an editor
+    if let Err(e) = repo.run_command(&["-c", "core.editor=true", operation, "--continue"]) {
+        if operation == "rebase" && repo.worktree_state()?.is_some() {
+            return Err(GitError::RebaseConflict {
+                target_branch: repo.require_target_ref(opts.target)?,
+                git_output: e.to_string(),
+            }
+            .into());
+        }
+        return Err(e.context(format!("Failed to continue the {operation}")));
+    }
+
+    if operation == "merge" {
+        // --into-current has no further steps
+        eprintln!("{}", success_message("Merge completed"));
+        return Ok(());
+    }
+
+    eprintln!("{}", success_message("Rebase completed"));
+    handle_merge(opts)
+}
@@ -5,6 +5,7 @@ pub(crate) mod commit;
 pub(crate) mod config;
 pub(crate) mod configure_shell;
 pub(crate) mod context;
+mod diff;
 mod for_each;
 mod handle_switch;
 mod hook_commands;
@@ -12,6 +13,7 @@ mod hook_filter;
 pub(crate) mod hooks;
 pub(crate) mod init;
 pub(crate) mod list;
+mod log;
 pub(crate) mod merge;
 pub(crate) mod process;
 pub(crate) mod project_config;
@@ -25,19 +27,26 @@ pub(crate) mod step_commands;
 pub(crate) mod worktree;
 
 pub(crate) use config::{
-    handle_config_create, handle_config_show, handle_hints_clear, handle_hints_get,
-    handle_logs_get, handle_state_clear, handle_state_clear_all, handle_state_get,
-    handle_state_set, handle_state_show,
+    handle_config_create, handle_config_edit, handle_config_show, handle_hints_clear,
+    handle_hints_get, handle_logs_get, handle_shell_status, handle_state_clear,
+    handle_state_clear_all, handle_state_get, handle_state_set, handle_state_show,
 };
 pub(crate) use configure_shell::{
     handle_configure_shell, handle_show_theme, handle_unconfigure_shell,
 };
+pub(crate) use diff::handle_diff;
 pub(crate) use for_each::step_for_each;
-pub(crate) use handle_switch::{SwitchOptions, handle_switch};
+pub(crate) use handle_switch::{
+    SwitchOptions, handle_switch, handle_switch_manifest, read_branch_from_stdin,
+};
 pub(crate) use hook_commands::{add_approvals, clear_approvals, handle_hook_show, run_hook};
-pub(crate) use init::{handle_completions, handle_init};
-pub(crate) use list::handle_list;
-pub(crate) use merge::{MergeOptions, handle_merge};
+pub(crate) use init::{handle_completions, handle_init, handle_init_print_path};
+pub(crate) use list::{ListOptions, handle_list};
+pub(crate) use log::handle_log;
+pub(crate) use merge::{
+    MergeOptions, handle_merge, handle_merge_abort, handle_merge_continue,
+    handle_merge_into_current,
+};
 #[cfg(unix)]
 pub(crate) use select::handle_select;
 pub(crate) use step_commands::{
@@ -22,6 +22,8 @@ use crate::commands::hook_filter::HookSource;
 pub enum InternalOp {
     /// Background worktree removal (`wt remove` in background mode)
     Remove,
+    /// Background `git commit-graph write` (the `maintenance` config option)
+    CommitGraph,
 }
 
 /// Specification for a hook log file.
@@ -132,7 +134,7 @@ impl HookLog {
             ["internal", op_str] => {
                 let op = InternalOp::from_str(op_str).map_err(|_| {
                     cformat!(
-                        "Unknown internal operation: <bold>{}</>. Valid: remove",
+                        "Unknown internal operation: <bold>{}</>. Valid: remove, commit-graph",
                         op_str
                     )
                 })?;
@@ -193,6 +195,7 @@ fn posix_command_separator(command: &str) -> &'static str {
 /// * `branch` - Branch name for log organization
 /// * `hook_log` - Log specification (determines the log filename)
 /// * `context_json` - Optional JSON context to pipe to command's stdin
+/// * `env` - Extra environment variables for the command
 ///
 /// # Returns
 /// Path to the log file where output is being written
@@ -203,6 +206,7 @@ pub fn spawn_detached(
     branch: &str,
     hook_log: &HookLog,
     context_json: Option<&str>,
+    env: &[(&str, String)],
 ) -> anyhow::Result<std::path::PathBuf> {
     // Create log directory in the common git directory
     let log_dir = repo.wt_logs_dir();
@@ -232,12 +236,12 @@ pub fn spawn_detached(
 
     #[cfg(unix)]
     {
-        spawn_detached_unix(worktree_path, command, log_file, context_json)?;
+        spawn_detached_unix(worktree_path, command, log_file, context_json, env)?;
     }
 
     #[cfg(windows)]
     {
-        spawn_detached_windows(worktree_path, command, log_file, context_json)?;
+        spawn_detached_windows(worktree_path, command, log_file, context_json, env)?;
     }
 
     Ok(log_path)
@@ -249,6 +253,7 @@ fn spawn_detached_unix(
     command: &str,
     log_file: fs::File,
     context_json: Option<&str>,
+    env: &[(&str, String)],
 ) -> anyhow::Result<()> {
     use std::os::unix::process::CommandExt;
 
@@ -284,6 +289,7 @@ fn spawn_detached_unix(
                 .context("Failed to clone log file handle")?,
         ))
         .stderr(Stdio::from(log_file))
+        .envs(env.iter().map(|(key, value)| (key, value)))
         // Prevent hooks from writing to the directive file
         .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR)
         .process_group(0) // New process group, not in PTY's foreground group
@@ -304,6 +310,7 @@ fn spawn_detached_windows(
     command: &str,
     log_file: fs::File,
     context_json: Option<&str>,
+    env: &[(&str, String)],
 ) -> anyhow::Result<()> {
     use std::os::windows::process::CommandExt;
     use worktrunk::shell_exec::ShellConfig;
@@ -357,6 +364,7 @@ fn spawn_detached_windows(
                 .context("Failed to clone log file handle")?,
         ))
         .stderr(Stdio::from(log_file))
+        .envs(env.iter().map(|(key, value)| (key, value)))
         // Prevent hooks from writing to the directive file
         .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR)
         .creation_flags(CREATE_NEW_PROCESS_GROUP | DETACHED_PROCESS)
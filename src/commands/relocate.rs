@@ -30,7 +30,7 @@ use worktrunk::git::{Repository, WorktreeInfo};
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell_exec::Cmd;
 use worktrunk::styling::{
-    eprintln, format_with_gutter, hint_message, info_message, progress_message, success_message,
+    eprintln, format_with_gutter, info_message, print_hint, print_progress, success_message,
     warning_message,
 };
 
@@ -215,10 +215,7 @@ pub fn validate_candidates(
         let worktree = repo.worktree_at(&candidate.wt.path);
         if worktree.is_dirty()? {
             if auto_commit {
-                eprintln!(
-                    "{}",
-                    progress_message(cformat!("Committing changes in <bold>{branch}</>..."))
-                );
+                print_progress(cformat!("Committing changes in <bold>{branch}</>..."));
                 // Stage all changes
                 worktree
                     .run_command(&["add", "-A"])
@@ -237,12 +234,9 @@ pub fn validate_candidates(
                     "{}",
                     warning_message(cformat!("Skipping <bold>{branch}</> (uncommitted changes)"))
                 );
-                eprintln!(
-                    "{}",
-                    hint_message(cformat!(
-                        "Use <bright-black>--commit</> to auto-commit changes before relocating"
-                    ))
-                );
+                print_hint(cformat!(
+                    "Use <bright-black>--commit</> to auto-commit changes before relocating"
+                ));
                 skipped += 1;
                 continue;
             }
@@ -314,7 +308,7 @@ impl RelocationExecutor {
                 );
                 eprintln!("{}", warning_message(msg));
                 let hint = cformat!("Relocate or remove <bright-black>{occupant_name}</> first");
-                eprintln!("{}", hint_message(hint));
+                print_hint(hint);
                 blocked.insert(i);
                 skipped += 1;
                 continue;
@@ -335,22 +329,16 @@ impl RelocationExecutor {
                 ));
                 let src = format_path_for_display(expected_path);
                 let dest = format_path_for_display(&backup_path);
-                eprintln!(
-                    "{}",
-                    progress_message(cformat!("Backing up {src} → {dest}"))
-                );
+                print_progress(cformat!("Backing up {src} → {dest}"));
                 std::fs::rename(expected_path, &backup_path)
                     .with_context(|| format!("Failed to backup {}", expected_path.display()))?;
             } else {
                 let blocked_path = format_path_for_display(expected_path);
                 let msg = cformat!("Skipping <bold>{branch}</> (target blocked: {blocked_path})");
                 eprintln!("{}", warning_message(msg));
-                eprintln!(
-                    "{}",
-                    hint_message(cformat!(
-                        "Use <bright-black>--clobber</> to backup blocking paths"
-                    ))
-                );
+                print_hint(cformat!(
+                    "Use <bright-black>--clobber</> to backup blocking paths"
+                ));
                 blocked.insert(i);
                 skipped += 1;
             }
@@ -505,7 +493,7 @@ impl RelocationExecutor {
         let branch = candidate.branch();
 
         let msg = cformat!("Switching main worktree to <bold>{default_branch}</>...");
-        eprintln!("{}", progress_message(msg));
+        print_progress(msg);
 
         Cmd::new("git")
             .args(["checkout", default_branch])
@@ -569,7 +557,7 @@ impl RelocationExecutor {
         let temp_path = self.temp_dir.join(&safe_branch);
 
         let msg = cformat!("Moving <bold>{branch}</> to temporary location...");
-        eprintln!("{}", progress_message(msg));
+        print_progress(msg);
 
         Cmd::new("git")
             .args(["worktree", "move"])
@@ -10,7 +10,7 @@ use worktrunk::git::{
     GitError, IntegrationReason, Repository, parse_porcelain_z, parse_untracked_files,
 };
 use worktrunk::path::format_path_for_display;
-use worktrunk::styling::{eprintln, format_with_gutter, progress_message, warning_message};
+use worktrunk::styling::{eprintln, format_with_gutter, print_progress, warning_message};
 
 /// Target for worktree removal.
 #[derive(Debug)]
@@ -77,7 +77,6 @@ impl RepositoryCliExt for Repository {
         config: &UserConfig,
     ) -> anyhow::Result<RemoveResult> {
         let current_path = self.current_worktree().root()?.to_path_buf();
-        let worktrees = self.list_worktrees()?;
         // Home worktree: prefer default branch's worktree, fall back to first worktree,
         // then repo base for bare repos with no worktrees.
         let home_worktree_path = self.home_path()?;
@@ -85,10 +84,7 @@ impl RepositoryCliExt for Repository {
         // Resolve target to worktree path and branch
         let (worktree_path, branch_name, is_current) = match target {
             RemoveTarget::Branch(branch) => {
-                match worktrees
-                    .iter()
-                    .find(|wt| wt.branch.as_deref() == Some(branch))
-                {
+                match self.worktree_info_for_branch(branch)? {
                     Some(wt) => {
                         if !wt.path.exists() {
                             // Directory missing - prune and continue
@@ -132,12 +128,14 @@ impl RepositoryCliExt for Repository {
                         return Err(GitError::BranchNotFound {
                             branch: branch.into(),
                             show_create_hint: false,
+                            suggestion: None,
                         }
                         .into());
                     }
                 }
             }
             RemoveTarget::Current => {
+                let worktrees = self.list_worktrees()?;
                 let wt = worktrees
                     .iter()
                     .find(|wt| wt.path == current_path)
@@ -275,13 +273,10 @@ impl RepositoryCliExt for Repository {
             nanos
         );
 
-        eprintln!(
-            "{}",
-            progress_message(cformat!(
-                "Stashing changes in <bold>{}</>...",
-                format_path_for_display(wt_path)
-            ))
-        );
+        print_progress(cformat!(
+            "Stashing changes in <bold>{}</>...",
+            format_path_for_display(wt_path)
+        ));
 
         // Stash all changes including untracked files.
         // Note: git stash push returns exit code 0 whether or not anything was stashed.
@@ -404,13 +399,10 @@ struct StashData {
 impl StashData {
     /// Restore the stash, printing progress and warning on failure.
     fn restore(self) {
-        eprintln!(
-            "{}",
-            progress_message(cformat!(
-                "Restoring stashed changes in <bold>{}</>...",
-                format_path_for_display(&self.path)
-            ))
-        );
+        print_progress(cformat!(
+            "Restoring stashed changes in <bold>{}</>...",
+            format_path_for_display(&self.path)
+        ));
 
         // Don't use --quiet so git shows conflicts if any
         let success = Repository::current()
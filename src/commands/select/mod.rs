@@ -21,7 +21,7 @@ use super::handle_switch::{
     approve_switch_hooks, spawn_switch_background_hooks, switch_extra_vars,
 };
 use super::list::collect;
-use super::worktree::{execute_switch, plan_switch};
+use super::worktree::{SwitchPlanOptions, execute_switch, plan_switch};
 use crate::output::handle_switch_output;
 
 use items::{HeaderSkimItem, PreviewCache, WorktreeSkimItem};
@@ -38,6 +38,7 @@ pub fn handle_select(
     }
 
     let repo = Repository::current()?;
+    worktrunk::styling::set_theme(config.theme());
 
     // Initialize preview mode state file (auto-cleanup on drop)
     let state = PreviewState::new();
@@ -60,14 +61,30 @@ pub fn handle_select(
 
     let Some(list_data) = collect::collect(
         &repo,
-        show_branches,
-        show_remotes,
-        &skip_tasks,
-        false, // show_progress (no progress bars)
-        false, // render_table (select renders its own UI)
         config,
-        command_timeout,
-        true, // skip_expensive_for_stale (faster for repos with many stale branches)
+        collect::CollectArgs {
+            show_branches,
+            show_worktrees: true,
+            show_remotes,
+            skip_tasks: &skip_tasks,
+            show_progress: false, // no progress bars
+            render_table: false,  // select renders its own UI
+            command_timeout,
+            skip_expensive_for_stale: true, // faster for repos with many stale branches
+            since: None,                    // show all items
+            since_fetch: None,
+            path_filter: None,
+            active_only: false,
+            conflicts_only: false,
+            group_by: crate::cli::ListGroupBy::None,
+            sort: crate::cli::ListSort::Recent,
+            path_style: None,    // from config
+            remote_names: false, // from config
+            max_width: None,     // select lays out its own UI
+            plain: false,
+            markdown: false,
+            stats: false,
+        },
     )?
     else {
         return Ok(());
@@ -82,12 +99,18 @@ pub fn handle_select(
         PreviewLayout::Right => terminal_width / 2,
         PreviewLayout::Down => terminal_width,
     };
+    let list_config = config
+        .list(repo.project_identifier().ok().as_deref())
+        .unwrap_or_default();
     let layout = super::list::layout::calculate_layout_with_width(
         &list_data.items,
         &skip_tasks,
         skim_list_width,
         &list_data.main_worktree_path,
         None, // URL column not shown in select
+        &list_config,
+        0,    // Upstream shows arrows only
+        None, // No fetch staleness glyph
     );
 
     // Render header using layout system (need both plain and styled text for skim)
@@ -282,9 +305,17 @@ pub fn handle_select(
         execute!(stderr(), crossterm::cursor::MoveTo(0, 0))?;
 
         // Switch to existing worktree or create new one
-        let plan = plan_switch(&repo, &identifier, should_create, None, false, &config)?;
+        let plan = plan_switch(
+            &repo,
+            SwitchPlanOptions {
+                branch: &identifier,
+                create: should_create,
+                ..Default::default()
+            },
+            &config,
+        )?;
         let skip_hooks = !approve_switch_hooks(&repo, &config, &plan, false, true)?;
-        let (result, branch_info) = execute_switch(&repo, plan, &config, false, skip_hooks)?;
+        let (result, branch_info) = execute_switch(&repo, plan, &config, false, skip_hooks, false)?;
 
         // Show success message; emit cd directive if shell integration is active
         // Interactive picker always performs cd (change_dir: true)
@@ -300,7 +331,7 @@ pub fn handle_select(
                 &repo,
                 &config,
                 &result,
-                &branch_info.branch,
+                Some(&branch_info.branch),
                 false,
                 &extra_vars,
                 hooks_display_path.as_deref(),
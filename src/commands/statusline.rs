@@ -359,9 +359,9 @@ fn get_git_status_segments(
 
     let Some(wt) = current_worktree else {
         // Not in a worktree - just show branch name as a segment
-        if let Ok(Some(branch)) = repo.current_worktree().branch() {
+        if let Some(branch) = repo.current_branch() {
             return Ok(vec![StatuslineSegment::from_column(
-                branch.to_string(),
+                branch,
                 ColumnKind::Branch,
             )]);
         }
@@ -18,7 +18,7 @@ use worktrunk::HookType;
 use worktrunk::config::UserConfig;
 use worktrunk::git::Repository;
 use worktrunk::styling::{
-    eprintln, format_with_gutter, hint_message, info_message, progress_message, success_message,
+    eprintln, format_with_gutter, info_message, print_hint, print_progress, success_message,
 };
 
 use super::command_approval::approve_hooks;
@@ -263,23 +263,20 @@ pub fn handle_squash(
             "Squashing {commit_count} {commit_text}{with_changes} into a single commit <bright-black>({parts_str}</>{paren_close}..."
         )
     };
-    eprintln!("{}", progress_message(squash_progress));
+    print_progress(squash_progress);
 
     // Create safety backup before potentially destructive reset if there are working tree changes
     if has_staged {
         let backup_message = format!("{} → {} (squash)", current_branch, integration_target);
         let sha = wt.create_safety_backup(&backup_message)?;
-        eprintln!("{}", hint_message(format!("Backup created @ {sha}")));
+        print_hint(format!("Backup created @ {sha}"));
     }
 
     // Get commit subjects for the squash message
     let subjects = repo.commit_subjects(&range)?;
 
     // Generate squash commit message
-    eprintln!(
-        "{}",
-        progress_message("Generating squash commit message...")
-    );
+    print_progress("Generating squash commit message...");
 
     generator.emit_hint_if_needed();
 
@@ -416,10 +413,7 @@ pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
 
     // Only show progress for true rebases (fast-forwards are instant)
     if !is_fast_forward {
-        eprintln!(
-            "{}",
-            progress_message(cformat!("Rebasing onto <bold>{integration_target}</>..."))
-        );
+        print_progress(cformat!("Rebasing onto <bold>{integration_target}</>..."));
     }
 
     let rebase_result = repo.run_command(&["rebase", &integration_target]);
@@ -0,0 +1,28 @@
+//! Yes/no prompts before destructive worktree operations.
+
+use std::io::{self, IsTerminal, Write};
+
+use color_print::cformat;
+use worktrunk::styling::{eprint, eprintln, prompt_message, stderr};
+
+/// Ask `question` on stderr and read the answer from stdin.
+///
+/// Returns `None` without a terminal, since there's no one to ask; callers turn
+/// that into an error naming the flag that skips the prompt. Stdin is read
+/// directly, so this works under shell integration too. Only `y`/`yes` confirm.
+pub(crate) fn confirm(question: &str) -> anyhow::Result<Option<bool>> {
+    if !io::stdin().is_terminal() {
+        return Ok(None);
+    }
+
+    eprintln!();
+    eprint!("{} ", prompt_message(cformat!("{question} <bold>[y/N]</>")));
+    stderr().flush()?;
+
+    let mut response = String::new();
+    io::stdin().read_line(&mut response)?;
+    eprintln!(); // End the prompt line
+
+    let response = response.trim().to_lowercase();
+    Ok(Some(response == "y" || response == "yes"))
+}
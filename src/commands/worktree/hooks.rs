@@ -5,6 +5,7 @@
 use std::path::Path;
 
 use worktrunk::HookType;
+use worktrunk::git::short_sha;
 use worktrunk::path::to_posix_path;
 
 use crate::commands::command_executor::CommandContext;
@@ -64,11 +65,7 @@ impl<'a> CommandContext<'a> {
         // Build extra_vars with all removed worktree context.
         // Commit is captured before removal to ensure it reflects the removed worktree's state.
         let commit = removed_commit.unwrap_or("");
-        let short_commit = if commit.len() >= 7 {
-            &commit[..7]
-        } else {
-            commit
-        };
+        let short_commit = short_sha(commit);
         let extra_vars: Vec<(&str, &str)> = vec![
             ("branch", removed_branch),
             ("worktree_path", &worktree_path_str),
@@ -78,6 +78,7 @@
 //!
 //! The shell wrapper is generated by `wt config shell init <shell>` from templates in `templates/`.
 
+mod confirm;
 mod hooks;
 mod push;
 mod remove;
@@ -86,6 +87,7 @@ mod switch;
 mod types;
 
 // Re-export public types and functions
+pub(crate) use confirm::confirm;
 pub use push::handle_push;
 pub use remove::{handle_remove, handle_remove_current};
 pub(crate) use resolve::paths_match;
@@ -95,6 +97,6 @@ pub use resolve::{
 };
 pub use switch::{execute_switch, plan_switch};
 pub use types::{
-    BranchDeletionMode, MergeOperations, OperationMode, RemoveResult, SwitchBranchInfo, SwitchPlan,
-    SwitchResult,
+    BranchDeletionMode, CreationMethod, MergeOperations, OperationMode, RemoveResult,
+    SwitchBranchInfo, SwitchPlan, SwitchPlanOptions, SwitchResult,
 };
@@ -5,7 +5,7 @@
 use color_print::cformat;
 use worktrunk::git::{GitError, Repository};
 use worktrunk::styling::{
-    eprintln, format_with_gutter, info_message, progress_message, success_message,
+    eprintln, format_with_gutter, info_message, print_progress, success_message,
 };
 
 use super::types::MergeOperations;
@@ -13,6 +13,9 @@ use crate::commands::repository_ext::RepositoryCliExt;
 
 /// Push changes to target branch
 ///
+/// `source` is the revision to push, defaulting to `HEAD` (`wt merge --no-ff`
+/// passes its merge commit instead).
+///
 /// The `operations` parameter indicates which merge operations occurred (commit, squash, rebase).
 /// Pass `None` for standalone push operations where these concepts don't apply.
 ///
@@ -20,12 +23,18 @@ use crate::commands::repository_ext::RepositoryCliExt;
 /// target worktree (if present) so that concurrent edits there do not block the
 /// fast-forward. The stash is restored afterward and we bail out early if any file
 /// overlaps with the push range.
+///
+/// With `set_upstream`, the target then tracks `<primary remote>/<target>` when
+/// that remote-tracking branch exists.
 pub fn handle_push(
     target: Option<&str>,
+    source: Option<&str>,
     verb: &str,
     operations: Option<MergeOperations>,
+    set_upstream: bool,
 ) -> anyhow::Result<()> {
     let repo = Repository::current()?;
+    let source = source.unwrap_or("HEAD");
 
     // Get and validate target branch (must be a branch since we're updating it)
     let target_branch = repo.require_target_branch(target)?;
@@ -36,7 +45,7 @@ pub fn handle_push(
     let target_worktree_path = repo.worktree_for_branch(&target_branch)?;
 
     // Check if it's a fast-forward
-    if !repo.is_ancestor(&target_branch, "HEAD")? {
+    if !repo.is_ancestor(&target_branch, source)? {
         // Get formatted commit log (commits in target that we don't have)
         let commits_formatted = repo
             .run_command(&[
@@ -44,7 +53,7 @@ pub fn handle_push(
                 "--color=always",
                 "--graph",
                 "--oneline",
-                &format!("HEAD..{}", target_branch),
+                &format!("{}..{}", source, target_branch),
             ])?
             .trim()
             .to_string();
@@ -63,11 +72,15 @@ pub fn handle_push(
         repo.prepare_target_worktree(target_worktree_path.as_ref(), &target_branch)?;
 
     // Count commits and show what will be pushed
-    let commit_count = repo.count_commits(&target_branch, "HEAD")?;
+    let commit_count = repo.count_commits(&target_branch, source)?;
 
     // Get diff statistics BEFORE push (will be needed for success message later)
     let stats_summary = if commit_count > 0 {
-        repo.diff_stats_summary(&["diff", "--shortstat", &format!("{}..HEAD", target_branch)])
+        repo.diff_stats_summary(&[
+            "diff",
+            "--shortstat",
+            &format!("{}..{}", target_branch, source),
+        ])
     } else {
         Vec::new()
     };
@@ -79,7 +92,7 @@ pub fn handle_push(
         } else {
             "commits"
         };
-        let head_sha = repo.run_command(&["rev-parse", "--short", "HEAD"])?;
+        let head_sha = repo.run_command(&["rev-parse", "--short", source])?;
         let head_sha = head_sha.trim();
 
         let verb_ing = if verb.starts_with("Merged") {
@@ -112,12 +125,9 @@ pub fn handle_push(
             format!(" ({})", notes.join(", "))
         };
 
-        eprintln!(
-            "{}",
-            progress_message(cformat!(
-                "{verb_ing} {commit_count} {commit_text} to <bold>{target_branch}</> @ <dim>{head_sha}</>{operations_note}"
-            ))
-        );
+        print_progress(cformat!(
+            "{verb_ing} {commit_count} {commit_text} to <bold>{target_branch}</> @ <dim>{head_sha}</>{operations_note}"
+        ));
 
         // Show the commit graph with color
         let log_output = repo.run_command(&[
@@ -125,12 +135,12 @@ pub fn handle_push(
             "--color=always",
             "--graph",
             "--oneline",
-            &format!("{}..HEAD", target_branch),
+            &format!("{}..{}", target_branch, source),
         ])?;
         eprintln!("{}", format_with_gutter(&log_output, None));
 
         // Show diff statistics
-        crate::commands::show_diffstat(&repo, &format!("{}..HEAD", target_branch))?;
+        crate::commands::show_diffstat(&repo, &format!("{}..{}", target_branch, source))?;
     }
 
     // Get git common dir for the push
@@ -139,7 +149,7 @@ pub fn handle_push(
 
     // Perform the push - stash guard will auto-restore on any exit path
     // Use --receive-pack to pass config to the receiving end without permanently mutating repo config
-    let push_target = format!("HEAD:{}", target_branch);
+    let push_target = format!("{}:{}", source, target_branch);
     repo.run_command(&[
         "push",
         "--receive-pack=git -c receive.denyCurrentBranch=updateInstead receive-pack",
@@ -207,5 +217,36 @@ pub fn handle_push(
         );
     }
 
+    if set_upstream {
+        track_remote_branch(&repo, &target_branch)?;
+    }
+
+    Ok(())
+}
+
+/// Point `branch` at its counterpart on the primary remote, if there is one.
+fn track_remote_branch(repo: &Repository, branch: &str) -> anyhow::Result<()> {
+    let remote_ref = repo
+        .primary_remote()
+        .ok()
+        .map(|remote| format!("{remote}/{branch}"))
+        .filter(|remote_ref| repo.is_remote_tracking_branch(remote_ref));
+    let Some(remote_ref) = remote_ref else {
+        eprintln!(
+            "{}",
+            info_message(cformat!(
+                "No remote branch for <bold>{branch}</>; upstream not set"
+            ))
+        );
+        return Ok(());
+    };
+
+    repo.set_upstream(branch, &remote_ref)?;
+    eprintln!(
+        "{}",
+        success_message(cformat!(
+            "<bold>{branch}</> now tracks <bold>{remote_ref}</>"
+        ))
+    );
     Ok(())
 }
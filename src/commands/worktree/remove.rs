@@ -1,8 +1,11 @@
 //! Worktree remove operations.
 
-use worktrunk::config::UserConfig;
-use worktrunk::git::Repository;
+use color_print::cformat;
+use worktrunk::config::{RemoveConfirm, UserConfig};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{eprintln, warning_message};
 
+use super::confirm::confirm;
 use super::types::{BranchDeletionMode, RemoveResult};
 use crate::commands::repository_ext::{RemoveTarget, RepositoryCliExt};
 
@@ -12,17 +15,20 @@ pub fn handle_remove(
     no_delete_branch: bool,
     force_delete: bool,
     force_worktree: bool,
+    yes: bool,
     config: &UserConfig,
 ) -> anyhow::Result<RemoveResult> {
     let repo = Repository::current()?;
 
     // Progress message is shown in handle_removed_worktree_output() after pre-remove hooks run
-    repo.prepare_worktree_removal(
+    let result = repo.prepare_worktree_removal(
         RemoveTarget::Branch(worktree_name),
         BranchDeletionMode::from_flags(no_delete_branch, force_delete),
         force_worktree,
         config,
-    )
+    )?;
+    confirm_removal(&repo, &result, yes, config)?;
+    Ok(result)
 }
 
 /// Handle removing the current worktree (supports detached HEAD state).
@@ -33,15 +39,111 @@ pub fn handle_remove_current(
     no_delete_branch: bool,
     force_delete: bool,
     force_worktree: bool,
+    yes: bool,
     config: &UserConfig,
 ) -> anyhow::Result<RemoveResult> {
     let repo = Repository::current()?;
 
     // Progress message is shown in handle_removed_worktree_output() after pre-remove hooks run
-    repo.prepare_worktree_removal(
+    let result = repo.prepare_worktree_removal(
         RemoveTarget::Current,
         BranchDeletionMode::from_flags(no_delete_branch, force_delete),
         force_worktree,
         config,
-    )
+    )?;
+    confirm_removal(&repo, &result, yes, config)?;
+    Ok(result)
+}
+
+/// Ask before removing a worktree, according to the `remove-confirm` setting.
+///
+/// With the default (`unsafe`), only asks when removal would lose commits that
+/// aren't in the default branch: force-deleting an unmerged branch, or removing
+/// a detached worktree that's ahead. When a force-deleted branch also has
+/// commits not pushed to its upstream, the prompt says so. `--yes` skips this
+/// check.
+///
+/// The prompt reads stdin, so it works under shell integration too. Without a
+/// terminal there's no one to ask, so we warn and require `--yes` instead.
+fn confirm_removal(
+    repo: &Repository,
+    result: &RemoveResult,
+    yes: bool,
+    config: &UserConfig,
+) -> anyhow::Result<()> {
+    // Branch-only removals don't touch a worktree; branch deletion has its own safety check
+    let RemoveResult::RemovedWorktree {
+        branch_name,
+        deletion_mode,
+        target_branch,
+        removed_commit,
+        ..
+    } = result
+    else {
+        return Ok(());
+    };
+    if yes {
+        return Ok(());
+    }
+
+    let project = repo.project_identifier().ok();
+    let label = branch_name.as_deref().unwrap_or("worktree");
+    let prompt = match config.remove_confirm(project.as_deref()) {
+        RemoveConfirm::Never => return Ok(()),
+        RemoveConfirm::Always => cformat!("Remove <bold>{label}</>?"),
+        RemoveConfirm::Unsafe => {
+            // Commits stay reachable while the branch is kept (safe delete refuses unmerged)
+            if !deletion_mode.is_force() && branch_name.is_some() {
+                return Ok(());
+            }
+            let (Some(target), Some(commit)) = (target_branch, removed_commit) else {
+                return Ok(());
+            };
+            if let Some(branch) = branch_name
+                && repo
+                    .integration_reason(branch, target)
+                    .is_ok_and(|(_, reason)| reason.is_some())
+            {
+                return Ok(());
+            }
+            let ahead = repo.count_commits(target, commit)?;
+            if ahead == 0 {
+                return Ok(());
+            }
+            let commits = if ahead == 1 { "commit" } else { "commits" };
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "<bold>{label}</> has {ahead} {commits} not in <bold>{target}</> that will be lost"
+                ))
+            );
+            // The upstream may be the only other copy; say so when it's behind too
+            if let Some(branch) = branch_name
+                && repo
+                    .has_unpushed_commits(branch)
+                    .is_ok_and(|unpushed| unpushed)
+                && let Ok(Some(upstream)) = repo.branch(branch).upstream()
+            {
+                eprintln!(
+                    "{}",
+                    warning_message(cformat!(
+                        "<bold>{label}</> has commits not pushed to <bold>{upstream}</>"
+                    ))
+                );
+            }
+            cformat!("Remove <bold>{label}</> anyway?")
+        }
+    };
+
+    match confirm(&prompt)? {
+        None => Err(GitError::RemoveNeedsConfirmation {
+            branch: branch_name.clone(),
+        }
+        .into()),
+        Some(true) => Ok(()),
+        Some(false) => Err(GitError::Other {
+            message: cformat!("Cancelled removal of <bold>{label}</>"),
+        }
+        .into()),
+    }
 }
@@ -79,6 +79,19 @@ pub fn compute_worktree_path(
     repo: &Repository,
     branch: &str,
     config: &UserConfig,
+) -> anyhow::Result<PathBuf> {
+    compute_worktree_path_with_template(repo, branch, config, None)
+}
+
+/// Compute the expected worktree path, optionally from an explicit template.
+///
+/// `template` overrides the configured `worktree-path` (used by
+/// `wt switch --template`). The default branch still maps to the repo root.
+pub fn compute_worktree_path_with_template(
+    repo: &Repository,
+    branch: &str,
+    config: &UserConfig,
+    template: Option<&str>,
 ) -> anyhow::Result<PathBuf> {
     let repo_root = repo.repo_path();
     let default_branch = repo.default_branch().unwrap_or_default();
@@ -90,7 +103,23 @@ pub fn compute_worktree_path(
         return Ok(repo_root.to_path_buf());
     }
 
-    let repo_name = repo_root
+    let repo_name = repo_dir_name(repo_root)?;
+
+    let expanded_path = match template {
+        Some(template) => UserConfig::format_path_with_template(template, repo_name, branch, repo),
+        None => {
+            let project = repo.project_identifier().ok();
+            config.format_path(repo_name, branch, repo, project.as_deref())
+        }
+    }
+    .map_err(|e| anyhow::anyhow!("Failed to format worktree path: {e}"))?;
+
+    Ok(repo_root.join(expanded_path).normalize())
+}
+
+/// The repository directory's name, for the `repo` template variable.
+fn repo_dir_name(repo_root: &Path) -> anyhow::Result<&str> {
+    repo_root
         .file_name()
         .ok_or_else(|| {
             anyhow::anyhow!(
@@ -104,14 +133,93 @@ pub fn compute_worktree_path(
                 "Repository path contains invalid UTF-8: {}",
                 format_path_for_display(repo_root)
             )
-        })?;
+        })
+}
 
-    let project = repo.project_identifier().ok();
-    let expanded_path = config
-        .format_path(repo_name, branch, repo, project.as_deref())
-        .map_err(|e| anyhow::anyhow!("Failed to format worktree path: {e}"))?;
+/// Compute the worktree base: the directory the path template puts worktrees in.
+///
+/// The template is rendered with a placeholder branch name, and the base is the
+/// path up to the component holding it, so blocks and filters behave as they do
+/// for the full path. For the default template it's the directory containing
+/// the repository; for `.worktrees/{{ branch }}` it's `.worktrees`, whatever
+/// slashes the branch name has.
+pub(crate) fn compute_worktree_base(
+    repo: &Repository,
+    config: &UserConfig,
+    template: Option<&str>,
+) -> anyhow::Result<PathBuf> {
+    let repo_root = repo.repo_path();
+    let configured;
+    let template = match template {
+        Some(template) => template,
+        None => {
+            configured = match repo.project_identifier() {
+                Ok(project) => config.worktree_path_for_project(&project),
+                Err(_) => config.worktree_path(),
+            };
+            &configured
+        }
+    };
+
+    let rendered = UserConfig::format_path_with_template(
+        template,
+        repo_dir_name(repo_root)?,
+        BRANCH_SENTINEL,
+        repo,
+    )
+    .map_err(|e| anyhow::anyhow!("Failed to format worktree path: {e}"))?;
+    Ok(repo_root.join(rendered_base_dir(&rendered)).normalize())
+}
 
-    Ok(repo_root.join(expanded_path).normalize())
+/// Stand-in branch name for finding where the branch lands in a rendered template.
+///
+/// Lowercase letters only, so filters like `sanitize` pass it through unchanged.
+const BRANCH_SENTINEL: &str = "wtbranchsentinel";
+
+/// The directory part of a rendered path, before the component holding
+/// [`BRANCH_SENTINEL`] (or the last component, if the branch isn't used).
+fn rendered_base_dir(rendered: &str) -> &str {
+    let end = rendered.find(BRANCH_SENTINEL).unwrap_or(rendered.len());
+    let prefix = &rendered[..end];
+    &prefix[..prefix.rfind(['/', '\\']).unwrap_or(0)]
+}
+
+/// Reject a templated worktree path that lands outside the worktree base.
+///
+/// The base is the directory holding the branch's component (see
+/// [`compute_worktree_base`]), so only templates that climb back out after it,
+/// like `{{ branch }}/../../elsewhere`, fail. The default branch maps to the
+/// repository itself and isn't checked.
+pub(crate) fn ensure_path_within_base(
+    repo: &Repository,
+    path: &Path,
+    base: &Path,
+) -> anyhow::Result<()> {
+    if path == repo.repo_path() || path.starts_with(base) {
+        return Ok(());
+    }
+    anyhow::bail!(
+        "Worktree path {} falls outside {}; check the worktree-path template",
+        format_path_for_display(path),
+        format_path_for_display(base)
+    );
+}
+
+/// Place a `wt switch --create --name` directory in the worktree base.
+///
+/// The template still decides where worktrees live (see
+/// [`compute_worktree_base`]); `name` only picks the directory within it.
+/// Rejects absolute paths and `..` so the result can't escape that base.
+pub(crate) fn worktree_path_with_name(base: &Path, name: &str) -> anyhow::Result<PathBuf> {
+    let relative = Path::new(name);
+    let is_plain_relative = !name.is_empty()
+        && relative
+            .components()
+            .all(|c| matches!(c, std::path::Component::Normal(_)));
+    if !is_plain_relative {
+        anyhow::bail!("--name must be a relative directory name, got '{name}'");
+    }
+    Ok(base.join(relative))
 }
 
 /// Check if a worktree is at its expected path based on config template.
@@ -344,6 +452,40 @@ mod tests {
         assert!(generate_backup_path(&path, "20250101-000000").is_err());
     }
 
+    #[test]
+    fn test_worktree_path_with_name() {
+        let base = Path::new("/code");
+        assert_eq!(
+            worktree_path_with_name(base, "scratch").unwrap(),
+            PathBuf::from("/code/scratch")
+        );
+        assert_eq!(
+            worktree_path_with_name(base, "tmp/scratch").unwrap(),
+            PathBuf::from("/code/tmp/scratch")
+        );
+        assert!(worktree_path_with_name(base, "").is_err());
+        assert!(worktree_path_with_name(base, "../escape").is_err());
+        assert!(worktree_path_with_name(base, "/abs/path").is_err());
+    }
+
+    #[test]
+    fn test_rendered_base_dir() {
+        assert_eq!(
+            rendered_base_dir("/code/repo/../repo.wtbranchsentinel"),
+            "/code/repo/.."
+        );
+        assert_eq!(
+            rendered_base_dir(".worktrees/wtbranchsentinel"),
+            ".worktrees"
+        );
+        assert_eq!(rendered_base_dir("wtbranchsentinel"), "");
+        assert_eq!(
+            rendered_base_dir("~/wt/repo/wtbranchsentinel_a1b/x"),
+            "~/wt/repo"
+        );
+        assert_eq!(rendered_base_dir("../fixed/name"), "../fixed");
+    }
+
     #[test]
     fn test_paths_match_identical() {
         let path = PathBuf::from("/tmp/test");
@@ -11,14 +11,18 @@ use worktrunk::config::UserConfig;
 use worktrunk::git::remote_ref::{
     self, GitHubProvider, GitLabProvider, RemoteRefInfo, RemoteRefProvider,
 };
-use worktrunk::git::{GitError, RefContext, RefType, Repository};
+use worktrunk::git::{GitError, RefContext, RefType, Repository, WorktreeCheckout};
 use worktrunk::styling::{
-    eprintln, format_with_gutter, hint_message, info_message, progress_message, suggest_command,
+    eprintln, format_with_gutter, info_message, print_hint, print_progress, suggest_command,
     warning_message,
 };
+use worktrunk::utils::closest_match;
 
-use super::resolve::{compute_clobber_backup, compute_worktree_path, paths_match};
-use super::types::{CreationMethod, SwitchBranchInfo, SwitchPlan, SwitchResult};
+use super::resolve::{
+    compute_clobber_backup, compute_worktree_base, compute_worktree_path_with_template,
+    ensure_path_within_base, paths_match, worktree_path_with_name,
+};
+use super::types::{CreationMethod, SwitchBranchInfo, SwitchPlan, SwitchPlanOptions, SwitchResult};
 use crate::commands::command_executor::CommandContext;
 
 /// Result of resolving the switch target.
@@ -70,10 +74,7 @@ fn resolve_remote_ref(
     }
 
     // Fetch ref info (network call via gh/glab CLI)
-    eprintln!(
-        "{}",
-        progress_message(cformat!("Fetching {} {symbol}{number}...", ref_type.name()))
-    );
+    print_progress(cformat!("Fetching {} {symbol}{number}...", ref_type.name()));
 
     let repo_root = repo.repo_path();
     let info = provider.fetch_info(number, repo_root)?;
@@ -296,10 +297,7 @@ fn resolve_same_repo_ref(
             })?;
         let branch = &info.source_branch;
 
-        eprintln!(
-            "{}",
-            progress_message(cformat!("Fetching <bold>{branch}</> from {remote}..."))
-        );
+        print_progress(cformat!("Fetching <bold>{branch}</> from {remote}..."));
         // Use -- to prevent branch names starting with - from being interpreted as flags
         repo.run_command(&["fetch", "--", &remote, branch])
             .with_context(|| format!("Failed to fetch branch '{}' from {}", branch, remote))?;
@@ -316,6 +314,28 @@ fn resolve_same_repo_ref(
     })
 }
 
+/// Whether a base names the current worktree's `HEAD` (`HEAD`, `HEAD~2`, `HEAD^`).
+fn is_head_relative(base: &str) -> bool {
+    base.strip_prefix("HEAD")
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(['~', '^']))
+}
+
+/// Name used for a `--detach` worktree's path: `detached-<short SHA>`.
+fn detached_worktree_name(repo: &Repository, commitish: &str) -> anyhow::Result<String> {
+    let sha = repo
+        .current_worktree()
+        .run_command(&[
+            "rev-parse",
+            "--short",
+            "--verify",
+            &format!("{commitish}^{{commit}}"),
+        ])
+        .map_err(|_| GitError::ReferenceNotFound {
+            reference: commitish.to_string(),
+        })?;
+    Ok(format!("detached-{}", sha.trim()))
+}
+
 /// Resolve the switch target, handling pr:/mr: syntax and --create/--base flags.
 ///
 /// This is the first phase of planning: determine what branch we're switching to
@@ -324,8 +344,24 @@ fn resolve_switch_target(
     repo: &Repository,
     branch: &str,
     create: bool,
+    detach: bool,
     base: Option<&str>,
 ) -> anyhow::Result<ResolvedTarget> {
+    // --detach: the positional is a commit-ish, not a branch (clap rejects --create/--base)
+    if detach {
+        let commitish = repo.resolve_worktree_name(branch)?;
+        if !repo.ref_exists(&commitish)? {
+            return Err(GitError::ReferenceNotFound {
+                reference: commitish,
+            }
+            .into());
+        }
+        return Ok(ResolvedTarget {
+            branch: commitish,
+            method: CreationMethod::Detached,
+        });
+    }
+
     // Handle pr:<number> syntax
     if let Some(suffix) = branch.strip_prefix("pr:")
         && let Ok(number) = suffix.parse::<u32>()
@@ -354,7 +390,19 @@ fn resolve_switch_target(
             );
             None
         } else {
-            let resolved = repo.resolve_worktree_name(base_str)?;
+            let mut resolved = repo.resolve_worktree_name(base_str)?;
+            // `HEAD` (and `HEAD~N`) means this worktree's commit; pin it to a SHA so the
+            // new branch starts exactly here and the base shown is meaningful
+            if is_head_relative(&resolved) {
+                resolved = repo
+                    .current_worktree()
+                    .run_command(&["rev-parse", "--short", "--verify", &resolved])
+                    .map_err(|_| GitError::ReferenceNotFound {
+                        reference: resolved.clone(),
+                    })?
+                    .trim()
+                    .to_string();
+            }
             if !repo.ref_exists(&resolved)? {
                 return Err(GitError::ReferenceNotFound {
                     reference: resolved,
@@ -377,9 +425,15 @@ fn resolve_switch_target(
             .into());
         }
 
-        // Warn if --create would shadow a remote branch
+        // Warn if --create would shadow a remote branch (unless that remote branch
+        // is the base, in which case the new branch tracks it)
         let remotes = branch_handle.remotes()?;
-        if !remotes.is_empty() {
+        let base_is_same_remote_branch = resolved_base.as_deref().is_some_and(|base| {
+            remotes
+                .iter()
+                .any(|remote| base == format!("{remote}/{resolved_branch}"))
+        });
+        if !remotes.is_empty() && !base_is_same_remote_branch {
             let remote_ref = format!("{}/{}", remotes[0], resolved_branch);
             eprintln!(
                 "{}",
@@ -389,12 +443,9 @@ fn resolve_switch_target(
             );
             let remove_cmd = suggest_command("remove", &[&resolved_branch], &[]);
             let switch_cmd = suggest_command("switch", &[&resolved_branch], &[]);
-            eprintln!(
-                "{}",
-                hint_message(cformat!(
-                    "To switch to the remote branch, delete this branch and run without <bright-black>--create</>: <bright-black>{remove_cmd} && {switch_cmd}</>"
-                ))
-            );
+            print_hint(cformat!(
+                "To switch to the remote branch, delete this branch and run without <bright-black>--create</>: <bright-black>{remove_cmd} && {switch_cmd}</>"
+            ));
         }
     }
 
@@ -409,12 +460,9 @@ fn resolve_switch_target(
                         "Configured default branch <bold>{configured}</> does not exist locally"
                     ))
                 );
-                eprintln!(
-                    "{}",
-                    hint_message(cformat!(
-                        "To reset, run <bright-black>wt config state default-branch clear</>"
-                    ))
-                );
+                print_hint(cformat!(
+                    "To reset, run <bright-black>wt config state default-branch clear</>"
+                ));
             }
             repo.resolve_target_branch(None)
                 .ok()
@@ -480,11 +528,15 @@ fn validate_worktree_creation(
         create_branch: false,
         ..
     } = method
-        && !repo.branch(branch).exists()?
+        && !repo.branch_exists(branch, true)?
     {
+        // Only pay for listing branches on the error path
+        let available = repo.available_branches().unwrap_or_default();
+        let suggestion = closest_match(branch, available.iter().map(String::as_str));
         return Err(GitError::BranchNotFound {
             branch: branch.to_string(),
             show_create_hint: true,
+            suggestion: suggestion.map(str::to_string),
         }
         .into());
     }
@@ -543,14 +595,9 @@ fn setup_fork_branch(
         .with_context(|| format!("Failed to create local branch '{}' from {}", branch, label))?;
 
     // Configure branch tracking for pull and push
-    let branch_remote_key = format!("branch.{}.remote", branch);
-    let branch_merge_key = format!("branch.{}.merge", branch);
     let merge_ref = format!("refs/{}", remote_ref);
-
-    repo.run_command(&["config", &branch_remote_key, remote])
-        .with_context(|| format!("Failed to configure branch.{}.remote", branch))?;
-    repo.run_command(&["config", &branch_merge_key, &merge_ref])
-        .with_context(|| format!("Failed to configure branch.{}.merge", branch))?;
+    repo.set_branch_tracking(branch, remote, &merge_ref)
+        .with_context(|| format!("Failed to configure tracking for branch {}", branch))?;
 
     // Only configure pushRemote if we have a fork URL (not using prefixed branch)
     if let Some(url) = fork_push_url {
@@ -559,25 +606,65 @@ fn setup_fork_branch(
             .with_context(|| format!("Failed to configure branch.{}.pushRemote", branch))?;
     }
 
-    // Create worktree (delayed streaming: silent if fast, shows progress if slow)
-    // Use -- to prevent branch names starting with - from being interpreted as flags
-    let worktree_path_str = worktree_path.to_string_lossy();
-    repo.run_command_delayed_stream(
-        &["worktree", "add", "--", worktree_path_str.as_ref(), branch],
-        Repository::SLOW_OPERATION_DELAY_MS,
-        Some(
-            progress_message(cformat!("Creating worktree for <bold>{}</>...", branch)).to_string(),
-        ),
-    )
-    .map_err(|e| GitError::WorktreeCreationFailed {
-        branch: branch.to_string(),
-        base_branch: None,
-        error: e.to_string(),
-    })?;
+    repo.create_worktree(worktree_path, branch, WorktreeCheckout::Existing)?;
 
     Ok(())
 }
 
+/// Copy untracked files (`--copy-env` / `copy-on-create`) from the current
+/// worktree into a newly created one.
+///
+/// Missing sources are skipped and existing destinations are left untouched, so
+/// files checked into the branch win. Paths must stay inside the worktree.
+fn copy_env_files(repo: &Repository, files: &[String], worktree_path: &Path) -> anyhow::Result<()> {
+    let Ok(source_root) = repo.current_worktree().root() else {
+        eprintln!(
+            "{}",
+            warning_message("Not copying env files: not inside a worktree")
+        );
+        return Ok(());
+    };
+
+    let mut copied = Vec::new();
+    for file in files {
+        let relative = Path::new(file);
+        let is_contained = relative
+            .components()
+            .all(|c| matches!(c, std::path::Component::Normal(_)));
+        if !is_contained || file.is_empty() {
+            eprintln!(
+                "{}",
+                warning_message(cformat!(
+                    "Not copying <bold>{file}</>: path must be relative to the worktree root"
+                ))
+            );
+            continue;
+        }
+
+        let source = source_root.join(relative);
+        let dest = worktree_path.join(relative);
+        if !source.is_file() || dest.exists() {
+            continue;
+        }
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory for {file}"))?;
+        }
+        std::fs::copy(&source, &dest).with_context(|| format!("Failed to copy {file}"))?;
+        copied.push(file.as_str());
+    }
+
+    if !copied.is_empty() {
+        let list = copied
+            .iter()
+            .map(|f| cformat!("<bold>{f}</>"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        eprintln!("{}", info_message(format!("Copied {list}")));
+    }
+    Ok(())
+}
+
 /// Validate and plan a switch operation.
 ///
 /// This performs all validation upfront, returning a `SwitchPlan` that can be
@@ -588,29 +675,57 @@ fn setup_fork_branch(
 /// are printed during planning since they're informational, not blocking.
 pub fn plan_switch(
     repo: &Repository,
-    branch: &str,
-    create: bool,
-    base: Option<&str>,
-    clobber: bool,
+    opts: SwitchPlanOptions<'_>,
     config: &UserConfig,
 ) -> anyhow::Result<SwitchPlan> {
+    let SwitchPlanOptions {
+        branch,
+        create,
+        detach,
+        base,
+        clobber,
+        template,
+        name,
+    } = opts;
+
+    // Validate --template before any other work (it's a pure config lookup)
+    let template = template
+        .map(|name| config.named_worktree_path(name))
+        .transpose()?;
+
     // Record current branch for `wt switch -` support
-    let new_previous = repo.current_worktree().branch().ok().flatten();
+    let new_previous = repo.current_branch();
 
     // Phase 1: Resolve target (handles pr:, validates --create/--base, may do network)
-    let target = resolve_switch_target(repo, branch, create, base)?;
-
-    // Phase 2: Compute expected path
-    let expected_path = compute_worktree_path(repo, &target.branch, config)?;
+    let target = resolve_switch_target(repo, branch, create, detach, base)?;
+
+    // Phase 2: Compute expected path (from the named template when --template is given,
+    // with the final directory replaced when --name is given)
+    // Detached worktrees are named after the commit, so a commit-ish that's also a
+    // branch name (`--detach main`) doesn't resolve to that branch's worktree path
+    let path_name = match target.method {
+        CreationMethod::Detached => detached_worktree_name(repo, &target.branch)?,
+        _ => target.branch.clone(),
+    };
+    let worktree_base = compute_worktree_base(repo, config, template)?;
+    let expected_path = match name {
+        Some(name) => worktree_path_with_name(&worktree_base, name)?,
+        None => compute_worktree_path_with_template(repo, &path_name, config, template)?,
+    };
 
     // Phase 3: Check if worktree already exists for this branch
-    if let Some(existing) =
-        check_existing_worktree(repo, &target.branch, &expected_path, new_previous.clone())?
+    // (--detach always creates a new worktree; its target isn't a branch)
+    if !matches!(target.method, CreationMethod::Detached)
+        && let Some(existing) =
+            check_existing_worktree(repo, &target.branch, &expected_path, new_previous.clone())?
     {
         return Ok(existing);
     }
 
-    // Phase 4: Validate we can create at this path
+    // Phase 4: Validate we can create at this path (`--name` is placed in the base already)
+    if name.is_none() {
+        ensure_path_within_base(repo, &expected_path, &worktree_base)?;
+    }
     let clobber_backup = validate_worktree_creation(
         repo,
         &target.branch,
@@ -640,6 +755,7 @@ pub fn execute_switch(
     config: &UserConfig,
     force: bool,
     no_verify: bool,
+    copy_env: bool,
 ) -> anyhow::Result<(SwitchResult, SwitchBranchInfo)> {
     match plan {
         SwitchPlan::Existing {
@@ -709,6 +825,13 @@ pub fn execute_switch(
 
             // Execute based on creation method
             let (created_branch, base_branch, from_remote) = match &method {
+                CreationMethod::Detached => {
+                    // `branch` holds the commit-ish for detached worktrees
+                    repo.create_worktree(&worktree_path, &branch, WorktreeCheckout::Detached)?;
+
+                    (false, None, None)
+                }
+
                 CreationMethod::Regular {
                     create_branch,
                     base_branch,
@@ -718,56 +841,47 @@ pub fn execute_switch(
                     let local_branch_existed =
                         !create_branch && branch_handle.exists_locally().unwrap_or(false);
 
-                    // Build git worktree add command
-                    let worktree_path_str = worktree_path.to_string_lossy();
-                    let mut args = vec!["worktree", "add", worktree_path_str.as_ref()];
-
-                    if *create_branch {
-                        args.push("-b");
-                        args.push(&branch);
-                        if let Some(base) = base_branch {
-                            args.push(base);
+                    let checkout = if *create_branch {
+                        WorktreeCheckout::NewBranch {
+                            base: base_branch.as_deref(),
                         }
                     } else {
-                        args.push(&branch);
-                    }
-
-                    // Delayed streaming: silent if fast, shows progress if slow
-                    let progress_msg = Some(
-                        progress_message(cformat!("Creating worktree for <bold>{}</>...", branch))
-                            .to_string(),
-                    );
-                    if let Err(e) = repo.run_command_delayed_stream(
-                        &args,
-                        Repository::SLOW_OPERATION_DELAY_MS,
-                        progress_msg,
-                    ) {
-                        return Err(GitError::WorktreeCreationFailed {
-                            branch: branch.clone(),
-                            base_branch: base_branch.clone(),
-                            error: e.to_string(),
+                        WorktreeCheckout::Existing
+                    };
+                    repo.create_worktree(&worktree_path, &branch, checkout)?;
+
+                    let remote_base = base_branch
+                        .as_deref()
+                        .filter(|base| *create_branch && repo.is_remote_tracking_branch(base));
+
+                    // Upstream for a new branch created from a remote tracking branch:
+                    // - Same name (`--create feature --base origin/feature`): track it, so
+                    //   pull/push go to the branch the user asked for.
+                    // - Different name (`--create feature --base origin/main`): unset the
+                    //   upstream git sets by default, since `git push` would push to main
+                    //   instead of the feature branch.
+                    //   See: https://github.com/max-sixty/worktrunk/issues/713
+                    let mut tracked_remote_base = None;
+                    if let Some(remote_base) = remote_base {
+                        let same_name = branch_handle
+                            .remotes()?
+                            .iter()
+                            .any(|remote| remote_base == format!("{remote}/{branch}"));
+                        if same_name {
+                            repo.set_upstream(&branch, remote_base)?;
+                            tracked_remote_base = Some(remote_base.to_string());
+                        } else {
+                            // Unset the upstream to prevent accidental pushes
+                            branch_handle.unset_upstream()?;
                         }
-                        .into());
                     }
 
-                    // Safety: unset unsafe upstream when creating a new branch from a remote
-                    // tracking branch. When `git worktree add -b feature origin/main` runs,
-                    // git sets feature to track origin/main. This is dangerous because
-                    // `git push` would push to main instead of the feature branch.
-                    // See: https://github.com/max-sixty/worktrunk/issues/713
-                    if *create_branch
-                        && let Some(base) = base_branch
-                        && repo.is_remote_tracking_branch(base)
-                    {
-                        // Unset the upstream to prevent accidental pushes
-                        branch_handle.unset_upstream()?;
-                    }
-
-                    // Report tracking info only if git's DWIM created the branch from a remote
+                    // Report tracking info if git's DWIM created the branch from a remote,
+                    // or if --create set up tracking for a same-named remote base
                     let from_remote = if !create_branch && !local_branch_existed {
                         branch_handle.upstream()?
                     } else {
-                        None
+                        tracked_remote_base
                     };
 
                     (*create_branch, base_branch.clone(), from_remote)
@@ -801,7 +915,7 @@ pub fn execute_switch(
 
                     if let Err(e) = setup_result {
                         // Cleanup: try to delete the branch if it was created
-                        let _ = repo.run_command(&["branch", "-D", "--", &branch]);
+                        let _ = repo.delete_branch(&branch, true);
                         return Err(e);
                     }
 
@@ -821,11 +935,8 @@ pub fn execute_switch(
                                 "Using prefixed branch name <bold>{branch}</> due to name conflict"
                             ))
                         );
-                        eprintln!(
-                            "{}",
-                            hint_message(
-                                "Push to fork is not supported with prefixed branches; feedback welcome at https://github.com/max-sixty/worktrunk/issues/714",
-                            )
+                        print_hint(
+                            "Push to fork is not supported with prefixed branches; feedback welcome at https://github.com/max-sixty/worktrunk/issues/714",
                         );
                     }
 
@@ -839,11 +950,21 @@ pub fn execute_switch(
                 .and_then(|b| repo.worktree_for_branch(b).ok().flatten())
                 .map(|p| worktrunk::path::to_posix_path(&p.to_string_lossy()));
 
+            let detached = matches!(method, CreationMethod::Detached);
+
+            // Copy env files before hooks, which commonly need them (e.g. `direnv allow`)
+            if let Some(files) = config.copy_on_create(copy_env) {
+                copy_env_files(repo, &files, &worktree_path)?;
+            }
+
             // Execute post-create commands
             if !no_verify {
-                let ctx = CommandContext::new(repo, config, Some(&branch), &worktree_path, force);
+                // Detached worktrees have no branch; hooks see `HEAD`, as elsewhere
+                let hook_branch = (!detached).then_some(branch.as_str());
+                let ctx = CommandContext::new(repo, config, hook_branch, &worktree_path, force);
 
                 match &method {
+                    CreationMethod::Detached => ctx.execute_post_create_commands(&[])?,
                     CreationMethod::Regular { base_branch, .. } => {
                         let extra_vars: Vec<(&str, &str)> = [
                             base_branch.as_ref().map(|b| ("base", b.as_str())),
@@ -884,6 +1005,7 @@ pub fn execute_switch(
                     base_branch,
                     base_worktree_path,
                     from_remote,
+                    detached,
                 },
                 SwitchBranchInfo {
                     branch,
@@ -32,6 +32,8 @@ pub enum SwitchResult {
         /// Remote tracking branch if created from remote (e.g., "origin/feature")
         /// This is set when git's DWIM created a local branch from a remote
         from_remote: Option<String>,
+        /// True for `--detach`: the worktree has no branch, checked out at a commit
+        detached: bool,
     },
 }
 
@@ -44,6 +46,11 @@ impl SwitchResult {
             SwitchResult::Created { path, .. } => path,
         }
     }
+
+    /// Whether a detached-HEAD worktree was created (`--detach`).
+    pub fn is_detached(&self) -> bool {
+        matches!(self, SwitchResult::Created { detached: true, .. })
+    }
 }
 
 /// Branch state for a switch operation.
@@ -85,6 +92,28 @@ pub enum CreationMethod {
         /// Resolved remote name where PR/MR refs live (e.g., "origin", "upstream").
         remote: String,
     },
+    /// `--detach`: use `git worktree add --detach` at a commit-ish, no branch.
+    ///
+    /// The commit-ish (validated during planning) is also used in place of the
+    /// branch name for the path template.
+    Detached,
+}
+
+/// What `plan_switch()` should plan: the target and the flags that shape it.
+///
+/// Unset fields mean the flag wasn't given.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SwitchPlanOptions<'a> {
+    /// Branch name, or commit-ish with `detach`
+    pub branch: &'a str,
+    pub create: bool,
+    pub detach: bool,
+    pub base: Option<&'a str>,
+    pub clobber: bool,
+    /// Named `[templates]` entry to use instead of `worktree-path`
+    pub template: Option<&'a str>,
+    /// Directory name for the new worktree, replacing the template's (`--name`)
+    pub name: Option<&'a str>,
 }
 
 /// Validated plan for a switch operation.
@@ -253,6 +282,7 @@ mod tests {
             base_branch: Some("main".to_string()),
             base_worktree_path: Some("/test/main".to_string()),
             from_remote: None,
+            detached: false,
         };
         assert_eq!(result.path(), &path);
     }
@@ -266,6 +296,7 @@ mod tests {
             base_branch: None,
             base_worktree_path: None,
             from_remote: Some("origin/feature".to_string()),
+            detached: false,
         };
         assert_eq!(result.path(), &path);
     }
@@ -7,7 +7,7 @@ use clap_complete::engine::{ArgValueCompleter, CompletionCandidate, ValueComplet
 use clap_complete::env::CompleteEnv;
 
 use crate::cli;
-use crate::display::format_relative_time_short;
+use crate::display::{format_relative_time_short, truncate_to_width};
 use worktrunk::config::{ProjectConfig, UserConfig};
 use worktrunk::git::{BranchCategory, HookType, Repository};
 
@@ -16,6 +16,9 @@ use worktrunk::git::{BranchCategory, HookType, Repository};
 /// that appear when completing `--`.
 const DEPRECATED_ARGS: &[&str] = &["--no-background"];
 
+/// Maximum display width of the commit subject in fish and zsh branch descriptions.
+const MAX_SUBJECT_WIDTH: usize = 40;
+
 /// Handle shell-initiated completion requests via `COMPLETE=$SHELL wt`
 pub(crate) fn maybe_handle_env_completion() -> bool {
     let Some(shell_name) = std::env::var_os("COMPLETE") else {
@@ -27,7 +30,12 @@ pub(crate) fn maybe_handle_env_completion() -> bool {
     }
 
     let mut args: Vec<OsString> = std::env::args_os().collect();
-    CONTEXT.with(|ctx| *ctx.borrow_mut() = Some(CompletionContext { args: args.clone() }));
+    CONTEXT.with(|ctx| {
+        *ctx.borrow_mut() = Some(CompletionContext {
+            args: args.clone(),
+            shell: shell_name.to_string_lossy().into_owned(),
+        })
+    });
 
     // Remove the binary name and find the `--` separator
     args.remove(0);
@@ -320,7 +328,10 @@ fn complete_branches(
         return Vec::new();
     }
 
-    let branches = match Repository::current().and_then(|repo| repo.branches_for_completion()) {
+    let Ok(repo) = Repository::current() else {
+        return Vec::new();
+    };
+    let branches = match repo.branches_for_completion() {
         Ok(b) => b,
         Err(_) => return Vec::new(),
     };
@@ -329,6 +340,8 @@ fn complete_branches(
         return Vec::new();
     }
 
+    let with_subject = completing_with_descriptions();
+
     branches
         .into_iter()
         .filter(|branch| {
@@ -342,16 +355,32 @@ fn complete_branches(
         })
         .map(|branch| {
             let time_str = format_relative_time_short(branch.timestamp);
-            let help = match branch.category {
+            let mut help = match branch.category {
                 BranchCategory::Worktree => format!("+ {}", time_str),
                 BranchCategory::Local => format!("/ {}", time_str),
                 BranchCategory::Remote(remotes) => format!("⇣ {} {}", time_str, remotes.join(", ")),
             };
+            // Fish and zsh show a description column, so add the last commit subject
+            if with_subject && !branch.subject.is_empty() {
+                help.push_str(" · ");
+                help.push_str(&truncate_to_width(&branch.subject, MAX_SUBJECT_WIDTH));
+            }
             CompletionCandidate::new(branch.name).help(Some(help.into()))
         })
         .collect()
 }
 
+/// Whether completions are being generated for a shell that displays descriptions.
+///
+/// zsh receives `value:description` pairs and renders them via `_describe`.
+fn completing_with_descriptions() -> bool {
+    CONTEXT.with(|ctx| {
+        ctx.borrow()
+            .as_ref()
+            .is_some_and(|ctx| matches!(ctx.shell.as_str(), "fish" | "zsh"))
+    })
+}
+
 fn suppress_switch_branch_completion() -> bool {
     CONTEXT.with(|ctx| {
         ctx.borrow()
@@ -362,6 +391,8 @@ fn suppress_switch_branch_completion() -> bool {
 
 struct CompletionContext {
     args: Vec<OsString>,
+    /// Shell requesting completions (value of `COMPLETE`)
+    shell: String,
 }
 
 impl CompletionContext {
@@ -39,6 +39,7 @@ pub const TEMPLATE_VARS: &[&str] = &[
     "remote_url",
     "upstream",
     "target",             // Added by merge/rebase hooks via extra_vars
+    "squashed",           // Added by post-merge hooks via extra_vars
     "base",               // Added by creation hooks via extra_vars
     "base_worktree_path", // Added by creation hooks via extra_vars
 ];
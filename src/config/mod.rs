@@ -92,9 +92,10 @@ pub use project::{
     find_unknown_keys as find_unknown_project_keys,
 };
 pub use user::{
-    CommitConfig, CommitGenerationConfig, ListConfig, MergeConfig, OverridableConfig,
-    ResolvedConfig, SelectConfig, StageMode, UserConfig, UserProjectOverrides,
-    find_unknown_keys as find_unknown_user_keys, get_config_path, set_config_path,
+    CommitConfig, CommitGenerationConfig, ListColumn, ListConfig, MergeConfig, OverridableConfig,
+    PathStyle, RemoveConfirm, ResolvedConfig, SelectConfig, StageMode, ThemeConfig, TimeFormat,
+    UserConfig, UserProjectOverrides, find_unknown_keys as find_unknown_user_keys, get_config_path,
+    get_config_profile, resolve_editor, set_config_path, set_config_profile,
 };
 
 #[cfg(test)]
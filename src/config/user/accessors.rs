@@ -11,7 +11,7 @@ use crate::config::expansion::expand_template;
 use super::UserConfig;
 use super::merge::{Merge, merge_optional};
 use super::sections::{
-    CommitConfig, CommitGenerationConfig, ListConfig, MergeConfig, SelectConfig,
+    CommitConfig, CommitGenerationConfig, ListConfig, MergeConfig, RemoveConfirm, SelectConfig,
 };
 
 /// Default worktree path template
@@ -19,6 +19,18 @@ fn default_worktree_path() -> String {
     "{{ repo_path }}/../{{ repo }}.{{ branch | sanitize }}".to_string()
 }
 
+/// Pick the user's editor: `$VISUAL`, then `$EDITOR`, skipping empty values.
+///
+/// Shared by `wt switch --open` and `wt config edit`, which adds its own
+/// platform fallback.
+pub fn resolve_editor(visual: Option<String>, editor: Option<String>) -> Option<String> {
+    [visual, editor]
+        .into_iter()
+        .flatten()
+        .map(|e| e.trim().to_string())
+        .find(|e| !e.is_empty())
+}
+
 impl UserConfig {
     /// Returns the worktree path template, falling back to the default if not set.
     pub fn worktree_path(&self) -> String {
@@ -28,6 +40,56 @@ impl UserConfig {
             .unwrap_or_else(default_worktree_path)
     }
 
+    /// Returns the command `wt switch --open` runs, falling back to the
+    /// environment's editor (see [`resolve_editor`]) on `.`.
+    ///
+    /// `None` when neither is set.
+    pub fn editor_command(&self) -> Option<String> {
+        self.editor_command.clone().or_else(|| {
+            resolve_editor(std::env::var("VISUAL").ok(), std::env::var("EDITOR").ok())
+                .map(|editor| format!("{editor} ."))
+        })
+    }
+
+    /// Returns how far behind its upstream a `--create` base may be before
+    /// `wt switch` warns, or `None` when the check is disabled (set to 0).
+    pub fn stale_base_threshold(&self) -> Option<usize> {
+        match self.stale_base_threshold.unwrap_or(10) {
+            0 => None,
+            threshold => Some(threshold),
+        }
+    }
+
+    /// Returns whether `wt switch` reminds to restart the shell when shell
+    /// integration is installed but not active (default: true).
+    pub fn show_integration_hint(&self) -> bool {
+        self.show_integration_hint.unwrap_or(true)
+    }
+
+    /// Returns the files to copy into a new worktree, or `None` when nothing
+    /// should be copied.
+    ///
+    /// `copy-on-create` applies to every create; without it, `--copy-env`
+    /// (`copy_env`) copies `.env` and `.envrc`.
+    pub fn copy_on_create(&self, copy_env: bool) -> Option<Vec<String>> {
+        match &self.copy_on_create {
+            Some(files) => Some(files.clone()),
+            None if copy_env => Some(vec![".env".to_string(), ".envrc".to_string()]),
+            None => None,
+        }
+    }
+
+    /// Returns the `[theme]` colors, with built-in defaults for unset roles.
+    ///
+    /// Invalid values are rejected when the config loads, so this falls back
+    /// to the defaults only for configs built in code.
+    pub fn theme(&self) -> crate::styling::Theme {
+        self.theme
+            .as_ref()
+            .and_then(|theme| theme.theme().ok())
+            .unwrap_or_default()
+    }
+
     /// Returns true if the user has explicitly set a custom worktree-path.
     pub fn has_custom_worktree_path(&self) -> bool {
         self.configs.worktree_path.is_some()
@@ -44,6 +106,34 @@ impl UserConfig {
             .unwrap_or_else(|| self.worktree_path())
     }
 
+    /// Returns when `wt remove` should ask for confirmation in a specific project.
+    ///
+    /// Project-specific setting takes precedence over the global one.
+    pub fn remove_confirm(&self, project: Option<&str>) -> RemoveConfirm {
+        project
+            .and_then(|p| self.projects.get(p))
+            .and_then(|c| c.overrides.remove_confirm)
+            .or(self.configs.remove_confirm)
+            .unwrap_or_default()
+    }
+
+    /// Returns the named worktree path template from `[templates]`.
+    ///
+    /// Errors if no template has that name, listing the available names.
+    pub fn named_worktree_path(&self, name: &str) -> anyhow::Result<&str> {
+        if let Some(template) = self.templates.get(name) {
+            return Ok(template);
+        }
+        if self.templates.is_empty() {
+            anyhow::bail!("Unknown template '{name}': no [templates] configured in user config");
+        }
+        let available: Vec<&str> = self.templates.keys().map(String::as_str).collect();
+        anyhow::bail!(
+            "Unknown template '{name}'; available: {}",
+            available.join(", ")
+        )
+    }
+
     /// Returns the commit generation config for a specific project.
     ///
     /// Merges project-specific settings with global settings, where project
@@ -167,6 +257,18 @@ impl UserConfig {
             Some(p) => self.worktree_path_for_project(p),
             None => self.worktree_path(),
         };
+        Self::format_path_with_template(&template, main_worktree, branch, repo)
+    }
+
+    /// Format a worktree path from an explicit template (e.g., a named `[templates]` entry).
+    ///
+    /// Same variables as [`format_path`](Self::format_path).
+    pub fn format_path_with_template(
+        template: &str,
+        main_worktree: &str,
+        branch: &str,
+        repo: &crate::git::Repository,
+    ) -> Result<String, String> {
         // Use native path format (not POSIX) since this is used for filesystem operations
         let repo_path = repo.repo_path().to_string_lossy().to_string();
         let mut vars = HashMap::new();
@@ -174,7 +276,9 @@ impl UserConfig {
         vars.insert("repo", main_worktree);
         vars.insert("branch", branch);
         vars.insert("repo_path", repo_path.as_str());
+        // Expand `~` and `$VARS` in the template itself, so branch names never get expanded
+        let template = crate::path::expand_home_and_env(template)
+            .map_err(|e| format!("Failed to expand worktree-path: {e}"))?;
         expand_template(&template, &vars, false, repo, "worktree-path")
-            .map(|p| shellexpand::tilde(&p).into_owned())
     }
 }
@@ -18,13 +18,15 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // Re-export public types
+pub use accessors::resolve_editor;
 pub use merge::Merge;
-pub use path::{get_config_path, set_config_path};
+pub use path::{get_config_path, get_config_profile, set_config_path, set_config_profile};
 pub use resolved::ResolvedConfig;
 pub use schema::{find_unknown_keys, valid_user_config_keys};
 pub use sections::{
-    CommitConfig, CommitGenerationConfig, ListConfig, MergeConfig, OverridableConfig, SelectConfig,
-    StageMode, UserProjectOverrides,
+    CommitConfig, CommitGenerationConfig, ListColumn, ListConfig, MergeConfig, OverridableConfig,
+    PathStyle, RemoveConfirm, SelectConfig, StageMode, ThemeConfig, TimeFormat,
+    UserProjectOverrides,
 };
 
 /// User-level configuration for worktree path formatting and LLM integration.
@@ -50,6 +52,11 @@ pub use sections::{
 /// # Repository-namespaced (useful for shared directories with multiple repos)
 /// worktree-path = "../worktrees/{{ repo }}/{{ branch | sanitize }}"
 ///
+/// # Named templates for `wt switch --template <name>`
+/// [templates]
+/// fix = "../{{ repo }}/fixes/{{ branch | sanitize }}"
+/// feature = "../{{ repo }}/features/{{ branch | sanitize }}"
+///
 /// # Commit generation configuration
 /// [commit.generation]
 /// command = "llm -m claude-haiku-4.5"  # Shell command for generating commit messages
@@ -88,6 +95,68 @@ pub struct UserConfig {
     #[serde(flatten, default)]
     pub configs: OverridableConfig,
 
+    /// Named worktree path templates, selected with `wt switch --template <name>`
+    ///
+    /// Same syntax as `worktree-path`, which remains the default when no
+    /// `--template` is given.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub templates: std::collections::BTreeMap<String, String>,
+
+    /// Named profiles, selected with `--profile <name>` or `WORKTRUNK_CONFIG_PROFILE`
+    ///
+    /// Each `[profiles.<name>]` table takes the same keys as the top level and
+    /// overrides only the keys it sets (nested tables merge key by key).
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub profiles: std::collections::BTreeMap<String, serde_json::Value>,
+
+    /// Command `wt switch --open` runs in the worktree (default: `$VISUAL .` or `$EDITOR .`)
+    ///
+    /// Supports the same template variables as `--execute`.
+    #[serde(
+        rename = "editor-command",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub editor_command: Option<String>,
+
+    /// Warn when `wt switch --create` bases a branch on one this many commits
+    /// behind its upstream (default: 10, 0 disables the check)
+    #[serde(
+        rename = "stale-base-threshold",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub stale_base_threshold: Option<usize>,
+
+    /// Remind to restart the shell after `wt switch` when shell integration is
+    /// installed but not active (default: true)
+    ///
+    /// The install hint still shows when integration is missing.
+    #[serde(
+        rename = "show-integration-hint",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub show_integration_hint: Option<bool>,
+
+    /// Write git's commit-graph in the background on first `wt list`
+    ///
+    /// Only runs when the repository has no commit-graph yet; worktrunk
+    /// records that it did so and doesn't try again.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub maintenance: bool,
+
+    /// Untracked files `wt switch` copies from the current worktree into each
+    /// new worktree, e.g. `[".env", ".env.local"]`
+    ///
+    /// Unset, only `wt switch --copy-env` copies, using `.env` and `.envrc`.
+    #[serde(
+        default,
+        rename = "copy-on-create",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub copy_on_create: Option<Vec<String>>,
+
     /// Skip the first-run shell integration prompt
     #[serde(
         default,
@@ -103,6 +172,10 @@ pub struct UserConfig {
         skip_serializing_if = "std::ops::Not::not"
     )]
     pub skip_commit_generation_prompt: bool,
+
+    /// Colors for `wt list` columns
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<ThemeConfig>,
 }
 
 impl UserConfig {
@@ -111,13 +184,17 @@ impl UserConfig {
     /// Configuration is loaded in the following order (later sources override earlier ones):
     /// 1. Default values
     /// 2. Config file (see struct documentation for platform-specific paths)
-    /// 3. Environment variables (WORKTRUNK_*)
+    /// 3. Selected `[profiles.<name>]` table (`--profile` / `WORKTRUNK_CONFIG_PROFILE`)
+    /// 4. Environment variables (WORKTRUNK_*)
     pub fn load() -> Result<Self, ConfigError> {
         // Note: worktree-path has no default set here - it's handled by the getter
         // which returns the default when None. This allows us to distinguish
         // "user explicitly set this" from "using default".
         let mut builder = Config::builder();
 
+        let profile = path::get_config_profile();
+        let mut profile_source = None;
+
         // Add config file if it exists
         let config_path = get_config_path();
         if let Some(config_path) = config_path.as_ref()
@@ -151,6 +228,10 @@ impl UserConfig {
                     &unknown_keys,
                     "User config",
                 );
+
+                if let Some(profile) = &profile {
+                    profile_source = Some(profile_table(&content, profile, config_path)?);
+                }
             }
 
             builder = builder.add_source(File::from(config_path.clone()));
@@ -167,6 +248,20 @@ impl UserConfig {
             );
         }
 
+        // The profile table is a second TOML source over the file, so the config
+        // crate merges it key by key rather than replacing whole sections
+        match (profile_source, &profile) {
+            (Some(table), _) => {
+                builder = builder.add_source(File::from_str(&table, config::FileFormat::Toml));
+            }
+            (None, Some(profile)) => {
+                return Err(ConfigError::Message(format!(
+                    "Config profile '{profile}' not found: no user config file"
+                )));
+            }
+            (None, None) => {}
+        }
+
         // Add environment variables with WORKTRUNK prefix
         // - prefix_separator("_"): strip prefix with single underscore (WORKTRUNK_ → key)
         // - separator("__"): double underscore for nested fields (COMMIT__GENERATION__COMMAND → commit.generation.command)
@@ -188,6 +283,24 @@ impl UserConfig {
         Ok(config)
     }
 
+    /// Load configuration from a TOML string with a profile applied, for testing.
+    ///
+    /// Applies `profile` the same way `load()` applies `--profile`.
+    #[cfg(test)]
+    pub(crate) fn load_from_str_with_profile(
+        content: &str,
+        profile: &str,
+    ) -> Result<Self, ConfigError> {
+        let table = profile_table(content, profile, std::path::Path::new("config.toml"))?;
+        let config: Self = Config::builder()
+            .add_source(File::from_str(content, config::FileFormat::Toml))
+            .add_source(File::from_str(&table, config::FileFormat::Toml))
+            .build()?
+            .try_deserialize()?;
+        config.validate()?;
+        Ok(config)
+    }
+
     /// Load configuration from a TOML string for testing.
     #[cfg(test)]
     pub(crate) fn load_from_str(content: &str) -> Result<Self, ConfigError> {
@@ -197,3 +310,57 @@ impl UserConfig {
         Ok(config)
     }
 }
+
+/// Extract `[profiles.<name>]` from the config file as a standalone TOML document.
+///
+/// Errors when the profile doesn't exist, listing the profiles that do, or when
+/// it sets a key that isn't a user config setting. A selected profile is
+/// explicit, so a typo there fails rather than silently changing nothing.
+fn profile_table(
+    content: &str,
+    profile: &str,
+    config_path: &std::path::Path,
+) -> Result<String, ConfigError> {
+    // Parse errors are reported by the file source itself
+    let Ok(table) = content.parse::<toml::Table>() else {
+        return Ok(String::new());
+    };
+    let profiles = table.get("profiles").and_then(toml::Value::as_table);
+
+    match profiles
+        .and_then(|profiles| profiles.get(profile))
+        .and_then(toml::Value::as_table)
+    {
+        Some(profile_table) => {
+            let valid_keys = valid_user_config_keys();
+            let mut unknown: Vec<&str> = profile_table
+                .keys()
+                .map(String::as_str)
+                .filter(|key| *key == "profiles" || !valid_keys.iter().any(|k| k == *key))
+                .collect();
+            if !unknown.is_empty() {
+                unknown.sort();
+                return Err(ConfigError::Message(format!(
+                    "Config profile '{profile}' in {} has unknown keys: {}",
+                    config_path.display(),
+                    unknown.join(", ")
+                )));
+            }
+            toml::to_string(profile_table).map_err(|e| ConfigError::Message(e.to_string()))
+        }
+        None => {
+            let available: Vec<&str> = profiles
+                .map(|profiles| profiles.keys().map(String::as_str).collect())
+                .unwrap_or_default();
+            let detail = if available.is_empty() {
+                "no profiles defined".to_string()
+            } else {
+                format!("available: {}", available.join(", "))
+            };
+            Err(ConfigError::Message(format!(
+                "Config profile '{profile}' not found in {} ({detail})",
+                config_path.display()
+            )))
+        }
+    }
+}
@@ -1,7 +1,8 @@
-//! Config path management.
+//! Config path and profile management.
 //!
 //! Handles determining the user config file location across platforms,
-//! with support for CLI overrides and environment variables.
+//! and which `[profiles.<name>]` table applies, with support for CLI
+//! overrides and environment variables.
 
 use std::path::PathBuf;
 use std::sync::OnceLock;
@@ -17,6 +18,29 @@ pub fn set_config_path(path: PathBuf) {
     CONFIG_PATH.set(path).ok();
 }
 
+/// Override for the user config profile, set via --profile CLI flag
+static CONFIG_PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Set the user config profile override (called from CLI --profile flag)
+pub fn set_config_profile(profile: String) {
+    CONFIG_PROFILE.set(profile).ok();
+}
+
+/// Get the selected user config profile, if any.
+///
+/// Priority:
+/// 1. CLI --profile flag (set via `set_config_profile`)
+/// 2. WORKTRUNK_CONFIG_PROFILE environment variable
+pub fn get_config_profile() -> Option<String> {
+    if let Some(profile) = CONFIG_PROFILE.get() {
+        return Some(profile.clone());
+    }
+
+    std::env::var("WORKTRUNK_CONFIG_PROFILE")
+        .ok()
+        .filter(|profile| !profile.is_empty())
+}
+
 /// Check if the config path was explicitly specified via --config CLI flag.
 ///
 /// Returns true only if --config flag was used. Environment variable
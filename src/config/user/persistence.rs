@@ -361,6 +361,10 @@ impl UserConfig {
             ));
         }
 
+        if let Some(ref theme) = self.theme {
+            theme.theme().map_err(ConfigError::Message)?;
+        }
+
         Ok(())
     }
 
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 
 use super::merge::Merge;
 use crate::config::HooksConfig;
+use crate::styling::{Theme, parse_style};
 
 /// What to stage before committing
 #[derive(
@@ -33,6 +34,19 @@ pub enum StageMode {
     None,
 }
 
+/// When `wt remove` asks for confirmation before removing a worktree
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum RemoveConfirm {
+    /// Confirm only when commits not in the default branch would be lost
+    #[default]
+    Unsafe,
+    /// Confirm every worktree removal
+    Always,
+    /// Never confirm
+    Never,
+}
+
 /// Configuration for commit message generation
 ///
 /// The command is a shell string executed via `sh -c`. Environment variables
@@ -113,6 +127,62 @@ impl Merge for CommitGenerationConfig {
     }
 }
 
+/// How `wt list` renders the Path column
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    clap::ValueEnum,
+    serde::Serialize,
+    serde::Deserialize,
+    JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum PathStyle {
+    /// Relative to the main worktree (`.`, `../repo.feature`)
+    #[default]
+    Relative,
+    /// Full absolute path
+    Absolute,
+    /// Absolute, with the home directory shown as `~`
+    Home,
+}
+
+/// How `wt list` renders commit ages in the Age column
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum TimeFormat {
+    /// Abbreviated units (`2d`, `3h`, `5m`)
+    #[default]
+    Compact,
+    /// Spelled out (`2 days ago`, `3 hours ago`)
+    Long,
+}
+
+/// A `wt list` column that can be named in `[list] columns`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ListColumn {
+    Branch,
+    Status,
+    WorkingDiff,
+    AheadBehind,
+    BranchDiff,
+    Path,
+    Upstream,
+    Url,
+    CiStatus,
+    Commit,
+    Time,
+    Author,
+    Message,
+}
+
 /// Configuration for the `wt list` command
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
 pub struct ListConfig {
@@ -134,6 +204,40 @@ pub struct ListConfig {
     /// (useful to override a global setting). Disabled when --full is used.
     #[serde(rename = "timeout-ms", skip_serializing_if = "Option::is_none")]
     pub timeout_ms: Option<u64>,
+
+    /// Maximum width of the Branch column; longer names are truncated with `…`.
+    /// Set to 0 to disable truncation. JSON output always has full names.
+    #[serde(rename = "branch-width", skip_serializing_if = "Option::is_none")]
+    pub branch_width: Option<usize>,
+
+    /// Columns to show, in display order. Unlisted columns are hidden.
+    /// When unset, all columns are shown in the default order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<ListColumn>>,
+
+    /// Show the Author column (initials of the last commit's author)
+    #[serde(rename = "show-author", skip_serializing_if = "Option::is_none")]
+    pub show_author: Option<bool>,
+
+    /// How to render the Path column: "relative", "absolute", or "home"
+    #[serde(rename = "path-style", skip_serializing_if = "Option::is_none")]
+    pub path_style: Option<PathStyle>,
+
+    /// Prefix the Upstream column with the remote name (default: only with multiple remotes)
+    #[serde(rename = "remote-names", skip_serializing_if = "Option::is_none")]
+    pub remote_names: Option<bool>,
+
+    /// How to render the Age column: "compact" (`2d`) or "long" (`2 days ago`)
+    #[serde(rename = "time-format", skip_serializing_if = "Option::is_none")]
+    pub time_format: Option<TimeFormat>,
+
+    /// Compute upstream ahead/behind by default (`--no-upstream` disables it)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream: Option<bool>,
+
+    /// Gray out rows with no unique work (integrated or empty branches)
+    #[serde(rename = "dim-removable", skip_serializing_if = "Option::is_none")]
+    pub dim_removable: Option<bool>,
 }
 
 impl ListConfig {
@@ -156,6 +260,43 @@ impl ListConfig {
     pub fn timeout_ms(&self) -> Option<u64> {
         self.timeout_ms
     }
+
+    /// Maximum Branch column width (default: 40, 0 = unlimited)
+    pub fn branch_width(&self) -> usize {
+        match self.branch_width.unwrap_or(40) {
+            0 => usize::MAX,
+            width => width,
+        }
+    }
+
+    /// Show the Author column (default: false, or true when `columns` lists `author`)
+    pub fn show_author(&self) -> bool {
+        self.show_author.unwrap_or_else(|| {
+            self.columns
+                .as_ref()
+                .is_some_and(|columns| columns.contains(&ListColumn::Author))
+        })
+    }
+
+    /// How to render the Path column (default: relative)
+    pub fn path_style(&self) -> PathStyle {
+        self.path_style.unwrap_or_default()
+    }
+
+    /// How to render the Age column (default: compact)
+    pub fn time_format(&self) -> TimeFormat {
+        self.time_format.unwrap_or_default()
+    }
+
+    /// Compute upstream tracking status (default: true)
+    pub fn upstream(&self) -> bool {
+        self.upstream.unwrap_or(true)
+    }
+
+    /// Gray out potentially removable rows (default: true)
+    pub fn dim_removable(&self) -> bool {
+        self.dim_removable.unwrap_or(true)
+    }
 }
 
 impl Merge for ListConfig {
@@ -165,6 +306,14 @@ impl Merge for ListConfig {
             branches: other.branches.or(self.branches),
             remotes: other.remotes.or(self.remotes),
             timeout_ms: other.timeout_ms.or(self.timeout_ms),
+            branch_width: other.branch_width.or(self.branch_width),
+            columns: other.columns.clone().or_else(|| self.columns.clone()),
+            show_author: other.show_author.or(self.show_author),
+            path_style: other.path_style.or(self.path_style),
+            remote_names: other.remote_names.or(self.remote_names),
+            time_format: other.time_format.or(self.time_format),
+            upstream: other.upstream.or(self.upstream),
+            dim_removable: other.dim_removable.or(self.dim_removable),
         }
     }
 }
@@ -303,6 +452,67 @@ impl Merge for SelectConfig {
     }
 }
 
+/// Colors for `wt list` (`[theme]`)
+///
+/// Each value is a color name (`green`, `bright-blue`) or hex value
+/// (`#ff8800`), optionally with `bold`, `dim`, `italic` or `underline`;
+/// `none` removes styling. Unset roles keep the built-in colors.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub struct ThemeConfig {
+    /// Added lines and commits ahead (default: `green`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub addition: Option<String>,
+
+    /// Deleted lines and commits behind (default: `red`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deletion: Option<String>,
+
+    /// `@` marker for the current worktree (default: `none`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current: Option<String>,
+
+    /// `^` marker for the primary worktree (default: `none`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<String>,
+
+    /// CI indicator when checks passed (default: `green`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ci_passed: Option<String>,
+
+    /// CI indicator when checks failed (default: `red`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ci_failed: Option<String>,
+
+    /// Secondary text such as times, commits and messages (default: `dim`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimmed: Option<String>,
+}
+
+impl ThemeConfig {
+    /// Build the theme, starting from the defaults and applying each set role.
+    ///
+    /// Errors name the offending key, e.g. `theme.addition: unknown color 'grene' ...`.
+    pub fn theme(&self) -> Result<Theme, String> {
+        let mut theme = Theme::default();
+        let roles = [
+            ("addition", &self.addition, &mut theme.addition),
+            ("deletion", &self.deletion, &mut theme.deletion),
+            ("current", &self.current, &mut theme.current),
+            ("primary", &self.primary, &mut theme.primary),
+            ("ci-passed", &self.ci_passed, &mut theme.ci_passed),
+            ("ci-failed", &self.ci_failed, &mut theme.ci_failed),
+            ("dimmed", &self.dimmed, &mut theme.dimmed),
+        ];
+        for (key, spec, style) in roles {
+            if let Some(spec) = spec {
+                *style = parse_style(spec).map_err(|e| format!("theme.{key}: {e}"))?;
+            }
+        }
+        Ok(theme)
+    }
+}
+
 /// Settings that can be set globally or per-project.
 ///
 /// This struct is flattened into both `UserConfig` (global) and `UserProjectOverrides`
@@ -330,6 +540,15 @@ pub struct OverridableConfig {
     )]
     pub worktree_path: Option<String>,
 
+    /// When `wt remove` asks for confirmation (default: unsafe)
+    /// Values: "unsafe", "always", "never"
+    #[serde(
+        rename = "remove-confirm",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub remove_confirm: Option<RemoveConfirm>,
+
     /// Configuration for the `wt list` command
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub list: Option<ListConfig>,
@@ -354,6 +573,7 @@ impl OverridableConfig {
     pub fn is_empty(&self) -> bool {
         self.hooks == HooksConfig::default()
             && self.worktree_path.is_none()
+            && self.remove_confirm.is_none()
             && self.list.is_none()
             && self.commit.is_none()
             && self.merge.is_none()
@@ -371,6 +591,7 @@ impl Merge for OverridableConfig {
                 .worktree_path
                 .clone()
                 .or_else(|| self.worktree_path.clone()),
+            remove_confirm: other.remove_confirm.or(self.remove_confirm),
             list: merge_optional(self.list.as_ref(), other.list.as_ref()),
             commit: merge_optional(self.commit.as_ref(), other.commit.as_ref()),
             merge: merge_optional(self.merge.as_ref(), other.merge.as_ref()),
@@ -281,6 +281,14 @@ fn test_list_config_serde() {
         branches: Some(false),
         remotes: None,
         timeout_ms: Some(500),
+        branch_width: None,
+        columns: None,
+        show_author: None,
+        path_style: None,
+        remote_names: None,
+        time_format: None,
+        upstream: None,
+        dim_removable: None,
     };
     let json = serde_json::to_string(&config).unwrap();
     let parsed: ListConfig = serde_json::from_str(&json).unwrap();
@@ -452,6 +460,44 @@ fn test_worktrunk_config_format_path_custom_template() {
     assert_eq!(path, ".worktrees/feature");
 }
 
+#[test]
+fn test_named_worktree_path_from_templates_table() {
+    let config = UserConfig::load_from_str(
+        r#"
+[templates]
+fix = "../fixes/{{ branch | sanitize }}"
+feature = "../features/{{ branch | sanitize }}"
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        config.named_worktree_path("fix").unwrap(),
+        "../fixes/{{ branch | sanitize }}"
+    );
+
+    let err = config.named_worktree_path("docs").unwrap_err().to_string();
+    assert_eq!(err, "Unknown template 'docs'; available: feature, fix");
+
+    let test = test_repo();
+    let path = UserConfig::format_path_with_template(
+        config.named_worktree_path("feature").unwrap(),
+        "myrepo",
+        "feature/branch",
+        &test.repo,
+    )
+    .unwrap();
+    assert_eq!(path, "../features/feature-branch");
+}
+
+#[test]
+fn test_named_worktree_path_without_templates() {
+    let err = UserConfig::default()
+        .named_worktree_path("fix")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("no [templates] configured"), "got: {err}");
+}
+
 #[test]
 fn test_worktrunk_config_format_path_repo_path_variable() {
     let test = test_repo();
@@ -529,6 +575,56 @@ fn test_merge_config_serde() {
     assert_eq!(parsed.rebase, Some(false));
 }
 
+#[test]
+fn test_maintenance_config() {
+    assert!(!UserConfig::default().maintenance);
+
+    let config: UserConfig = toml::from_str("maintenance = true").unwrap();
+    assert!(config.maintenance);
+    assert!(
+        toml::to_string(&config)
+            .unwrap()
+            .contains("maintenance = true")
+    );
+}
+
+#[test]
+fn test_copy_on_create() {
+    let config = UserConfig::default();
+    assert_eq!(config.copy_on_create(false), None);
+    assert_eq!(
+        config.copy_on_create(true),
+        Some(vec![".env".to_string(), ".envrc".to_string()])
+    );
+
+    // A configured list replaces the defaults and applies without --copy-env
+    let config: UserConfig = toml::from_str(r#"copy-on-create = [".env.local"]"#).unwrap();
+    assert_eq!(
+        config.copy_on_create(false),
+        Some(vec![".env.local".to_string()])
+    );
+}
+
+#[test]
+fn test_stale_base_threshold() {
+    assert_eq!(UserConfig::default().stale_base_threshold(), Some(10));
+
+    let config = UserConfig::load_from_str("stale-base-threshold = 25").unwrap();
+    assert_eq!(config.stale_base_threshold(), Some(25));
+
+    // 0 disables the check
+    let config = UserConfig::load_from_str("stale-base-threshold = 0").unwrap();
+    assert_eq!(config.stale_base_threshold(), None);
+}
+
+#[test]
+fn test_show_integration_hint() {
+    assert!(UserConfig::default().show_integration_hint());
+
+    let config = UserConfig::load_from_str("show-integration-hint = false").unwrap();
+    assert!(!config.show_integration_hint());
+}
+
 #[test]
 fn test_skip_shell_integration_prompt_default_false() {
     let config = UserConfig::default();
@@ -588,12 +684,28 @@ fn test_merge_list_config() {
         branches: Some(false),
         remotes: None,
         timeout_ms: Some(1000),
+        branch_width: None,
+        columns: None,
+        show_author: None,
+        path_style: None,
+        remote_names: None,
+        time_format: None,
+        upstream: None,
+        dim_removable: None,
     };
     let override_config = ListConfig {
         full: None,           // Should fall back to base
         branches: Some(true), // Should override
         remotes: Some(true),  // Should override (base was None)
         timeout_ms: None,     // Should fall back to base
+        branch_width: None,
+        columns: None,
+        show_author: None,
+        path_style: None,
+        remote_names: None,
+        time_format: None,
+        upstream: None,
+        dim_removable: None,
     };
 
     let merged = base.merge_with(&override_config);
@@ -939,6 +1051,14 @@ fn test_effective_list_project_only() {
                     branches: None,
                     remotes: None,
                     timeout_ms: None,
+                    branch_width: None,
+                    columns: None,
+                    show_author: None,
+                    path_style: None,
+                    remote_names: None,
+                    time_format: None,
+                    upstream: None,
+                    dim_removable: None,
                 }),
                 ..Default::default()
             },
@@ -1022,6 +1142,7 @@ fn test_list_config_accessor_methods_defaults() {
     assert!(!config.branches());
     assert!(!config.remotes());
     assert!(config.timeout_ms().is_none());
+    assert_eq!(config.branch_width(), 40);
 }
 
 #[test]
@@ -1031,6 +1152,14 @@ fn test_list_config_accessor_methods_with_values() {
         branches: Some(true),
         remotes: Some(false),
         timeout_ms: Some(5000),
+        branch_width: None,
+        columns: None,
+        show_author: None,
+        path_style: None,
+        remote_names: None,
+        time_format: None,
+        upstream: None,
+        dim_removable: None,
     };
     assert!(config.full());
     assert!(config.branches());
@@ -1038,6 +1167,97 @@ fn test_list_config_accessor_methods_with_values() {
     assert_eq!(config.timeout_ms(), Some(5000));
 }
 
+#[test]
+fn test_list_config_branch_width() {
+    let config = UserConfig::load_from_str("[list]\nbranch-width = 24\n").unwrap();
+    assert_eq!(config.list(None).unwrap().branch_width(), 24);
+
+    // 0 disables truncation
+    let config = ListConfig {
+        branch_width: Some(0),
+        ..Default::default()
+    };
+    assert_eq!(config.branch_width(), usize::MAX);
+}
+
+#[test]
+fn test_list_config_upstream() {
+    assert!(ListConfig::default().upstream());
+
+    let config = UserConfig::load_from_str("[list]\nupstream = false\n").unwrap();
+    assert!(!config.list(None).unwrap().upstream());
+}
+
+#[test]
+fn test_list_config_dim_removable() {
+    assert!(ListConfig::default().dim_removable());
+
+    let config = UserConfig::load_from_str("[list]\ndim-removable = false\n").unwrap();
+    assert!(!config.list(None).unwrap().dim_removable());
+}
+
+#[test]
+fn test_list_config_columns() {
+    let config =
+        UserConfig::load_from_str("[list]\ncolumns = [\"branch\", \"ahead_behind\"]\n").unwrap();
+    assert_eq!(
+        config.list(None).unwrap().columns,
+        Some(vec![ListColumn::Branch, ListColumn::AheadBehind])
+    );
+
+    // Unknown column names are a config error
+    assert!(UserConfig::load_from_str("[list]\ncolumns = [\"bogus\"]\n").is_err());
+}
+
+#[test]
+fn test_list_config_show_author() {
+    assert!(!ListConfig::default().show_author());
+
+    let config = UserConfig::load_from_str("[list]\nshow-author = true\n").unwrap();
+    assert!(config.list(None).unwrap().show_author());
+
+    // Listing the column in `columns` enables it too
+    let config = UserConfig::load_from_str("[list]\ncolumns = [\"branch\", \"author\"]\n").unwrap();
+    assert!(config.list(None).unwrap().show_author());
+}
+
+#[test]
+fn test_list_config_time_format() {
+    assert_eq!(ListConfig::default().time_format(), TimeFormat::Compact);
+
+    let config = UserConfig::load_from_str("[list]\ntime-format = \"long\"\n").unwrap();
+    assert_eq!(config.list(None).unwrap().time_format(), TimeFormat::Long);
+
+    assert!(UserConfig::load_from_str("[list]\ntime-format = \"verbose\"\n").is_err());
+}
+
+#[test]
+fn test_remove_confirm_project_overrides_global() {
+    assert_eq!(
+        UserConfig::default().remove_confirm(None),
+        RemoveConfirm::Unsafe
+    );
+
+    let config = UserConfig::load_from_str(
+        r#"
+remove-confirm = "always"
+
+[projects."github.com/user/repo"]
+remove-confirm = "never"
+"#,
+    )
+    .unwrap();
+    assert_eq!(config.remove_confirm(None), RemoveConfirm::Always);
+    assert_eq!(
+        config.remove_confirm(Some("github.com/user/other")),
+        RemoveConfirm::Always
+    );
+    assert_eq!(
+        config.remove_confirm(Some("github.com/user/repo")),
+        RemoveConfirm::Never
+    );
+}
+
 #[test]
 fn test_merge_config_accessor_methods_defaults() {
     let config = MergeConfig::default();
@@ -1391,6 +1611,36 @@ template-file = "path/to/file"
     assert!(err.contains("mutually exclusive"), "{err}");
 }
 
+#[test]
+fn test_theme_overrides_only_set_roles() {
+    let content = r##"
+[theme]
+addition = "bright-green"
+ci-failed = "bold #ff0000"
+"##;
+    let config = UserConfig::load_from_str(content).unwrap();
+    let theme = config.theme();
+    let default = crate::styling::Theme::default();
+    assert_eq!(
+        theme.addition,
+        crate::styling::parse_style("bright-green").unwrap()
+    );
+    assert_ne!(theme.ci_failed, default.ci_failed);
+    assert_eq!(theme.deletion, default.deletion);
+    assert_eq!(theme.dimmed, default.dimmed);
+    assert_eq!(UserConfig::default().theme(), default);
+}
+
+#[test]
+fn test_validation_theme_unknown_color() {
+    let content = r#"
+[theme]
+deletion = "rde"
+"#;
+    let err = UserConfig::load_from_str(content).unwrap_err().to_string();
+    assert!(err.contains("theme.deletion: unknown color 'rde'"), "{err}");
+}
+
 #[test]
 fn test_validation_squash_template_mutual_exclusivity() {
     let content = r#"
@@ -2039,3 +2289,53 @@ fn test_reload_projects_from_permission_error() {
         "Expected path in error, got: {err}"
     );
 }
+
+#[test]
+fn test_config_profile_overrides_only_its_keys() {
+    let content = r#"
+worktree-path = "../{{ repo }}.{{ branch }}"
+
+[merge]
+squash = false
+remove = false
+
+[profiles.work]
+worktree-path = ".worktrees/{{ branch }}"
+
+[profiles.work.merge]
+squash = true
+"#;
+
+    let config = UserConfig::load_from_str_with_profile(content, "work").unwrap();
+    assert_eq!(config.worktree_path(), ".worktrees/{{ branch }}");
+    let merge = config.merge(None).unwrap();
+    assert!(merge.squash());
+    // Keys the profile doesn't set keep their top-level values
+    assert!(!merge.remove());
+
+    let err = UserConfig::load_from_str_with_profile(content, "home").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Config profile 'home' not found in config.toml (available: work)"
+    );
+}
+
+#[test]
+fn test_config_profile_rejects_unknown_keys() {
+    let content = r#"
+[profiles.work]
+worktree-pth = ".worktrees/{{ branch }}"
+
+[profiles.work.merge]
+squash = true
+
+[profiles.work.profiles.nested]
+maintenance = true
+"#;
+
+    let err = UserConfig::load_from_str_with_profile(content, "work").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Config profile 'work' in config.toml has unknown keys: profiles, worktree-pth"
+    );
+}
@@ -39,7 +39,7 @@
 //! use crate::diagnostic::issue_hint;
 //!
 //! // Show hint telling user to run with -vv
-//! eprintln!("{}", hint_message(issue_hint()));
+//! print_hint(issue_hint());
 //! ```
 //!
 use std::path::PathBuf;
@@ -51,7 +51,7 @@ use minijinja::{Environment, context};
 use worktrunk::git::Repository;
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell_exec::Cmd;
-use worktrunk::styling::{eprintln, hint_message, info_message, warning_message};
+use worktrunk::styling::{eprintln, info_message, print_hint, warning_message};
 
 use crate::cli::version_str;
 use crate::output;
@@ -243,12 +243,9 @@ pub(crate) fn write_if_verbose(verbose: u8, command_line: &str, error_msg: Optio
                 let path_str = format_path_for_display(&path);
                 // URL with prefilled body: ## Gist\n\n[Paste URL]\n\n## Description\n\n[Describe the issue]
                 let issue_url = "https://github.com/max-sixty/worktrunk/issues/new?body=%23%23%20Gist%0A%0A%5BPaste%20gist%20URL%5D%0A%0A%23%23%20Description%0A%0A%5BDescribe%20the%20issue%5D";
-                eprintln!(
-                    "{}",
-                    hint_message(cformat!(
-                        "To report a bug, create a secret gist with <bright-black>gh gist create --web {path_str}</> and reference it from an issue at <bright-black>{issue_url}</>"
-                    ))
-                );
+                print_hint(cformat!(
+                    "To report a bug, create a secret gist with <bright-black>gh gist create --web {path_str}</> and reference it from an issue at <bright-black>{issue_url}</>"
+                ));
             }
         }
         None => {
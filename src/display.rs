@@ -9,6 +9,7 @@
 use std::path::{Component, Path};
 
 use unicode_width::UnicodeWidthChar;
+use worktrunk::config::{PathStyle, TimeFormat};
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::visual_width;
 use worktrunk::utils::get_now;
@@ -19,7 +20,29 @@ pub(crate) fn format_relative_time_short(timestamp: i64) -> String {
     format_relative_time_impl(timestamp, get_now() as i64)
 }
 
-fn format_relative_time_impl(timestamp: i64, now: i64) -> String {
+/// Format timestamp as relative time in the given style ("2h" or "2 hours ago")
+pub(crate) fn format_relative_time(timestamp: i64, format: TimeFormat) -> String {
+    let now = get_now() as i64;
+    match format {
+        TimeFormat::Compact => format_relative_time_impl(timestamp, now),
+        TimeFormat::Long => format_relative_time_long_impl(timestamp, now),
+    }
+}
+
+/// Widest output of [`format_relative_time`] for ages under 100 years
+/// ("11mo" / "11 months ago")
+pub(crate) fn relative_time_max_width(format: TimeFormat) -> usize {
+    match format {
+        TimeFormat::Compact => 4,
+        TimeFormat::Long => 13,
+    }
+}
+
+/// Largest whole unit in an age, as `(count, abbreviation, unit name)`.
+///
+/// Both formats truncate through this so they always agree: 90 minutes is
+/// `1h` and `1 hour ago`. Returns `None` for ages under a minute.
+fn relative_time_unit(seconds_ago: i64) -> Option<(i64, &'static str, &'static str)> {
     const MINUTE: i64 = 60;
     const HOUR: i64 = MINUTE * 60;
     const DAY: i64 = HOUR * 24;
@@ -27,33 +50,46 @@ fn format_relative_time_impl(timestamp: i64, now: i64) -> String {
     const MONTH: i64 = DAY * 30;
     const YEAR: i64 = DAY * 365;
 
+    const UNITS: &[(i64, &str, &str)] = &[
+        (YEAR, "y", "year"),
+        (MONTH, "mo", "month"),
+        (WEEK, "w", "week"),
+        (DAY, "d", "day"),
+        (HOUR, "h", "hour"),
+        (MINUTE, "m", "minute"),
+    ];
+
+    UNITS.iter().find_map(|&(unit_seconds, abbrev, name)| {
+        let value = seconds_ago / unit_seconds;
+        (value > 0).then_some((value, abbrev, name))
+    })
+}
+
+fn format_relative_time_impl(timestamp: i64, now: i64) -> String {
     let seconds_ago = now - timestamp;
 
     if seconds_ago < 0 {
         return "future".to_string();
     }
 
-    if seconds_ago < MINUTE {
-        return "now".to_string();
+    match relative_time_unit(seconds_ago) {
+        Some((value, abbrev, _)) => format!("{}{}", value, abbrev),
+        None => "now".to_string(),
     }
+}
 
-    const UNITS: &[(i64, &str)] = &[
-        (YEAR, "y"),
-        (MONTH, "mo"),
-        (WEEK, "w"),
-        (DAY, "d"),
-        (HOUR, "h"),
-        (MINUTE, "m"),
-    ];
+fn format_relative_time_long_impl(timestamp: i64, now: i64) -> String {
+    let seconds_ago = now - timestamp;
 
-    for &(unit_seconds, abbrev) in UNITS {
-        let value = seconds_ago / unit_seconds;
-        if value > 0 {
-            return format!("{}{}", value, abbrev);
-        }
+    if seconds_ago < 0 {
+        return "in the future".to_string();
     }
 
-    "now".to_string()
+    match relative_time_unit(seconds_ago) {
+        Some((1, _, name)) => format!("1 {} ago", name),
+        Some((value, _, name)) => format!("{} {}s ago", value, name),
+        None => "just now".to_string(),
+    }
 }
 
 /// Shorten a path relative to the main worktree.
@@ -84,6 +120,15 @@ pub(crate) fn shorten_path(path: &Path, main_worktree_path: &Path) -> String {
     }
 }
 
+/// Format a worktree path for the `wt list` Path column according to `style`.
+pub(crate) fn format_list_path(path: &Path, main_worktree_path: &Path, style: PathStyle) -> String {
+    match style {
+        PathStyle::Relative => shorten_path(path, main_worktree_path),
+        PathStyle::Absolute => path.display().to_string(),
+        PathStyle::Home => format_path_for_display(path),
+    }
+}
+
 /// Truncate text with ellipsis at exact width limit.
 ///
 /// Truncates at character boundary (mid-word if needed) to fill the allocated
@@ -231,6 +276,88 @@ mod tests {
         assert_eq!(format_relative_time_impl(now + 1000, now), "future");
     }
 
+    #[test]
+    fn test_format_relative_time_long() {
+        let now: i64 = 1700000000;
+
+        assert_eq!(format_relative_time_long_impl(now - 30, now), "just now");
+        assert_eq!(
+            format_relative_time_long_impl(now - 60, now),
+            "1 minute ago"
+        );
+        assert_eq!(
+            format_relative_time_long_impl(now - 7200, now),
+            "2 hours ago"
+        );
+        assert_eq!(
+            format_relative_time_long_impl(now - 172800, now),
+            "2 days ago"
+        );
+        assert_eq!(
+            format_relative_time_long_impl(now - 31536000, now),
+            "1 year ago"
+        );
+        assert_eq!(
+            format_relative_time_long_impl(now + 1000, now),
+            "in the future"
+        );
+
+        // Both formats truncate to the same unit and count
+        for age in [90 * 60, 36 * 3600, 13 * 86400, 330 * 86400] {
+            let compact = format_relative_time_impl(now - age, now);
+            let long = format_relative_time_long_impl(now - age, now);
+            let count = compact.trim_end_matches(char::is_alphabetic);
+            assert!(
+                long.starts_with(&format!("{count} ")),
+                "{compact} vs {long}"
+            );
+        }
+        assert_eq!(format_relative_time_impl(now - 90 * 60, now), "1h");
+        assert_eq!(
+            format_relative_time_long_impl(now - 90 * 60, now),
+            "1 hour ago"
+        );
+
+        // The widest values fit the reserved column width
+        let widest = now - (365 * 86400 - 1);
+        assert_eq!(
+            format_relative_time_impl(widest, now).len(),
+            relative_time_max_width(TimeFormat::Compact)
+        );
+        assert_eq!(
+            format_relative_time_long_impl(widest, now).len(),
+            relative_time_max_width(TimeFormat::Long)
+        );
+    }
+
+    #[test]
+    #[cfg(unix)] // Uses Unix-style paths
+    fn test_format_list_path() {
+        let main_worktree = PathBuf::from("/srv/repos/project");
+        let sibling = PathBuf::from("/srv/repos/project.feature");
+
+        assert_eq!(
+            format_list_path(&sibling, &main_worktree, PathStyle::Relative),
+            "../project.feature"
+        );
+        assert_eq!(
+            format_list_path(&sibling, &main_worktree, PathStyle::Absolute),
+            "/srv/repos/project.feature"
+        );
+        // Outside the home directory, `home` is the absolute path
+        assert_eq!(
+            format_list_path(&sibling, &main_worktree, PathStyle::Home),
+            "/srv/repos/project.feature"
+        );
+
+        if let Some(home) = worktrunk::path::home_dir() {
+            assert_eq!(
+                format_list_path(&home.join("project"), &main_worktree, PathStyle::Home),
+                "~/project"
+            );
+        }
+    }
+
     #[test]
     #[cfg(unix)] // Uses Unix-style paths
     fn test_shorten_path() {
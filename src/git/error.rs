@@ -131,11 +131,15 @@ pub enum GitError {
         /// Show hint about creating the branch. Set to false for remove operations
         /// where suggesting creation doesn't make sense.
         show_create_hint: bool,
+        /// Closest existing branch name, shown as a "did you mean" hint.
+        suggestion: Option<String>,
     },
     /// Reference (branch, tag, commit) not found - used when any commit-ish is accepted
     ReferenceNotFound {
         reference: String,
     },
+    /// Default branch could not be determined (no config, remote HEAD, or local inference)
+    DefaultBranchUnknown,
 
     // Worktree errors
     NotInWorktree {
@@ -170,6 +174,9 @@ pub enum GitError {
         error: String,
     },
     CannotRemoveMainWorktree,
+    RemoveNeedsConfirmation {
+        branch: Option<String>,
+    },
     WorktreeLocked {
         branch: String,
         path: PathBuf,
@@ -191,6 +198,10 @@ pub enum GitError {
         target_branch: String,
         git_output: String,
     },
+    MergeConflict {
+        target_branch: String,
+        git_output: String,
+    },
     NotRebased {
         target_branch: String,
     },
@@ -300,7 +311,7 @@ impl std::fmt::Display for GitError {
                 let hint = if *force_hint {
                     // Construct full command: "wt remove [branch] --force"
                     let args: Vec<&str> = branch.as_deref().into_iter().collect();
-                    let cmd = suggest_command("remove", &args, &["--force"]);
+                    let cmd = suggest_command("remove", &args, &["--yes"]);
                     cformat!(
                         "Commit or stash changes first, or to lose uncommitted changes, run <bright-black>{cmd}</>"
                     )
@@ -325,6 +336,7 @@ impl std::fmt::Display for GitError {
             GitError::BranchNotFound {
                 branch,
                 show_create_hint,
+                suggestion,
             } => {
                 let list_cmd = suggest_command("list", &[], &["--branches", "--remotes"]);
                 let hint = if *show_create_hint {
@@ -337,10 +349,20 @@ impl std::fmt::Display for GitError {
                 };
                 write!(
                     f,
-                    "{}\n{}",
-                    error_message(cformat!("No branch named <bold>{branch}</>")),
-                    hint_message(hint)
-                )
+                    "{}",
+                    error_message(cformat!("No branch named <bold>{branch}</>"))
+                )?;
+                if let Some(suggestion) = suggestion {
+                    let switch_cmd = suggest_command("switch", &[suggestion], &[]);
+                    write!(
+                        f,
+                        "\n{}",
+                        hint_message(cformat!(
+                            "Did you mean <bold>{suggestion}</>? To switch to it, run <bright-black>{switch_cmd}</>"
+                        ))
+                    )?;
+                }
+                write!(f, "\n{}", hint_message(hint))
             }
 
             GitError::ReferenceNotFound { reference } => {
@@ -353,6 +375,16 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::DefaultBranchUnknown => {
+                write!(
+                    f,
+                    "{}",
+                    error_message(cformat!(
+                        "Cannot determine default branch. Specify target explicitly or run <bold>wt config state default-branch set BRANCH</>"
+                    ))
+                )
+            }
+
             GitError::NotInWorktree { action } => {
                 let message = match action {
                     Some(action) => format!("Cannot {action}: not in a worktree"),
@@ -582,6 +614,28 @@ impl std::fmt::Display for GitError {
                 }
             }
 
+            GitError::MergeConflict {
+                target_branch,
+                git_output,
+            } => {
+                write!(
+                    f,
+                    "{}",
+                    error_message(cformat!("Merge of <bold>{target_branch}</> incomplete"))
+                )?;
+                if !git_output.is_empty() {
+                    write!(f, "\n{}", format_with_gutter(git_output, None))?;
+                }
+                write!(
+                    f,
+                    "\n{}\n{}",
+                    hint_message(cformat!(
+                        "To continue after resolving conflicts, run <bright-black>git merge --continue</>"
+                    )),
+                    hint_message(cformat!("To abort, run <bright-black>git merge --abort</>"))
+                )
+            }
+
             GitError::NotRebased { target_branch } => {
                 let rebase_cmd = suggest_command("step", &["rebase", target_branch], &[]);
                 write!(
@@ -604,6 +658,21 @@ impl std::fmt::Display for GitError {
                 write!(f, "{}", format_error_block(header, error))
             }
 
+            GitError::RemoveNeedsConfirmation { branch } => {
+                let message = match branch {
+                    Some(b) => cformat!("Not removing <bold>{b}</> without confirmation"),
+                    None => "Not removing worktree without confirmation".to_string(),
+                };
+                let args: Vec<&str> = branch.as_deref().into_iter().collect();
+                let cmd = suggest_command("remove", &args, &["--yes"]);
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(message),
+                    hint_message(cformat!("To remove anyway, run <bright-black>{cmd}</>"))
+                )
+            }
+
             GitError::NotInteractive => {
                 let approvals_cmd = suggest_command("hook", &["approvals", "add"], &[]);
                 write!(
@@ -1098,6 +1167,13 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_git_error_default_branch_unknown() {
+        let display = GitError::DefaultBranchUnknown.to_string();
+        assert!(display.contains("Cannot determine default branch"));
+        assert!(display.contains("wt config state default-branch set"));
+    }
+
     #[test]
     fn test_git_error_not_in_worktree() {
         // With action
@@ -1166,6 +1242,23 @@ mod tests {
         assert!(display.contains("feature"));
     }
 
+    #[test]
+    fn test_git_error_remove_needs_confirmation() {
+        let err = GitError::RemoveNeedsConfirmation {
+            branch: Some("feature".into()),
+        };
+        let display = err.to_string();
+        assert!(display.contains("Not removing"));
+        assert!(display.contains("feature"));
+        assert!(display.contains("wt remove feature --yes"));
+
+        // Detached HEAD: suggest removing the current worktree
+        let err = GitError::RemoveNeedsConfirmation { branch: None };
+        let display = err.to_string();
+        assert!(display.contains("Not removing worktree"));
+        assert!(display.contains("wt remove --yes"));
+    }
+
     #[test]
     fn test_git_error_worktree_locked_with_reason() {
         let err = GitError::WorktreeLocked {
@@ -1292,7 +1385,7 @@ mod tests {
         };
         let display = err.to_string();
         assert!(display.contains("Cannot remove worktree"));
-        assert!(display.contains("wt remove feature --force"));
+        assert!(display.contains("wt remove feature --yes"));
         assert!(display.contains("to lose uncommitted changes, run"));
     }
 
@@ -1442,4 +1535,18 @@ mod tests {
         assert!(display.contains("main"));
         // Empty output shouldn't cause issues
     }
+
+    #[test]
+    fn test_merge_conflict_hints() {
+        let err = GitError::MergeConflict {
+            target_branch: "main".into(),
+            git_output: "CONFLICT (content): Merge conflict in file.txt".into(),
+        };
+        let display = err.to_string();
+        assert!(display.contains("Merge of"));
+        assert!(display.contains("main"));
+        assert!(display.contains("Merge conflict in file.txt"));
+        assert!(display.contains("git merge --continue"));
+        assert!(display.contains("git merge --abort"));
+    }
 }
@@ -46,7 +46,9 @@ pub use error::{
     exit_code,
 };
 pub use parse::{parse_porcelain_z, parse_untracked_files};
-pub use repository::{Branch, Repository, ResolvedWorktree, WorkingTree, set_base_path};
+pub use repository::{
+    Branch, ConfigScope, Repository, ResolvedWorktree, WorkingTree, WorktreeCheckout, set_base_path,
+};
 pub use url::GitRemoteUrl;
 pub use url::{parse_owner_repo, parse_remote_owner};
 /// Why branch content is considered integrated into the target branch.
@@ -243,10 +245,23 @@ pub struct CompletionBranch {
     pub name: String,
     /// Unix timestamp of last commit
     pub timestamp: i64,
+    /// Subject line of the last commit (from the first remote for remote-only branches)
+    pub subject: String,
     /// Category for sorting and display
     pub category: BranchCategory,
 }
 
+/// Commit metadata fetched in bulk by [`Repository::commit_metadata`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommitMetadata {
+    /// Unix timestamp of the commit (committer date)
+    pub timestamp: i64,
+    /// Subject line of the commit message
+    pub subject: String,
+    pub author_name: String,
+    pub author_email: String,
+}
+
 // Re-export parsing helpers for internal use
 pub(crate) use parse::DefaultBranchName;
 
@@ -440,6 +455,13 @@ pub fn path_dir_name(path: &std::path::Path) -> &str {
         .unwrap_or("(unknown)")
 }
 
+/// Abbreviate a commit SHA to its first 7 characters for display.
+///
+/// Shorter input (e.g. an empty SHA for an unborn branch) is returned as-is.
+pub fn short_sha(sha: &str) -> &str {
+    sha.get(..7).unwrap_or(sha)
+}
+
 impl WorktreeInfo {
     /// Returns true if this worktree is prunable (directory deleted but git still tracks metadata).
     ///
@@ -470,7 +492,8 @@ impl WorktreeInfo {
 
 /// Helper function to read rebase branch information
 fn read_rebase_branch(worktree_path: &PathBuf) -> Option<String> {
-    let repo = Repository::current().ok()?;
+    // Resolve from the worktree itself, not the process cwd, which may be another repo
+    let repo = Repository::at(worktree_path.as_path()).ok()?;
     let git_dir = repo.worktree_at(worktree_path).git_dir().ok()?;
 
     // Check both rebase-merge and rebase-apply
@@ -506,6 +529,13 @@ pub(crate) fn finalize_worktree(mut wt: WorktreeInfo) -> WorktreeInfo {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_short_sha() {
+        assert_eq!(short_sha("0123456789abcdef"), "0123456");
+        assert_eq!(short_sha("abc"), "abc");
+        assert_eq!(short_sha(""), "");
+    }
+
     #[test]
     fn test_check_integration() {
         // Each integration reason + not integrated
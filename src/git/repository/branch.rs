@@ -92,21 +92,32 @@ impl<'a> Branch<'a> {
 
     /// Get the upstream tracking branch for this branch.
     ///
-    /// Uses [`@{upstream}` syntax][1] to resolve the tracking branch.
+    /// Uses [`@{upstream}` syntax][1] to resolve the tracking branch. The result
+    /// is cached per branch for the lifetime of the repository handle.
     ///
     /// [1]: https://git-scm.com/docs/gitrevisions#Documentation/gitrevisions.txt-emltaboranchgtemuaboranchgtupaboranchgtupstream
     pub fn upstream(&self) -> anyhow::Result<Option<String>> {
+        if let Some(cached) = self.repo.cache.upstreams.get(&self.name) {
+            return Ok(cached.clone());
+        }
+
         let result =
             self.repo
                 .run_command(&["rev-parse", "--abbrev-ref", &format!("{}@{{u}}", self.name)]);
 
-        match result {
+        let upstream = match result {
             Ok(upstream) => {
                 let trimmed = upstream.trim();
-                Ok((!trimmed.is_empty()).then(|| trimmed.to_string()))
+                (!trimmed.is_empty()).then(|| trimmed.to_string())
             }
-            Err(_) => Ok(None), // No upstream configured
-        }
+            Err(_) => None, // No upstream configured
+        };
+
+        self.repo
+            .cache
+            .upstreams
+            .insert(self.name.clone(), upstream.clone());
+        Ok(upstream)
     }
 
     /// Unset the upstream tracking branch for this branch.
@@ -116,6 +127,7 @@ impl<'a> Branch<'a> {
     pub fn unset_upstream(&self) -> anyhow::Result<()> {
         self.repo
             .run_command(&["branch", "--unset-upstream", &self.name])?;
+        self.repo.cache.upstreams.remove(&self.name);
         Ok(())
     }
 
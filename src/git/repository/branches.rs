@@ -24,6 +24,18 @@ impl Repository {
             .is_ok())
     }
 
+    /// Check if a branch exists locally, or (with `include_remotes`) on any remote.
+    ///
+    /// Unlike [`Self::ref_exists`], only branch names match — tags and SHAs don't.
+    pub fn branch_exists(&self, name: &str, include_remotes: bool) -> anyhow::Result<bool> {
+        let branch = self.branch(name);
+        if include_remotes {
+            branch.exists()
+        } else {
+            branch.exists_locally()
+        }
+    }
+
     /// Get all branch names (local branches only).
     pub fn all_branches(&self) -> anyhow::Result<Vec<String>> {
         let stdout = self.run_command(&[
@@ -145,6 +157,19 @@ impl Repository {
             .collect())
     }
 
+    /// Delete a local branch: `git branch -d`, or `-D` with `force` for unmerged branches.
+    ///
+    /// Refuses the default branch. Git itself refuses branches checked out in
+    /// any worktree, including the current one.
+    pub fn delete_branch(&self, name: &str, force: bool) -> anyhow::Result<()> {
+        if self.default_branch().as_deref() == Some(name) {
+            anyhow::bail!("Refusing to delete the default branch '{name}'");
+        }
+        let flag = if force { "-D" } else { "-d" };
+        self.run_command(&["branch", flag, "--", name])?;
+        Ok(())
+    }
+
     /// Get branches with metadata for shell completions.
     ///
     /// Returns branches in completion order: worktrees first, then local branches,
@@ -164,44 +189,50 @@ impl Repository {
             .filter_map(|wt| wt.branch.clone())
             .collect();
 
-        // Get local branches with timestamps
+        // Get local branches with timestamps and subjects. The subject is the
+        // last field, so a tab inside it doesn't shift the others.
         let local_output = self.run_command(&[
             "for-each-ref",
             "--sort=-committerdate",
-            "--format=%(refname:lstrip=2)\t%(committerdate:unix)",
+            "--format=%(refname:lstrip=2)\t%(committerdate:unix)\t%(contents:subject)",
             "refs/heads/",
         ])?;
 
-        let local_branches: Vec<(String, i64)> = local_output
+        let local_branches: Vec<(String, i64, String)> = local_output
             .lines()
             .filter_map(|line| {
-                let (name, timestamp_str) = line.split_once('\t')?;
-                let timestamp = timestamp_str.parse().unwrap_or(0);
-                Some((name.to_string(), timestamp))
+                let mut fields = line.splitn(3, '\t');
+                let name = fields.next()?;
+                let timestamp = fields.next()?.parse().unwrap_or(0);
+                let subject = fields.next().unwrap_or_default();
+                Some((name.to_string(), timestamp, subject.trim().to_string()))
             })
             .collect();
 
         let local_branch_names: HashSet<String> =
-            local_branches.iter().map(|(n, _)| n.clone()).collect();
+            local_branches.iter().map(|(n, _, _)| n.clone()).collect();
 
         // Get remote branches with timestamps from all remotes
         // Matches git's behavior: searches all remotes for branch names
         let remote_output = self.run_command(&[
             "for-each-ref",
             "--sort=-committerdate",
-            "--format=%(refname:lstrip=2)\t%(committerdate:unix)",
+            "--format=%(refname:lstrip=2)\t%(committerdate:unix)\t%(contents:subject)",
             "refs/remotes/",
         ])?;
 
-        // Group by branch name, collecting all remotes that have each branch.
-        // Uses HashMap for grouping, then sorts by timestamp to preserve recency order.
-        let mut branch_remotes: HashMap<String, (Vec<String>, i64)> = HashMap::new();
+        // Group by branch name, collecting all remotes (with their subjects) that
+        // have each branch. Uses HashMap for grouping, then sorts by timestamp to
+        // preserve recency order.
+        let mut branch_remotes: HashMap<String, (Vec<(String, String)>, i64)> = HashMap::new();
 
         for line in remote_output.lines() {
-            // Format: "<remote>/<branch>\t<timestamp>"
-            let Some((full_name, timestamp_str)) = line.split_once('\t') else {
+            // Format: "<remote>/<branch>\t<timestamp>\t<subject>"
+            let mut fields = line.splitn(3, '\t');
+            let (Some(full_name), Some(timestamp_str)) = (fields.next(), fields.next()) else {
                 continue;
             };
+            let subject = fields.next().unwrap_or_default();
 
             // Parse <remote>/<branch> - find first slash to split
             let Some((remote_name, local_name)) = full_name.split_once('/') else {
@@ -218,23 +249,30 @@ impl Repository {
             }
 
             let timestamp = timestamp_str.parse().unwrap_or(0);
+            let remote = (remote_name.to_string(), subject.trim().to_string());
 
             // Add remote to this branch's list, keeping the most recent timestamp
             branch_remotes
                 .entry(local_name.to_string())
                 .and_modify(|(remotes, existing_ts)| {
-                    remotes.push(remote_name.to_string());
+                    remotes.push(remote.clone());
                     *existing_ts = (*existing_ts).max(timestamp);
                 })
-                .or_insert_with(|| (vec![remote_name.to_string()], timestamp));
+                .or_insert_with(|| (vec![remote], timestamp));
         }
 
-        // Convert to vec and sort by timestamp (descending = most recent first)
-        let mut remote_branches: Vec<(String, Vec<String>, i64)> = branch_remotes
+        // Convert to vec and sort by timestamp (descending = most recent first).
+        // The subject comes from the first remote in name order.
+        let mut remote_branches: Vec<(String, Vec<String>, i64, String)> = branch_remotes
             .into_iter()
             .map(|(name, (mut remotes, timestamp))| {
                 remotes.sort(); // Deterministic remote ordering within each branch
-                (name, remotes, timestamp)
+                let subject = remotes
+                    .first()
+                    .map(|(_, subject)| subject.clone())
+                    .unwrap_or_default();
+                let remotes = remotes.into_iter().map(|(remote, _)| remote).collect();
+                (name, remotes, timestamp, subject)
             })
             .collect();
         remote_branches.sort_by(|a, b| b.2.cmp(&a.2));
@@ -243,32 +281,35 @@ impl Repository {
         let mut result = Vec::new();
 
         // Worktree branches (sorted by recency from local_branches order)
-        for (name, timestamp) in &local_branches {
+        for (name, timestamp, subject) in &local_branches {
             if worktree_branches.contains(name) {
                 result.push(CompletionBranch {
                     name: name.clone(),
                     timestamp: *timestamp,
+                    subject: subject.clone(),
                     category: BranchCategory::Worktree,
                 });
             }
         }
 
         // Local branches without worktrees
-        for (name, timestamp) in &local_branches {
+        for (name, timestamp, subject) in &local_branches {
             if !worktree_branches.contains(name) {
                 result.push(CompletionBranch {
                     name: name.clone(),
                     timestamp: *timestamp,
+                    subject: subject.clone(),
                     category: BranchCategory::Local,
                 });
             }
         }
 
         // Remote-only branches
-        for (local_name, remotes, timestamp) in remote_branches {
+        for (local_name, remotes, timestamp, subject) in remote_branches {
             result.push(CompletionBranch {
                 name: local_name,
                 timestamp,
+                subject,
                 category: BranchCategory::Remote(remotes),
             });
         }
@@ -1,27 +1,122 @@
 //! Git config, hints, marker, and default branch operations for Repository.
 
 use anyhow::Context;
-use color_print::cformat;
 
 use crate::config::ProjectConfig;
 
-use super::{DefaultBranchName, GitError, Repository};
+use super::{ConfigScope, DefaultBranchName, GitError, Repository};
 
 impl Repository {
-    /// Get a git config value. Returns None if the key doesn't exist.
+    /// Get a git config value from any scope. Returns None if the key doesn't exist.
+    ///
+    /// Shorthand for [`config_get`](Self::config_get) with [`ConfigScope::Any`].
     pub fn get_config(&self, key: &str) -> anyhow::Result<Option<String>> {
-        match self.run_command(&["config", key]) {
-            Ok(value) => Ok(Some(value.trim().to_string())),
-            Err(_) => Ok(None), // Config key doesn't exist
-        }
+        self.config_get(key, ConfigScope::Any)
     }
 
-    /// Set a git config value.
+    /// Set a git config value in the repository's config.
+    ///
+    /// Shorthand for [`config_set`](Self::config_set) with [`ConfigScope::Local`].
     pub fn set_config(&self, key: &str, value: &str) -> anyhow::Result<()> {
-        self.run_command(&["config", key, value])?;
+        self.config_set(key, value, ConfigScope::Local)
+    }
+
+    /// Read a git config value from a single scope.
+    ///
+    /// Returns `None` when the key is unset or empty. Worktree scope reads nothing
+    /// until `extensions.worktreeConfig` is enabled (see [`config_set`](Self::config_set)).
+    /// All worktrunk keys (`worktrunk.*`) go through this and its siblings.
+    pub fn config_get(&self, key: &str, scope: ConfigScope) -> anyhow::Result<Option<String>> {
+        if scope == ConfigScope::Worktree && !self.worktree_config_enabled()? {
+            return Ok(None);
+        }
+        let output = self.run_command_output(&scope.config_args(&["--get", key]))?;
+        match output.status.code() {
+            Some(0) => {
+                let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                Ok(Some(value).filter(|v| !v.is_empty()))
+            }
+            // Exit code 1: the key isn't set
+            Some(1) => Ok(None),
+            _ => anyhow::bail!(
+                "Failed to read git config {key}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        }
+    }
+
+    /// Write a git config value to a single scope.
+    ///
+    /// Worktree scope enables `extensions.worktreeConfig` first if needed, since
+    /// git rejects `--worktree` in a repository with several worktrees otherwise.
+    pub fn config_set(&self, key: &str, value: &str, scope: ConfigScope) -> anyhow::Result<()> {
+        if scope == ConfigScope::Worktree && !self.worktree_config_enabled()? {
+            self.config_set("extensions.worktreeConfig", "true", ConfigScope::Local)?;
+        }
+        self.run_command(&scope.config_args(&[key, value]))
+            .with_context(|| format!("Failed to set git config {key}"))?;
         Ok(())
     }
 
+    /// Remove a git config value from a single scope.
+    ///
+    /// Returns `false` if the key wasn't set.
+    pub fn config_unset(&self, key: &str, scope: ConfigScope) -> anyhow::Result<bool> {
+        if scope == ConfigScope::Worktree && !self.worktree_config_enabled()? {
+            return Ok(false);
+        }
+        let output = self.run_command_output(&scope.config_args(&["--unset", key]))?;
+        match output.status.code() {
+            Some(0) => Ok(true),
+            // Exit code 5: the key isn't set
+            Some(5) => Ok(false),
+            _ => anyhow::bail!(
+                "Failed to unset git config {key}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        }
+    }
+
+    /// Read every `(key, value)` whose key matches the regex `pattern` in a single scope.
+    ///
+    /// Returns an empty list when nothing matches. Worktree scope reads nothing
+    /// until `extensions.worktreeConfig` is enabled.
+    pub fn config_get_regexp(
+        &self,
+        pattern: &str,
+        scope: ConfigScope,
+    ) -> anyhow::Result<Vec<(String, String)>> {
+        if scope == ConfigScope::Worktree && !self.worktree_config_enabled()? {
+            return Ok(Vec::new());
+        }
+        let output = self.run_command_output(&scope.config_args(&["--get-regexp", pattern]))?;
+        match output.status.code() {
+            Some(0) => Ok(String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .map(|line| {
+                    // Format: "<key> <value>", or just "<key>" for a valueless entry
+                    let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+                    (key.to_string(), value.to_string())
+                })
+                .collect()),
+            // Exit code 1: no key matches
+            Some(1) => Ok(Vec::new()),
+            _ => anyhow::bail!(
+                "Failed to read git config {pattern}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        }
+    }
+
+    /// Whether `extensions.worktreeConfig` is enabled, allowing per-worktree config.
+    fn worktree_config_enabled(&self) -> anyhow::Result<bool> {
+        Ok(self
+            .config_get("extensions.worktreeConfig", ConfigScope::Local)?
+            .is_some_and(|v| {
+                matches!(v.to_ascii_lowercase().as_str(), "true" | "yes" | "on" | "1")
+            }))
+    }
+
     /// Read a user-defined marker from `worktrunk.state.<branch>.marker` in git config.
     ///
     /// Markers are stored as JSON: `{"marker": "text", "set_at": unix_timestamp}`.
@@ -33,10 +128,9 @@ impl Repository {
 
         let config_key = format!("worktrunk.state.{branch}.marker");
         let raw = self
-            .run_command(&["config", "--get", &config_key])
+            .config_get(&config_key, ConfigScope::Local)
             .ok()
-            .map(|output| output.trim().to_string())
-            .filter(|s| !s.is_empty())?;
+            .flatten()?;
 
         let parsed: MarkerValue = serde_json::from_str(&raw).ok()?;
         parsed.marker
@@ -47,12 +141,34 @@ impl Repository {
         branch.and_then(|branch| self.branch_marker(branch))
     }
 
+    /// Get the stash recorded by `wt switch --stash` for a branch's worktree.
+    ///
+    /// Stored as a stash commit SHA in `worktrunk.state.<branch>.stash`. Git config
+    /// is shared across worktrees, so any worktree can see (and restore) it.
+    pub fn branch_stash(&self, branch: &str) -> Option<String> {
+        self.config_get(
+            &format!("worktrunk.state.{branch}.stash"),
+            ConfigScope::Local,
+        )
+        .ok()
+        .flatten()
+    }
+
+    /// Record (or with `None`, clear) the stash for a branch's worktree.
+    pub fn set_branch_stash(&self, branch: &str, sha: Option<&str>) -> anyhow::Result<()> {
+        let key = format!("worktrunk.state.{branch}.stash");
+        match sha {
+            Some(sha) => self.config_set(&key, sha, ConfigScope::Local),
+            None => self.config_unset(&key, ConfigScope::Local).map(|_| ()),
+        }
+    }
+
     /// Set the previous branch in worktrunk.history for `wt switch -` support.
     ///
     /// Stores the branch we're switching FROM, so `wt switch -` can return to it.
     pub fn set_switch_previous(&self, previous: Option<&str>) -> anyhow::Result<()> {
         if let Some(prev) = previous {
-            self.run_command(&["config", "worktrunk.history", prev])?;
+            self.config_set("worktrunk.history", prev, ConfigScope::Local)?;
         }
         // If previous is None (detached HEAD), don't update history
         Ok(())
@@ -62,60 +178,84 @@ impl Repository {
     ///
     /// Returns the branch we came from, enabling ping-pong switching.
     pub fn switch_previous(&self) -> Option<String> {
-        self.run_command(&["config", "--get", "worktrunk.history"])
+        self.config_get("worktrunk.history", ConfigScope::Local)
             .ok()
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
+            .flatten()
     }
 
     /// Check if a hint has been shown in this repo.
     ///
-    /// Hints are stored as `worktrunk.hints.<name> = true`.
+    /// Hints are stored as `worktrunk.hints.<name> = true`. Any scope counts, so
+    /// setting a hint in global git config silences it everywhere.
     /// TODO: Could move to global git config if we accumulate more global hints.
     pub fn has_shown_hint(&self, name: &str) -> bool {
-        self.run_command(&["config", "--get", &format!("worktrunk.hints.{name}")])
-            .is_ok()
+        matches!(
+            self.config_get(&format!("worktrunk.hints.{name}"), ConfigScope::Any),
+            Ok(Some(_))
+        )
     }
 
     /// Mark a hint as shown in this repo.
     pub fn mark_hint_shown(&self, name: &str) -> anyhow::Result<()> {
-        self.run_command(&["config", &format!("worktrunk.hints.{name}"), "true"])?;
-        Ok(())
+        self.config_set(
+            &format!("worktrunk.hints.{name}"),
+            "true",
+            ConfigScope::Local,
+        )
     }
 
     /// Clear a hint so it will show again.
     pub fn clear_hint(&self, name: &str) -> anyhow::Result<bool> {
-        match self.run_command(&["config", "--unset", &format!("worktrunk.hints.{name}")]) {
-            Ok(_) => Ok(true),
-            Err(_) => Ok(false), // Key didn't exist
-        }
+        self.config_unset(&format!("worktrunk.hints.{name}"), ConfigScope::Local)
     }
 
-    /// List all hints that have been shown in this repo.
+    /// List all hints that have been shown in this repo (in any scope).
     pub fn list_shown_hints(&self) -> Vec<String> {
-        self.run_command(&["config", "--get-regexp", r"^worktrunk\.hints\."])
+        self.config_get_regexp(r"^worktrunk\.hints\.", ConfigScope::Any)
             .unwrap_or_default()
-            .lines()
-            .filter_map(|line| {
-                // Format: "worktrunk.hints.worktree-path true"
-                line.split_whitespace()
-                    .next()
-                    .and_then(|key| key.strip_prefix("worktrunk.hints."))
-                    .map(String::from)
-            })
+            .into_iter()
+            .filter_map(|(key, _)| key.strip_prefix("worktrunk.hints.").map(String::from))
             .collect()
     }
 
     /// Clear all hints so they will show again.
     pub fn clear_all_hints(&self) -> anyhow::Result<usize> {
-        let hints = self.list_shown_hints();
-        let count = hints.len();
-        for hint in hints {
-            self.clear_hint(&hint)?;
+        let mut count = 0;
+        for hint in self.list_shown_hints() {
+            // Hints set in global config are listed but stay put
+            if self.clear_hint(&hint)? {
+                count += 1;
+            }
         }
         Ok(count)
     }
 
+    /// Whether the repository already has a commit-graph file (single or split).
+    pub fn has_commit_graph(&self) -> bool {
+        let info = self.git_common_dir().join("objects/info");
+        info.join("commit-graph").is_file() || info.join("commit-graphs").is_dir()
+    }
+
+    /// Whether worktrunk has already written the commit-graph in this repo.
+    ///
+    /// Recorded as `worktrunk.maintenance.commit-graph = <epoch seconds>`.
+    pub fn commit_graph_maintenance_recorded(&self) -> bool {
+        matches!(
+            self.config_get("worktrunk.maintenance.commit-graph", ConfigScope::Local),
+            Ok(Some(_))
+        )
+    }
+
+    /// Record that worktrunk started a background commit-graph write.
+    pub fn record_commit_graph_maintenance(&self) -> anyhow::Result<()> {
+        let now = crate::utils::get_now().to_string();
+        self.config_set(
+            "worktrunk.maintenance.commit-graph",
+            &now,
+            ConfigScope::Local,
+        )
+    }
+
     // =========================================================================
     // Default branch detection
     // =========================================================================
@@ -145,10 +285,9 @@ impl Repository {
             .get_or_init(|| {
                 // Fast path: check worktrunk's persistent cache (git config)
                 let configured = self
-                    .run_command(&["config", "--get", "worktrunk.default-branch"])
+                    .config_get("worktrunk.default-branch", ConfigScope::Local)
                     .ok()
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty());
+                    .flatten();
 
                 // If configured, validate it exists locally
                 if let Some(ref branch) = configured {
@@ -177,7 +316,7 @@ impl Repository {
 
                 // Cache detected result to git config for future runs
                 if let Some(ref branch) = detected {
-                    let _ = self.run_command(&["config", "worktrunk.default-branch", branch]);
+                    let _ = self.config_set("worktrunk.default-branch", branch, ConfigScope::Local);
                 }
 
                 detected
@@ -217,6 +356,15 @@ impl Repository {
         self.query_remote_default_branch(&remote).ok()
     }
 
+    /// Like [`default_branch`](Self::default_branch), but errors when no default can be determined.
+    ///
+    /// Returns [`GitError::DefaultBranchUnknown`] so callers (e.g., `wt merge`, `wt step push`
+    /// without a target) can tell the user how to configure one.
+    pub fn require_default_branch(&self) -> anyhow::Result<String> {
+        self.default_branch()
+            .ok_or_else(|| GitError::DefaultBranchUnknown.into())
+    }
+
     /// Resolve a target branch from an optional override
     ///
     /// If target is Some, expands special symbols ("@", "-", "^") via `resolve_worktree_name`.
@@ -228,14 +376,7 @@ impl Repository {
     pub fn resolve_target_branch(&self, target: Option<&str>) -> anyhow::Result<String> {
         match target {
             Some(b) => self.resolve_worktree_name(b),
-            None => self.default_branch().ok_or_else(|| {
-                GitError::Other {
-                    message: cformat!(
-                        "Cannot determine default branch. Specify target explicitly or run <bold>wt config state default-branch set BRANCH</>"
-                    ),
-                }
-                .into()
-            }),
+            None => self.require_default_branch(),
         }
     }
 
@@ -249,6 +390,7 @@ impl Repository {
             return Err(GitError::BranchNotFound {
                 branch,
                 show_create_hint: true,
+                suggestion: None,
             }
             .into());
         }
@@ -320,6 +462,17 @@ impl Repository {
         .into())
     }
 
+    /// The primary remote's default branch as a remote-tracking ref, e.g. `origin/main`.
+    ///
+    /// Reads `refs/remotes/<remote>/HEAD` (set by `git clone` or `git remote
+    /// set-head`) without touching the network. Returns `None` when there's no
+    /// remote or its HEAD isn't configured.
+    pub fn remote_default_branch_ref(&self) -> Option<String> {
+        let remote = self.primary_remote().ok()?;
+        let branch = self.get_local_default_branch(&remote).ok()?;
+        Some(format!("{remote}/{branch}"))
+    }
+
     // Private helpers for default_branch detection
 
     fn get_local_default_branch(&self, remote: &str) -> anyhow::Result<String> {
@@ -338,8 +491,7 @@ impl Repository {
     /// This sets worktrunk's cache (`worktrunk.default-branch`). Use `clear` then
     /// `get` to re-detect from remote.
     pub fn set_default_branch(&self, branch: &str) -> anyhow::Result<()> {
-        self.run_command(&["config", "worktrunk.default-branch", branch])?;
-        Ok(())
+        self.config_set("worktrunk.default-branch", branch, ConfigScope::Local)
     }
 
     /// Clear the default branch cache.
@@ -349,9 +501,7 @@ impl Repository {
     ///
     /// Returns `true` if cache was cleared, `false` if no cache existed.
     pub fn clear_default_branch_cache(&self) -> anyhow::Result<bool> {
-        Ok(self
-            .run_command(&["config", "--unset", "worktrunk.default-branch"])
-            .is_ok())
+        self.config_unset("worktrunk.default-branch", ConfigScope::Local)
     }
 
     // =========================================================================
@@ -4,7 +4,7 @@ use std::collections::HashMap;
 
 use anyhow::{Context, bail};
 
-use super::{DiffStats, LineDiff, Repository};
+use super::{CommitMetadata, DiffStats, LineDiff, Repository};
 
 impl Repository {
     /// Count commits between base and head.
@@ -21,6 +21,34 @@ impl Repository {
             .context("Failed to parse commit count")
     }
 
+    /// List commits reachable from head but not base, newest first.
+    ///
+    /// Returns (short SHA, subject) pairs, capped at `limit` commits.
+    pub fn rev_list_commits(
+        &self,
+        base: &str,
+        head: &str,
+        limit: usize,
+    ) -> anyhow::Result<Vec<(String, String)>> {
+        let range = format!("{}..{}", base, head);
+        let max_count = format!("--max-count={limit}");
+        let stdout = self.run_command(&[
+            "log",
+            "--no-decorate",
+            "--format=%h%x1f%s",
+            &max_count,
+            &range,
+        ])?;
+
+        Ok(stdout
+            .lines()
+            .filter_map(|line| {
+                let (sha, subject) = line.split_once('\x1f')?;
+                Some((sha.to_string(), subject.to_string()))
+            })
+            .collect())
+    }
+
     /// Get files changed between base and head.
     ///
     /// For renames and copies, both old and new paths are included to ensure
@@ -85,27 +113,82 @@ impl Repository {
         Ok(result)
     }
 
+    /// Get timestamp, subject, and author for multiple commits in a single git command.
+    ///
+    /// Returns a map from commit SHA to metadata. Lets `wt list` fill in commit
+    /// details for every row at once instead of running `commit_details` per item.
+    /// Commits that can't be read are missing from the map.
+    pub fn commit_metadata(
+        &self,
+        commits: &[&str],
+    ) -> anyhow::Result<HashMap<String, CommitMetadata>> {
+        if commits.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        // NUL-separated fields: subjects and author names can contain spaces
+        let mut args = vec!["show", "-s", "--format=%H%x00%ct%x00%an%x00%ae%x00%s"];
+        args.extend(commits);
+
+        let stdout = self.run_command(&args)?;
+
+        let mut result = HashMap::with_capacity(commits.len());
+        for line in stdout.lines() {
+            let mut fields = line.splitn(5, '\0');
+            let (Some(sha), Some(timestamp), Some(author_name), Some(author_email), Some(subject)) = (
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+            ) else {
+                continue;
+            };
+            let Ok(timestamp) = timestamp.parse::<i64>() else {
+                continue;
+            };
+            result.insert(
+                sha.to_string(),
+                CommitMetadata {
+                    timestamp,
+                    // Match commit_message(), which trims the subject
+                    subject: subject.trim().to_owned(),
+                    author_name: author_name.to_owned(),
+                    author_email: author_email.to_owned(),
+                },
+            );
+        }
+
+        Ok(result)
+    }
+
     /// Get commit message (subject line) for a commit.
     pub fn commit_message(&self, commit: &str) -> anyhow::Result<String> {
         let stdout = self.run_command(&["show", "-s", "--format=%s", commit])?;
         Ok(stdout.trim().to_owned())
     }
 
-    /// Get commit timestamp and message in a single git command.
+    /// Get commit timestamp, subject, and author in a single git command.
     ///
-    /// More efficient than calling `commit_timestamp` and `commit_message` separately.
-    pub fn commit_details(&self, commit: &str) -> anyhow::Result<(i64, String)> {
-        // Use space separator - timestamps don't contain spaces, and %s (subject)
-        // is the first line only (no embedded newlines). Split on first space.
-        let stdout = self.run_command(&["show", "-s", "--format=%ct %s", commit])?;
-        // Only strip trailing newline, not spaces (empty subject = "timestamp ")
-        let line = stdout.trim_end_matches('\n');
-        let (timestamp_str, message) = line
-            .split_once(' ')
-            .context("Failed to parse commit details")?;
-        let timestamp = timestamp_str.parse().context("Failed to parse timestamp")?;
-        // Trim the message to match commit_message() behavior
-        Ok((timestamp, message.trim().to_owned()))
+    /// The single-commit counterpart of [`Repository::commit_metadata`].
+    pub fn commit_details(&self, commit: &str) -> anyhow::Result<CommitMetadata> {
+        // NUL-separated fields: subjects and author names can contain spaces
+        let stdout =
+            self.run_command(&["show", "-s", "--format=%ct%x00%an%x00%ae%x00%s", commit])?;
+        // Only strip the trailing newline; the subject is trimmed below
+        let mut fields = stdout.trim_end_matches('\n').splitn(4, '\0');
+        let (Some(timestamp), Some(author_name), Some(author_email), Some(subject)) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            bail!("Failed to parse commit details");
+        };
+        Ok(CommitMetadata {
+            timestamp: timestamp.parse().context("Failed to parse timestamp")?,
+            // Match commit_message(), which trims the subject
+            subject: subject.trim().to_owned(),
+            author_name: author_name.to_owned(),
+            author_email: author_email.to_owned(),
+        })
     }
 
     /// Get commit subjects (first line of commit message) from a range.
@@ -24,6 +24,21 @@ impl Repository {
         }
     }
 
+    /// Resolve a ref to its commit SHA, preferring branches over tags when names collide.
+    fn commit_sha_preferring_branch(&self, r: &str) -> anyhow::Result<String> {
+        let sha = self
+            .run_command(&[
+                "rev-parse",
+                "--verify",
+                "-q",
+                &format!("refs/heads/{r}^{{commit}}"),
+            ])
+            .or_else(|_| {
+                self.run_command(&["rev-parse", "--verify", &format!("{r}^{{commit}}")])
+            })?;
+        Ok(sha.trim().to_string())
+    }
+
     /// Check if base is an ancestor of head (i.e., would be a fast-forward).
     ///
     /// See [`--is-ancestor`][1] for details.
@@ -100,6 +115,11 @@ impl Repository {
     /// Uses `git merge-tree` to simulate a merge without touching the working tree.
     /// Returns true if conflicts would occur, false for a clean merge.
     ///
+    /// When either side is an ancestor of the other, the merge is a fast-forward
+    /// and can't conflict, so merge-tree is skipped. Ancestry comes from the cached
+    /// [`merge_base`](Self::merge_base) for the refs as given, which `wt list` has
+    /// usually computed already for the ahead/behind counts.
+    ///
     /// # Examples
     /// ```no_run
     /// use worktrunk::git::Repository;
@@ -109,8 +129,12 @@ impl Repository {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn has_merge_conflicts(&self, base: &str, head: &str) -> anyhow::Result<bool> {
-        let base = self.resolve_preferring_branch(base);
-        let head = self.resolve_preferring_branch(head);
+        let merge_base = self.merge_base(base, head)?;
+        let base = self.commit_sha_preferring_branch(base)?;
+        let head = self.commit_sha_preferring_branch(head)?;
+        if merge_base.is_some_and(|merge_base| merge_base == base || merge_base == head) {
+            return Ok(false);
+        }
         // Use modern merge-tree --write-tree mode which exits with 1 when conflicts exist
         // (the old 3-argument deprecated mode always exits with 0)
         // run_command_check returns true for exit 0, false otherwise
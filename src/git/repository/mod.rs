@@ -39,7 +39,7 @@ use crate::config::ProjectConfig;
 use super::{DefaultBranchName, GitError, LineDiff, WorktreeInfo};
 
 // Re-export types needed by submodules
-pub(super) use super::{BranchCategory, CompletionBranch, DiffStats, GitRemoteUrl};
+pub(super) use super::{BranchCategory, CommitMetadata, CompletionBranch, DiffStats, GitRemoteUrl};
 
 // Submodules with impl blocks
 mod branch;
@@ -64,7 +64,7 @@ pub(super) use working_tree::path_to_logging_context;
 ///
 /// Contains:
 /// - Repo-wide values (same for all worktrees): is_bare, default_branch, etc.
-/// - Per-worktree values keyed by path: worktree_root, current_branch
+/// - Per-worktree values keyed by path: worktree_root, git_dir, current_branch
 ///
 /// Wrapped in Arc to allow releasing the outer HashMap lock before accessing
 /// cached values, avoiding deadlocks when cached methods call each other.
@@ -95,10 +95,15 @@ pub(super) struct RepoCache {
     /// Batch ahead/behind cache: (base_ref, branch_name) -> (ahead, behind)
     /// Populated by batch_ahead_behind(), used by get_cached_ahead_behind()
     pub(super) ahead_behind: DashMap<(String, String), (usize, usize)>,
+    /// Upstream cache: branch_name -> upstream (None = no upstream configured)
+    /// Populated by `Branch::upstream()`, shared by the list Upstream and CI tasks
+    pub(super) upstreams: DashMap<String, Option<String>>,
 
     // ========== Per-worktree values (keyed by path) ==========
     /// Worktree root paths: worktree_path -> canonicalized root
     pub(super) worktree_roots: DashMap<PathBuf, PathBuf>,
+    /// Git directories: worktree_path -> canonicalized git dir
+    pub(super) git_dirs: DashMap<PathBuf, PathBuf>,
     /// Current branch per worktree: worktree_path -> branch name (None = detached HEAD)
     pub(super) current_branches: DashMap<PathBuf, Option<String>>,
 }
@@ -124,6 +129,53 @@ pub enum ResolvedWorktree {
     },
 }
 
+/// What `branch` names when creating a worktree with [`Repository::create_worktree`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorktreeCheckout<'a> {
+    /// An existing branch (git's DWIM creates it from a same-named remote branch)
+    Existing,
+    /// A new branch, created with `-b` from `base` (or `HEAD`)
+    NewBranch {
+        /// Branch or commit-ish to start from
+        base: Option<&'a str>,
+    },
+    /// A commit-ish, checked out with `--detach`
+    Detached,
+}
+
+/// Which git config file [`Repository::config_get`] and [`Repository::config_set`] use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigScope {
+    /// The user's global config (`~/.gitconfig`)
+    Global,
+    /// The repository's config, shared by all worktrees
+    Local,
+    /// The current worktree's config (`extensions.worktreeConfig`)
+    Worktree,
+    /// Every config file git reads, with its usual precedence. Writes go to the
+    /// repository's config, like plain `git config`.
+    Any,
+}
+
+impl ConfigScope {
+    fn flag(self) -> Option<&'static str> {
+        match self {
+            ConfigScope::Global => Some("--global"),
+            ConfigScope::Local => Some("--local"),
+            ConfigScope::Worktree => Some("--worktree"),
+            ConfigScope::Any => None,
+        }
+    }
+
+    /// `git config` arguments for this scope, followed by `args`.
+    fn config_args<'a>(self, args: &[&'a str]) -> Vec<&'a str> {
+        std::iter::once("config")
+            .chain(self.flag())
+            .chain(args.iter().copied())
+            .collect()
+    }
+}
+
 /// Global base path for repository operations, set by -C flag.
 static BASE_PATH: OnceLock<PathBuf> = OnceLock::new();
 
@@ -286,6 +338,15 @@ impl Repository {
         }
     }
 
+    /// Get the current branch name, or `None` in detached HEAD state.
+    ///
+    /// Failures to read HEAD are also treated as `None`; use
+    /// [`require_current_branch`](Self::require_current_branch) when the
+    /// operation needs a branch.
+    pub fn current_branch(&self) -> Option<String> {
+        self.current_worktree().branch().ok().flatten()
+    }
+
     /// Get the current branch name, or error if in detached HEAD state.
     ///
     /// `action` describes what requires being on a branch (e.g., "merge").
@@ -552,6 +613,32 @@ impl Repository {
         Ok(self.run_command_output(args)?.status.success())
     }
 
+    /// Run a git command with its output streamed live to the terminal.
+    ///
+    /// Unlike [`run_command`](Self::run_command), nothing is captured: the child's
+    /// stderr is inherited and its stdout is redirected to stderr, so progress for
+    /// long operations (e.g. `fetch`) appears as it happens while stdout stays
+    /// clean for directives and machine-readable output.
+    ///
+    /// Returns the exit status; callers decide whether a non-zero exit is an error.
+    pub fn run_command_streaming(&self, args: &[&str]) -> anyhow::Result<std::process::ExitStatus> {
+        let cmd_str = format!("git {}", args.join(" "));
+        log::debug!("$ {} [{}] (streaming)", cmd_str, self.logging_context());
+
+        // Flush our own output first so it isn't interleaved with the child's
+        std::io::stderr().flush().ok();
+
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(&self.discovery_path)
+            .stdin(Stdio::null())
+            .stdout(Stdio::from(std::io::stderr()))
+            .stderr(Stdio::inherit())
+            .env_remove(crate::shell_exec::DIRECTIVE_FILE_ENV_VAR)
+            .status()
+            .with_context(|| format!("Failed to execute: {}", cmd_str))
+    }
+
     /// Delay before showing progress output for slow operations.
     /// See .claude/rules/cli-output-formatting.md: "Progress messages apply only to slow operations (>400ms)"
     pub const SLOW_OPERATION_DELAY_MS: i64 = 400;
@@ -564,7 +651,8 @@ impl Repository {
     /// Pass `-1` to never switch to streaming (always buffer).
     ///
     /// If `progress_message` is provided, it will be printed to stderr when
-    /// streaming starts (i.e., when the delay threshold is exceeded).
+    /// streaming starts (i.e., when the delay threshold is exceeded), unless
+    /// `--quiet` is active.
     ///
     /// All output (both stdout and stderr from the child) is sent to stderr
     /// to keep stdout clean for commands like `wt switch`.
@@ -669,7 +757,9 @@ impl Repository {
                     {
                         streaming.store(true, Ordering::Relaxed);
 
-                        if let Some(ref msg) = progress_message {
+                        if let Some(ref msg) = progress_message
+                            && !crate::styling::is_quiet()
+                        {
                             let _ = writeln!(std::io::stderr(), "{}", msg);
                         }
                         for line in buffer.lock().unwrap().drain(..) {
@@ -1,8 +1,10 @@
 //! Remote and URL operations for Repository.
 
-use anyhow::Context;
+use anyhow::{Context, bail};
 
-use super::{GitRemoteUrl, Repository};
+use color_print::cformat;
+
+use super::{GitError, GitRemoteUrl, Repository};
 
 impl Repository {
     /// Get the primary remote name for this repository.
@@ -50,6 +52,30 @@ impl Repository {
             .ok_or_else(|| anyhow::anyhow!("No remotes configured"))
     }
 
+    /// Fetch from the primary remote to refresh remote-tracking refs.
+    ///
+    /// Runs `git fetch <remote>` once for the whole repository (refs are shared
+    /// across worktrees), streaming git's progress to stderr so a slow network
+    /// doesn't look like a hang. Returns the remote that was fetched, or `None`
+    /// when no remote is configured — there's nothing to refresh, so this isn't
+    /// an error.
+    pub fn fetch(&self) -> anyhow::Result<Option<String>> {
+        let Ok(remote) = self.primary_remote() else {
+            log::debug!("No remotes configured, skipping fetch");
+            return Ok(None);
+        };
+        // Use -- to prevent remote names starting with - from being interpreted as flags
+        let status = self.run_command_streaming(&["fetch", "--", &remote])?;
+        if !status.success() {
+            let exit_info = status
+                .code()
+                .map(|c| format!("exit code {c}"))
+                .unwrap_or_else(|| "killed by signal".to_string());
+            bail!("Failed to fetch from {} ({})", remote, exit_info);
+        }
+        Ok(Some(remote))
+    }
+
     /// Check if a remote has a URL configured.
     fn remote_has_url(&self, remote: &str) -> bool {
         self.run_command(&["config", &format!("remote.{}.url", remote)])
@@ -211,6 +237,35 @@ impl Repository {
             .and_then(|list| list.url)
     }
 
+    /// When the remote-tracking branch `upstream` (e.g. `origin/main`) was last
+    /// refreshed, in epoch seconds, read from filesystem metadata only.
+    ///
+    /// Uses the newest mtime of the ref's loose file (or `packed-refs` once it's
+    /// packed) and `FETCH_HEAD`, since a fetch that finds nothing new leaves the ref
+    /// file untouched. Returns `None` when none of these exist, e.g. with reftable.
+    pub fn remote_ref_fetched_at(&self, upstream: &str) -> Option<u64> {
+        let common_dir = self.git_common_dir();
+        let loose_ref = common_dir.join("refs/remotes").join(upstream);
+        let ref_file = if loose_ref.is_file() {
+            loose_ref
+        } else {
+            common_dir.join("packed-refs")
+        };
+
+        // FETCH_HEAD is per-worktree, so a fetch from a linked worktree lands there
+        let mut candidates = vec![ref_file, common_dir.join("FETCH_HEAD")];
+        if let Ok(git_dir) = self.current_worktree().git_dir() {
+            candidates.push(git_dir.join("FETCH_HEAD"));
+        }
+
+        candidates
+            .iter()
+            .filter_map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+            .max()
+            .and_then(|modified| modified.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|elapsed| elapsed.as_secs())
+    }
+
     /// Check if a ref is a remote tracking branch.
     ///
     /// Returns true if the ref exists under `refs/remotes/` (e.g., `origin/main`).
@@ -223,4 +278,60 @@ impl Repository {
         ])
         .is_ok()
     }
+
+    /// Set `branch` to track the remote-tracking branch `remote_ref` (e.g., `origin/main`).
+    ///
+    /// Errors if `remote_ref` isn't `<remote>/<branch>` or doesn't exist under
+    /// `refs/remotes/` (fetch first if the remote branch is new).
+    pub fn set_upstream(&self, branch: &str, remote_ref: &str) -> anyhow::Result<()> {
+        let well_formed = remote_ref
+            .split_once('/')
+            .is_some_and(|(remote, name)| !remote.is_empty() && !name.is_empty());
+        if !well_formed || !self.is_remote_tracking_branch(remote_ref) {
+            return Err(GitError::Other {
+                message: cformat!(
+                    "<bold>{remote_ref}</> is not a remote-tracking branch (expected e.g. <bold>origin/main</>)"
+                ),
+            }
+            .into());
+        }
+
+        self.run_command(&[
+            "branch",
+            &format!("--set-upstream-to=refs/remotes/{remote_ref}"),
+            branch,
+        ])?;
+        self.cache.upstreams.remove(branch);
+        Ok(())
+    }
+
+    /// Set `branch` to pull `merge_ref` from `remote` (`branch.<name>.remote` and
+    /// `branch.<name>.merge`).
+    ///
+    /// Unlike [`Self::set_upstream`], `merge_ref` needn't have a remote-tracking
+    /// branch, e.g. `refs/pull/123/head` for a fork PR.
+    pub fn set_branch_tracking(
+        &self,
+        branch: &str,
+        remote: &str,
+        merge_ref: &str,
+    ) -> anyhow::Result<()> {
+        self.run_command(&["config", &format!("branch.{branch}.remote"), remote])?;
+        self.run_command(&["config", &format!("branch.{branch}.merge"), merge_ref])?;
+        self.cache.upstreams.remove(branch);
+        Ok(())
+    }
+
+    /// Whether `branch` has local commits that aren't on its upstream.
+    ///
+    /// Uses the same ahead count as `wt list`'s upstream column, so it reflects
+    /// the remote-tracking ref as of the last fetch. Branches without an upstream
+    /// return false.
+    pub fn has_unpushed_commits(&self, branch: &str) -> anyhow::Result<bool> {
+        let Some(upstream) = self.branch(branch).upstream()? else {
+            return Ok(false);
+        };
+        let (ahead, _) = self.ahead_behind(&upstream, branch)?;
+        Ok(ahead > 0)
+    }
 }
@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 
 use anyhow::{Context, bail};
 
+use crate::git::short_sha;
 use crate::shell_exec::Cmd;
 use dunce::canonicalize;
 
@@ -171,7 +172,14 @@ impl<'a> WorkingTree<'a> {
     ///
     /// Always returns a canonicalized absolute path, resolving symlinks.
     /// This ensures consistent comparison with `git_common_dir()`.
+    ///
+    /// Result is cached in the repository's shared cache (keyed by worktree path);
+    /// failures are not cached.
     pub fn git_dir(&self) -> anyhow::Result<PathBuf> {
+        if let Some(cached) = self.repo.cache.git_dirs.get(&self.path) {
+            return Ok(cached.clone());
+        }
+
         let stdout = self.run_command(&["rev-parse", "--git-dir"])?;
         let path = PathBuf::from(stdout.trim());
 
@@ -181,7 +189,12 @@ impl<'a> WorkingTree<'a> {
         } else {
             path
         };
-        canonicalize(&absolute_path).context("Failed to resolve git directory")
+        let git_dir = canonicalize(&absolute_path).context("Failed to resolve git directory")?;
+        self.repo
+            .cache
+            .git_dirs
+            .insert(self.path.clone(), git_dir.clone());
+        Ok(git_dir)
     }
 
     /// Check if a rebase is in progress.
@@ -196,6 +209,18 @@ impl<'a> WorkingTree<'a> {
         Ok(git_dir.join("MERGE_HEAD").exists())
     }
 
+    /// Check if a cherry-pick is in progress.
+    pub fn is_cherry_picking(&self) -> anyhow::Result<bool> {
+        let git_dir = self.git_dir()?;
+        Ok(git_dir.join("CHERRY_PICK_HEAD").exists())
+    }
+
+    /// Check if a bisect is in progress.
+    pub fn is_bisecting(&self) -> anyhow::Result<bool> {
+        let git_dir = self.git_dir()?;
+        Ok(git_dir.join("BISECT_LOG").exists())
+    }
+
     /// Check if this is a linked worktree (vs the main worktree).
     ///
     /// Returns `true` for linked worktrees (created via `git worktree add`),
@@ -321,6 +346,6 @@ impl<'a> WorkingTree<'a> {
         ])
         .context("Failed to create backup ref")?;
 
-        Ok(backup_sha[..7].to_string())
+        Ok(short_sha(&backup_sha).to_string())
     }
 }
@@ -6,8 +6,9 @@ use color_print::cformat;
 use dunce::canonicalize;
 use normalize_path::NormalizePath;
 
-use super::{GitError, Repository, ResolvedWorktree, WorktreeInfo};
+use super::{GitError, Repository, ResolvedWorktree, WorkingTree, WorktreeCheckout, WorktreeInfo};
 use crate::path::format_path_for_display;
+use crate::styling::progress_message;
 
 impl Repository {
     /// List all worktrees for this repository.
@@ -48,12 +49,19 @@ impl Repository {
 
     /// Find the worktree path for a given branch, if one exists.
     pub fn worktree_for_branch(&self, branch: &str) -> anyhow::Result<Option<PathBuf>> {
-        let worktrees = self.list_worktrees()?;
+        Ok(self.worktree_info_for_branch(branch)?.map(|wt| wt.path))
+    }
 
-        Ok(worktrees
-            .iter()
-            .find(|wt| wt.branch.as_deref() == Some(branch))
-            .map(|wt| wt.path.clone()))
+    /// Find the worktree that has a given branch checked out, if one exists.
+    ///
+    /// Detached worktrees never match. A worktree in the middle of a rebase
+    /// matches the branch being rebased, since listing fills it in from the
+    /// rebase state.
+    pub fn worktree_info_for_branch(&self, branch: &str) -> anyhow::Result<Option<WorktreeInfo>> {
+        Ok(self
+            .list_worktrees()?
+            .into_iter()
+            .find(|wt| wt.branch.as_deref() == Some(branch)))
     }
 
     /// The "home" worktree — main worktree for normal repos, default branch worktree for bare.
@@ -99,6 +107,67 @@ impl Repository {
         Ok(())
     }
 
+    /// Create a worktree at `path` with `git worktree add`.
+    ///
+    /// `checkout` says what `branch` names (see [`WorktreeCheckout`]). A
+    /// `<remote>/<branch>` base for a new branch is passed as its full ref, so a
+    /// local branch with the same name (e.g. `origin/feature`) can't shadow it.
+    ///
+    /// The parent directory of `path` must exist. Output is only shown when git
+    /// takes longer than [`SLOW_OPERATION_DELAY_MS`](Self::SLOW_OPERATION_DELAY_MS).
+    pub fn create_worktree(
+        &self,
+        path: &Path,
+        branch: &str,
+        checkout: WorktreeCheckout<'_>,
+    ) -> anyhow::Result<WorkingTree<'_>> {
+        let base = match checkout {
+            WorktreeCheckout::NewBranch { base } => base,
+            WorktreeCheckout::Existing | WorktreeCheckout::Detached => None,
+        };
+        let base_ref = base.map(|base| {
+            if self.is_remote_tracking_branch(base) {
+                format!("refs/remotes/{base}")
+            } else {
+                base.to_string()
+            }
+        });
+
+        let path_str = path.to_string_lossy();
+        let mut args = vec!["worktree", "add"];
+        match checkout {
+            WorktreeCheckout::Existing => {}
+            WorktreeCheckout::NewBranch { .. } => args.extend(["-b", branch]),
+            WorktreeCheckout::Detached => args.push("--detach"),
+        }
+        // Use -- to prevent paths and refs starting with - from being interpreted as flags
+        args.extend(["--", path_str.as_ref()]);
+        match (checkout, &base_ref) {
+            (WorktreeCheckout::NewBranch { .. }, Some(base_ref)) => args.push(base_ref),
+            (WorktreeCheckout::NewBranch { .. }, None) => {}
+            _ => args.push(branch),
+        }
+
+        let progress = match checkout {
+            WorktreeCheckout::Detached => {
+                cformat!("Creating detached worktree at <bold>{branch}</>...")
+            }
+            _ => cformat!("Creating worktree for <bold>{branch}</>..."),
+        };
+        self.run_command_delayed_stream(
+            &args,
+            Self::SLOW_OPERATION_DELAY_MS,
+            Some(progress_message(progress).to_string()),
+        )
+        .map_err(|e| GitError::WorktreeCreationFailed {
+            branch: branch.to_string(),
+            base_branch: base.map(str::to_string),
+            error: e.to_string(),
+        })?;
+
+        Ok(self.worktree_at(path))
+    }
+
     /// Remove a worktree at the specified path.
     ///
     /// When `force` is true, passes `--force` to `git worktree remove`,
@@ -122,6 +191,36 @@ impl Repository {
         Ok(())
     }
 
+    /// Stash uncommitted changes (including untracked files) in the worktree at `path`.
+    ///
+    /// Returns the stash commit SHA, or `None` if there was nothing to stash.
+    /// The SHA stays valid as other stashes are pushed and popped, unlike
+    /// `stash@{n}` indices, which shift.
+    pub fn stash_push(&self, path: &Path, message: &str) -> anyhow::Result<Option<String>> {
+        let worktree = self.worktree_at(path);
+        if !worktree.is_dirty()? {
+            return Ok(None);
+        }
+        worktree.run_command(&["stash", "push", "--include-untracked", "-m", message])?;
+        let sha = worktree.run_command(&["rev-parse", "refs/stash"])?;
+        Ok(Some(sha.trim().to_string()))
+    }
+
+    /// Apply and drop the stash with commit `sha` in the worktree at `path`.
+    ///
+    /// Returns `false` if the stash no longer exists (e.g., dropped manually).
+    /// If applying conflicts, git keeps the stash and this returns an error.
+    pub fn stash_pop(&self, path: &Path, sha: &str) -> anyhow::Result<bool> {
+        // The stash list is shared by all worktrees; find the entry's current index
+        let list = self.run_command(&["stash", "list", "--format=%H"])?;
+        let Some(index) = list.lines().position(|line| line.trim() == sha) else {
+            return Ok(false);
+        };
+        self.worktree_at(path)
+            .run_command(&["stash", "pop", &format!("stash@{{{index}}}")])?;
+        Ok(true)
+    }
+
     /// Resolve a worktree name, expanding "@" to current, "-" to previous, and "^" to main.
     ///
     /// # Arguments
@@ -157,14 +256,7 @@ impl Repository {
                     .into()
                 })
             }
-            "^" => self.default_branch().ok_or_else(|| {
-                GitError::Other {
-                    message: cformat!(
-                        "Cannot determine default branch. Specify target explicitly or run <bright-black>wt config state default-branch set <bold>BRANCH</></>"
-                    ),
-                }
-                .into()
-            }),
+            "^" => self.require_default_branch(),
             _ => Ok(name.to_string()),
         }
     }
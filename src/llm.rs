@@ -602,6 +602,42 @@ pub(crate) fn generate_squash_message(
     Ok(commit_message)
 }
 
+/// Generate the message for a `wt merge --no-ff` merge commit.
+///
+/// Goes through the squash prompt when commit generation is configured — both
+/// summarize the branch's commits — and otherwise falls back to git's
+/// conventional merge subject with the merged commits listed.
+pub(crate) fn generate_merge_message(
+    target_branch: &str,
+    merge_base: &str,
+    subjects: &[String],
+    current_branch: &str,
+    repo_name: &str,
+    commit_generation_config: &CommitGenerationConfig,
+) -> anyhow::Result<String> {
+    if commit_generation_config.is_configured() {
+        return generate_squash_message(
+            target_branch,
+            merge_base,
+            subjects,
+            current_branch,
+            repo_name,
+            commit_generation_config,
+        );
+    }
+
+    let mut commit_message = format!(
+        "Merge branch '{}' into {}\n\n",
+        current_branch, target_branch
+    );
+    commit_message.push_str("Merged commits:\n");
+    for subject in subjects.iter().rev() {
+        // Reverse so they're in chronological order
+        commit_message.push_str(&format!("- {}\n", subject));
+    }
+    Ok(commit_message)
+}
+
 /// Build the squash prompt from commits being squashed.
 ///
 /// Gathers the combined diff, commit subjects, branch names, and recent commits, then
@@ -6,12 +6,12 @@ use clap::FromArgMatches;
 use clap::error::ErrorKind as ClapErrorKind;
 use color_print::{ceprintln, cformat};
 use std::process;
-use worktrunk::config::{UserConfig, set_config_path};
+use worktrunk::config::{UserConfig, set_config_path, set_config_profile};
 use worktrunk::git::{Repository, ResolvedWorktree, exit_code, set_base_path};
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell::extract_filename_from_path;
 use worktrunk::styling::{
-    eprintln, error_message, format_with_gutter, hint_message, info_message, success_message,
+    eprintln, error_message, format_with_gutter, info_message, print_hint, success_message,
     warning_message,
 };
 
@@ -46,14 +46,16 @@ use commands::handle_select;
 use commands::prune::handle_prune;
 use commands::worktree::handle_push;
 use commands::{
-    MergeOptions, OperationMode, RebaseResult, SquashResult, SwitchOptions, add_approvals,
-    clear_approvals, handle_completions, handle_config_create, handle_config_show,
-    handle_configure_shell, handle_hints_clear, handle_hints_get, handle_hook_show, handle_init,
-    handle_list, handle_logs_get, handle_merge, handle_rebase, handle_remove,
-    handle_remove_current, handle_show_theme, handle_squash, handle_state_clear,
+    ListOptions, MergeOptions, OperationMode, RebaseResult, SquashResult, SwitchOptions,
+    add_approvals, clear_approvals, handle_completions, handle_config_create, handle_config_edit,
+    handle_config_show, handle_configure_shell, handle_diff, handle_hints_clear, handle_hints_get,
+    handle_hook_show, handle_init, handle_init_print_path, handle_list, handle_log,
+    handle_logs_get, handle_merge, handle_merge_abort, handle_merge_continue,
+    handle_merge_into_current, handle_rebase, handle_remove, handle_remove_current,
+    handle_shell_status, handle_show_theme, handle_squash, handle_state_clear,
     handle_state_clear_all, handle_state_get, handle_state_set, handle_state_show, handle_switch,
-    handle_unconfigure_shell, resolve_worktree_arg, run_hook, step_commit, step_copy_ignored,
-    step_for_each, step_relocate,
+    handle_switch_manifest, handle_unconfigure_shell, read_branch_from_stdin, resolve_worktree_arg,
+    run_hook, step_commit, step_copy_ignored, step_for_each, step_relocate,
 };
 use output::handle_remove_output;
 
@@ -141,6 +143,11 @@ fn main() {
         set_config_path(path);
     }
 
+    // Initialize config profile from --profile flag if provided
+    if let Some(profile) = cli.profile {
+        set_config_profile(profile);
+    }
+
     // Configure logging based on --verbose flag or RUST_LOG env var
     // When -vv is set, also write logs to .git/wt-logs/verbose.log
     if cli.verbose >= 2 {
@@ -151,8 +158,9 @@ fn main() {
     let verbose_level = cli.verbose;
     let command_line = std::env::args().collect::<Vec<_>>().join(" ");
 
-    // Set global verbosity level for styled verbose output
+    // Set global verbosity level and quiet mode for styled output
     output::set_verbosity(verbose_level);
+    worktrunk::styling::set_quiet(cli.quiet);
 
     // -vv enables debug logging via env_logger; -v uses styled output (not logging)
     // Otherwise, respect RUST_LOG (defaulting to off)
@@ -230,10 +238,21 @@ fn main() {
         Commands::Config { action } => match action {
             ConfigCommand::Shell { action } => {
                 match action {
-                    ConfigShellCommand::Init { shell, cmd } => {
-                        // Generate shell code to stdout
+                    ConfigShellCommand::Init {
+                        shell,
+                        cmd,
+                        print_path,
+                        format,
+                    } => {
                         let cmd = cmd.unwrap_or_else(binary_name);
-                        handle_init(shell, cmd).map_err(|e| anyhow::anyhow!("{}", e))
+                        match shell {
+                            _ if print_path => handle_init_print_path(shell, &cmd, format),
+                            // Generate shell code to stdout
+                            Some(shell) => {
+                                handle_init(shell, cmd).map_err(|e| anyhow::anyhow!("{}", e))
+                            }
+                            None => unreachable!("clap requires a shell without --print-path"),
+                        }
                     }
                     ConfigShellCommand::Install {
                         shell,
@@ -342,12 +361,9 @@ fn main() {
                                             warning_message(format!("No {what} found in {path}"))
                                         );
                                     } else {
-                                        eprintln!(
-                                            "{}",
-                                            hint_message(cformat!(
-                                                "No <bright-black>{shell}</> {what} in {path}"
-                                            ))
-                                        );
+                                        print_hint(cformat!(
+                                            "No <bright-black>{shell}</> {what} in {path}"
+                                        ));
                                     }
                                 }
 
@@ -369,12 +385,9 @@ fn main() {
                                             ))
                                         );
                                     } else {
-                                        eprintln!(
-                                            "{}",
-                                            hint_message(cformat!(
-                                                "No <bright-black>{shell}</> completions in {path}"
-                                            ))
-                                        );
+                                        print_hint(cformat!(
+                                            "No <bright-black>{shell}</> completions in {path}"
+                                        ));
                                     }
                                 }
 
@@ -384,10 +397,7 @@ fn main() {
                                 if total_changes == 0 {
                                     if all_not_found == 0 {
                                         eprintln!();
-                                        eprintln!(
-                                            "{}",
-                                            hint_message("No shell integration found to remove")
-                                        );
+                                        print_hint("No shell integration found to remove");
                                     }
                                     return;
                                 }
@@ -415,13 +425,11 @@ fn main() {
                                     });
 
                                 if current_shell_affected {
-                                    eprintln!(
-                                        "{}",
-                                        hint_message("Restart shell to complete uninstall")
-                                    );
+                                    print_hint("Restart shell to complete uninstall");
                                 }
                             })
                     }
+                    ConfigShellCommand::Status => handle_shell_status(),
                     ConfigShellCommand::ShowTheme => {
                         handle_show_theme();
                         Ok(())
@@ -430,7 +438,12 @@ fn main() {
                 }
             }
             ConfigCommand::Create { project } => handle_config_create(project),
-            ConfigCommand::Show { full } => handle_config_show(full),
+            ConfigCommand::Show {
+                full,
+                effective,
+                format,
+            } => handle_config_show(full, effective, format),
+            ConfigCommand::Edit => handle_config_edit(),
             ConfigCommand::State { action } => match action {
                 StateCommand::DefaultBranch { action } => match action {
                     Some(DefaultBranchAction::Get) | None => {
@@ -523,7 +536,10 @@ fn main() {
                     })
                 }
             }
-            StepCommand::Push { target } => handle_push(target.as_deref(), "Pushed to", None),
+            StepCommand::Push {
+                target,
+                set_upstream,
+            } => handle_push(target.as_deref(), None, "Pushed to", None, set_upstream),
             StepCommand::Rebase { target } => {
                 handle_rebase(target.as_deref()).map(|result| match result {
                     RebaseResult::Rebased => (),
@@ -659,20 +675,36 @@ fn main() {
                 "{}",
                 error_message("Interactive picker is not available on Windows")
             );
-            eprintln!(
-                "{}",
-                hint_message(cformat!(
-                    "Specify a branch: <bright-black>wt switch BRANCH</>"
-                ))
-            );
+            print_hint(cformat!(
+                "Specify a branch: <bright-black>wt switch BRANCH</>"
+            ));
             std::process::exit(1);
         }
+        Commands::Log { branch, limit } => handle_log(branch.as_deref(), limit),
+        Commands::Diff { branch, vs } => handle_diff(branch.as_deref(), vs.as_deref()),
         Commands::List {
             subcommand,
             format,
+            fields,
             branches,
+            no_worktrees,
             remotes,
             full,
+            fetch,
+            no_upstream,
+            legend,
+            stats,
+            since,
+            since_fetch,
+            path_filter,
+            active_only,
+            conflicts_only,
+            group_by,
+            sort,
+            path_style,
+            remote_names,
+            max_width,
+            plain,
             progressive,
             no_progressive,
         } => match subcommand {
@@ -704,6 +736,29 @@ fn main() {
                         let show_branches = branches || resolved.list.branches();
                         let show_remotes = remotes || resolved.list.remotes();
                         let show_full = full || resolved.list.full();
+                        let show_upstream = !no_upstream && resolved.list.upstream();
+                        if no_worktrees && !show_branches {
+                            anyhow::bail!("--no-worktrees requires --branches");
+                        }
+                        if !fields.is_empty() && !format.is_json() {
+                            anyhow::bail!("--fields requires --format=json or --format=jsonl");
+                        }
+                        if plain && !matches!(format, cli::ListFormat::Table) {
+                            anyhow::bail!("--plain requires --format=table");
+                        }
+                        if stats && !matches!(format, cli::ListFormat::Table) {
+                            anyhow::bail!("--stats requires --format=table");
+                        }
+                        let path_filter = path_filter
+                            .as_deref()
+                            .map(|pattern| {
+                                let expanded = worktrunk::path::expand_home_and_env(pattern)
+                                    .map_err(|e| anyhow::anyhow!("--path-filter: {e}"))?;
+                                glob::Pattern::new(&expanded).with_context(|| {
+                                    format!("Invalid --path-filter glob '{pattern}'")
+                                })
+                            })
+                            .transpose()?;
 
                         // Convert two bools to Option<bool>: Some(true), Some(false), or None
                         let progressive_opt = match (progressive, no_progressive) {
@@ -713,11 +768,30 @@ fn main() {
                         };
                         let render_mode = RenderMode::detect(progressive_opt);
                         handle_list(
-                            format,
-                            show_branches,
-                            show_remotes,
-                            show_full,
-                            render_mode,
+                            ListOptions {
+                                format,
+                                show_branches,
+                                show_worktrees: !no_worktrees,
+                                show_remotes,
+                                show_full,
+                                fetch,
+                                show_upstream,
+                                legend,
+                                stats,
+                                since,
+                                since_fetch,
+                                path_filter: path_filter.as_ref(),
+                                active_only,
+                                conflicts_only,
+                                group_by,
+                                sort,
+                                path_style,
+                                remote_names,
+                                max_width,
+                                plain,
+                                fields: &fields,
+                                render_mode,
+                            },
                             &config,
                         )
                     })
@@ -725,19 +799,44 @@ fn main() {
         },
         Commands::Switch {
             branch,
+            from_stdin,
+            json_stdin,
             branches,
             remotes,
             create,
+            reuse,
+            detach,
             base,
+            base_from_remote_default,
+            fetch,
+            track,
             execute,
             execute_args,
+            open,
             yes,
             clobber,
+            stash,
+            template,
+            name,
+            copy_env,
             no_cd,
+            cd_only,
+            dry_run,
             verify,
+            format,
         } => UserConfig::load()
             .context("Failed to load config")
             .and_then(|mut config| {
+                if json_stdin {
+                    return handle_switch_manifest(&config);
+                }
+
+                let branch = if from_stdin {
+                    Some(read_branch_from_stdin()?)
+                } else {
+                    branch
+                };
+
                 // No branch argument: open interactive picker
                 let Some(branch) = branch else {
                     #[cfg(unix)]
@@ -769,12 +868,9 @@ fn main() {
                             "{}",
                             error_message("Interactive picker is not available on Windows")
                         );
-                        eprintln!(
-                            "{}",
-                            hint_message(cformat!(
-                                "Specify a branch: <bright-black>wt switch BRANCH</>"
-                            ))
-                        );
+                        print_hint(cformat!(
+                            "Specify a branch: <bright-black>wt switch BRANCH</>"
+                        ));
                         std::process::exit(2);
                     }
                 };
@@ -783,13 +879,26 @@ fn main() {
                     SwitchOptions {
                         branch: &branch,
                         create,
+                        reuse,
+                        detach,
                         base: base.as_deref(),
+                        base_from_remote_default,
+                        fetch,
+                        track: track.as_deref(),
                         execute: execute.as_deref(),
                         execute_args: &execute_args,
+                        open,
                         yes,
                         clobber,
+                        template: template.as_deref(),
+                        name: name.as_deref(),
+                        copy_env,
                         change_dir: !no_cd,
+                        cd_only,
+                        stash,
                         verify,
+                        format,
+                        dry_run,
                     },
                     &mut config,
                     &binary_name(),
@@ -848,7 +957,7 @@ fn main() {
                 if branches.is_empty() {
                     // Single worktree removal: validate FIRST, then approve, then execute
                     let result =
-                        handle_remove_current(!delete_branch, force_delete, force, &config)
+                        handle_remove_current(!delete_branch, force_delete, force, yes, &config)
                             .context("Failed to remove worktree")?;
 
                     // "Approve at the Gate": approval happens AFTER validation passes
@@ -913,6 +1022,7 @@ fn main() {
                                         !delete_branch,
                                         force_delete,
                                         force,
+                                        yes,
                                         &config,
                                     ) {
                                         Ok(result) => plan_current = Some(result),
@@ -931,6 +1041,7 @@ fn main() {
                                     !delete_branch,
                                     force_delete,
                                     force,
+                                    yes,
                                     &config,
                                 ) {
                                     Ok(result) => plans_others.push(result),
@@ -943,6 +1054,7 @@ fn main() {
                                     !delete_branch,
                                     force_delete,
                                     force,
+                                    yes,
                                     &config,
                                 ) {
                                     Ok(result) => plans_branch_only.push(result),
@@ -1010,6 +1122,12 @@ fn main() {
                 };
                 handle_prune(opts, &config)
             }),
+        Commands::Merge { abort: true, .. } => handle_merge_abort(),
+        Commands::Merge {
+            target,
+            into_current: true,
+            ..
+        } => handle_merge_into_current(target.as_deref()),
         Commands::Merge {
             target,
             squash,
@@ -1023,7 +1141,13 @@ fn main() {
             verify,
             no_verify,
             yes,
+            confirm,
             stage,
+            no_ff,
+            into_current: _,
+            abort: _,
+            continue_merge,
+            format,
         } => {
             // Convert paired flags to Option<bool>
             fn flag_pair(positive: bool, negative: bool) -> Option<bool> {
@@ -1036,7 +1160,7 @@ fn main() {
 
             // Pass CLI flags as options; handle_merge determines effective defaults
             // using per-project config merged with global config
-            handle_merge(MergeOptions {
+            let opts = MergeOptions {
                 target: target.as_deref(),
                 squash: flag_pair(squash, no_squash),
                 commit: flag_pair(commit, no_commit),
@@ -1044,8 +1168,16 @@ fn main() {
                 remove: flag_pair(remove, no_remove),
                 verify: flag_pair(verify, no_verify),
                 yes,
+                confirm,
                 stage,
-            })
+                no_ff,
+                format,
+            };
+            if continue_merge {
+                handle_merge_continue(opts)
+            } else {
+                handle_merge(opts)
+            }
         }
     };
 
@@ -342,6 +342,7 @@ fn colorize_status_symbols(text: &str) -> String {
     // Working tree symbols: CYAN
     result = replace_dim(result, "+", working_tree);
     result = replace_dim(result, "!", working_tree);
+    result = replace_dim(result, "§", working_tree);
     result = replace_dim(result, "?", working_tree);
 
     // Conflicts: ERROR (red)
@@ -350,6 +351,8 @@ fn colorize_status_symbols(text: &str) -> String {
     // Git operations, MergeTreeConflicts: WARNING (yellow)
     result = replace_dim(result, "⤴", warning);
     result = replace_dim(result, "⤵", warning);
+    result = replace_dim(result, "⤷", warning);
+    result = replace_dim(result, "÷", warning);
     result = replace_dim(result, "✗", warning);
 
     // Worktree state: BranchWorktreeMismatch (red), Prunable/Locked (yellow)
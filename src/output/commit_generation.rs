@@ -8,7 +8,7 @@ use std::io::{self, IsTerminal};
 
 use color_print::cformat;
 use worktrunk::config::UserConfig;
-use worktrunk::styling::{eprintln, format_toml, hint_message, info_message, success_message};
+use worktrunk::styling::{eprintln, format_toml, info_message, print_hint, success_message};
 
 use super::prompt::{PromptResponse, prompt_yes_no_preview};
 
@@ -155,22 +155,16 @@ pub fn prompt_commit_generation(config: &mut UserConfig) -> anyhow::Result<bool>
             let command = command.to_string();
             if let Err(e) = config.set_commit_generation_command(command.clone(), None) {
                 log::error!("Failed to save config: {}", e);
-                eprintln!(
-                    "{}",
-                    hint_message(cformat!(
-                        "Config save failed; add manually to <bright-black>~/.config/worktrunk/config.toml</>"
-                    ))
-                );
+                print_hint(cformat!(
+                    "Config save failed; add manually to <bright-black>~/.config/worktrunk/config.toml</>"
+                ));
                 return Ok(false);
             }
 
             // Show what was added
             eprintln!("{}", success_message(cformat!("Added to user config:")));
             eprintln!("{}", format_toml(&config_preview));
-            eprintln!(
-                "{}",
-                hint_message(cformat!("View config: <bright-black>wt config show</>"))
-            );
+            print_hint(cformat!("View config: <bright-black>wt config show</>"));
 
             // Blank line separates this setup phase from the main operation that follows
             eprintln!();
@@ -16,6 +16,11 @@
 //! When `WORKTRUNK_DIRECTIVE_FILE` is set (by the shell wrapper), wt writes shell commands
 //! (like `cd '/path'`) to that file. The shell wrapper sources the file after wt exits.
 //! This allows the parent shell to change directory.
+//!
+//! The first line written is a `# worktrunk-protocol <N>` comment
+//! ([`DIRECTIVE_PROTOCOL_VERSION`]). Wrappers compare it against the version they
+//! were generated with and warn on mismatch, which catches a binary upgraded under
+//! a shell that still has the old wrapper loaded.
 
 use std::fs::OpenOptions;
 use std::io::{self, Write};
@@ -29,9 +34,9 @@ use std::sync::{Mutex, OnceLock};
 use worktrunk::git::WorktrunkError;
 #[cfg(not(unix))]
 use worktrunk::shell_exec::Cmd;
-use worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR;
 #[cfg(unix)]
 use worktrunk::shell_exec::ShellConfig;
+use worktrunk::shell_exec::{DIRECTIVE_FILE_ENV_VAR, DIRECTIVE_PROTOCOL_VERSION};
 
 // Re-export set_verbosity from the library's styling module.
 // This ensures the binary and library share the same global state.
@@ -54,6 +59,8 @@ struct OutputState {
     /// Path to the directive file (from WORKTRUNK_DIRECTIVE_FILE env var)
     /// If None, we're in interactive mode (no shell wrapper)
     directive_file: Option<PathBuf>,
+    /// Whether the protocol header has been written to the directive file
+    wrote_protocol_header: bool,
     /// Buffered target directory for execute() in interactive mode
     target_dir: Option<PathBuf>,
 }
@@ -71,6 +78,7 @@ fn get_state() -> &'static Mutex<OutputState> {
 
         Mutex::new(OutputState {
             directive_file,
+            wrote_protocol_header: false,
             target_dir: None,
         })
     })
@@ -86,22 +94,45 @@ fn has_directive_file() -> bool {
 }
 
 /// Write a directive to the directive file (if set)
+///
+/// The first directive is preceded by the protocol header.
 fn write_directive(directive: &str) -> io::Result<()> {
     // Copy path out of lock to avoid holding mutex during I/O
-    let path = {
-        let guard = get_state().lock().expect("OUTPUT_STATE lock poisoned");
-        guard.directive_file.clone()
+    let (path, needs_header) = {
+        let mut guard = get_state().lock().expect("OUTPUT_STATE lock poisoned");
+        let needs_header = !guard.wrote_protocol_header;
+        guard.wrote_protocol_header = true;
+        (guard.directive_file.clone(), needs_header)
     };
 
     let Some(path) = path else {
         return Ok(());
     };
 
+    // cmd reads batch files line by line and is only reliable with CRLF endings
+    let eol = match DirectiveShell::current() {
+        DirectiveShell::Cmd => "\r\n",
+        _ => "\n",
+    };
     let mut file = OpenOptions::new().append(true).open(&path)?;
-    writeln!(file, "{}", directive)?;
+    if needs_header {
+        write!(file, "{}{eol}", protocol_header())?;
+    }
+    write!(file, "{}{eol}", directive.replace('\n', eol))?;
     file.flush()
 }
 
+/// First line of a directive file, read by the shell wrappers.
+///
+/// A comment in whichever shell sources the file (`@rem` for cmd, `#` elsewhere).
+fn protocol_header() -> String {
+    let comment = match DirectiveShell::current() {
+        DirectiveShell::Cmd => "@rem",
+        _ => "#",
+    };
+    format!("{comment} worktrunk-protocol {DIRECTIVE_PROTOCOL_VERSION}")
+}
+
 /// Request directory change (for shell integration)
 ///
 /// If shell integration is active (WORKTRUNK_DIRECTIVE_FILE set), writes `cd` command to the file.
@@ -117,30 +148,131 @@ pub fn change_directory(path: impl AsRef<Path>) -> io::Result<()> {
     if guard.directive_file.is_some() {
         drop(guard); // Release lock before I/O
 
-        let path_str = path.to_string_lossy();
-        // Escape based on shell type. Both shell families use single-quoted strings
-        // where contents are literal, but they escape embedded quotes differently:
-        // - PowerShell: double the quote ('it''s')
-        // - POSIX (bash/zsh/fish): end quote, escaped quote, start quote ('it'\''s')
-        let is_powershell = std::env::var("WORKTRUNK_SHELL")
-            .map(|v| v.eq_ignore_ascii_case("powershell"))
-            .unwrap_or(false);
-        let escaped = if is_powershell {
-            path_str.replace('\'', "''")
-        } else {
-            path_str.replace('\'', "'\\''")
+        let shell = DirectiveShell::current();
+        let quoted = shell.quote(&path.to_string_lossy());
+        // Plain `cd` in cmd doesn't switch drives
+        let cd = match shell {
+            DirectiveShell::Cmd => "cd /d",
+            _ => "cd",
         };
-        write_directive(&format!("cd '{}'", escaped))?;
+        write_directive(&format!("{cd} {quoted}"))?;
     }
 
     Ok(())
 }
 
+/// Shell family of the wrapper that sources the directive file.
+///
+/// Wrappers announce themselves via `WORKTRUNK_SHELL`; bash and zsh don't set it, and
+/// neither do wrappers generated before fish started to, so the default is POSIX.
+/// Those older fish wrappers still run POSIX directives, since fish ships an
+/// `export` function; only the trailing `unset` fails there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DirectiveShell {
+    Posix,
+    Fish,
+    PowerShell,
+    Cmd,
+}
+
+impl DirectiveShell {
+    fn current() -> Self {
+        match std::env::var("WORKTRUNK_SHELL") {
+            Ok(v) if v.eq_ignore_ascii_case("powershell") => Self::PowerShell,
+            Ok(v) if v.eq_ignore_ascii_case("fish") => Self::Fish,
+            Ok(v) if v.eq_ignore_ascii_case("cmd") => Self::Cmd,
+            _ => Self::Posix,
+        }
+    }
+
+    /// Quote `value` as a single literal word.
+    ///
+    /// POSIX, fish and PowerShell use single-quoted strings but escape inside them
+    /// differently:
+    /// - POSIX: end quote, escaped quote, start quote (`'it'\''s'`)
+    /// - fish: backslash escapes `\` and `'` (`'it\'s'`)
+    /// - PowerShell: double the quote (`'it''s'`)
+    ///
+    /// cmd has no single quotes; double quotes protect spaces and `&`, and `%` is
+    /// doubled so batch files don't expand it (`"100%%"`).
+    fn quote(self, value: &str) -> String {
+        let escaped = match self {
+            Self::Posix => value.replace('\'', "'\\''"),
+            Self::Fish => value.replace('\\', "\\\\").replace('\'', "\\'"),
+            Self::PowerShell => value.replace('\'', "''"),
+            Self::Cmd => return format!("\"{}\"", value.replace('%', "%%")),
+        };
+        format!("'{escaped}'")
+    }
+
+    /// Set `env` around a directive command, then unset it again.
+    ///
+    /// The command runs in the user's shell itself, not a subshell or scope block,
+    /// so `-x 'source .venv/bin/activate'` or `-x 'export FOO=1'` still changes the
+    /// session. Every part of a compound command (`a && b`) sees the variables, and
+    /// they're removed once it finishes:
+    /// - POSIX: `export` before, `unset` after
+    /// - fish: `set -gx` before, `set -e` after
+    /// - PowerShell: `$env:` assignments before, `Remove-Item Env:` after
+    /// - cmd: `set "K=v"` before, `set "K="` after
+    ///
+    /// The command sits on its own line, so it may start with `(`, `{` or `if`, or
+    /// end in a comment.
+    fn with_env(self, command: &str, env: &[(&str, String)]) -> String {
+        if env.is_empty() {
+            return command.to_string();
+        }
+        let keys: Vec<&str> = env.iter().map(|(key, _)| *key).collect();
+        let mut lines: Vec<String> = Vec::new();
+        match self {
+            Self::Posix => {
+                lines.extend(env.iter().map(|(key, value)| {
+                    format!("export {key}={}", shell_escape::escape(value.into()))
+                }));
+                lines.push(command.to_string());
+                lines.push(format!("unset {}", keys.join(" ")));
+            }
+            Self::Fish => {
+                lines.extend(
+                    env.iter()
+                        .map(|(key, value)| format!("set -gx {key} {}", self.quote(value))),
+                );
+                lines.push(command.to_string());
+                lines.push(format!("set -e {}", keys.join(" ")));
+            }
+            Self::PowerShell => {
+                lines.extend(
+                    env.iter()
+                        .map(|(key, value)| format!("$env:{key} = {}", self.quote(value))),
+                );
+                lines.push(command.to_string());
+                lines.extend(
+                    keys.iter()
+                        .map(|key| format!("Remove-Item Env:{key} -ErrorAction Ignore")),
+                );
+            }
+            Self::Cmd => {
+                lines.extend(
+                    env.iter()
+                        .map(|(key, value)| format!("set \"{key}={}\"", value.replace('%', "%%"))),
+                );
+                lines.push(command.to_string());
+                lines.extend(keys.iter().map(|key| format!("set \"{key}=\"")));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
 /// Request command execution
 ///
 /// In interactive mode (no directive file), executes the command directly (replacing process on Unix).
 /// In shell integration mode, writes the command to the directive file.
-pub fn execute(command: impl Into<String>) -> anyhow::Result<()> {
+///
+/// `env` is set for the command only. In shell integration mode the command runs
+/// in the user's shell, with the variables unset afterwards (see
+/// [`DirectiveShell::with_env`]).
+pub fn execute(command: impl Into<String>, env: &[(&str, String)]) -> anyhow::Result<()> {
     let command = command.into();
 
     let (has_directive, target_dir) = {
@@ -150,17 +282,21 @@ pub fn execute(command: impl Into<String>) -> anyhow::Result<()> {
 
     if has_directive {
         // Write to directive file
-        write_directive(&command)?;
+        write_directive(&DirectiveShell::current().with_env(&command, env))?;
         Ok(())
     } else {
         // Execute directly
-        execute_command(command, target_dir.as_deref())
+        execute_command(command, target_dir.as_deref(), env)
     }
 }
 
 /// Execute a command in the given directory (Unix: exec, non-Unix: spawn)
 #[cfg(unix)]
-fn execute_command(command: String, target_dir: Option<&Path>) -> anyhow::Result<()> {
+fn execute_command(
+    command: String,
+    target_dir: Option<&Path>,
+    env: &[(&str, String)],
+) -> anyhow::Result<()> {
     let exec_dir = target_dir.unwrap_or_else(|| Path::new("."));
     let shell = ShellConfig::get();
 
@@ -170,6 +306,7 @@ fn execute_command(command: String, target_dir: Option<&Path>) -> anyhow::Result
     let mut cmd = shell.command(&command);
     let err = cmd
         .current_dir(exec_dir)
+        .envs(env.iter().map(|(key, value)| (key, value)))
         .stdin(Stdio::inherit())
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
@@ -186,8 +323,15 @@ fn execute_command(command: String, target_dir: Option<&Path>) -> anyhow::Result
 
 /// Execute a command in the given directory (non-Unix: spawn and wait)
 #[cfg(not(unix))]
-fn execute_command(command: String, target_dir: Option<&Path>) -> anyhow::Result<()> {
+fn execute_command(
+    command: String,
+    target_dir: Option<&Path>,
+    env: &[(&str, String)],
+) -> anyhow::Result<()> {
     let mut cmd = Cmd::shell(&command).stdin(Stdio::inherit());
+    for (key, value) in env {
+        cmd = cmd.env(*key, value);
+    }
     if let Some(dir) = target_dir {
         cmd = cmd.current_dir(dir);
     }
@@ -318,6 +462,12 @@ pub fn post_hook_display_path(destination: &std::path::Path) -> Option<&std::pat
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_protocol_header_is_shell_comment() {
+        // Older wrappers source the header as-is, so it must be a no-op in every shell
+        assert_eq!(protocol_header(), "# worktrunk-protocol 1");
+    }
+
     #[test]
     fn test_compute_hooks_display_path_same_location() {
         let path = PathBuf::from("/repo/worktree");
@@ -516,4 +666,51 @@ mod tests {
 
         // The good pattern maintains color through the bold section
     }
+
+    #[test]
+    fn test_directive_shell_quote() {
+        let value = r"/tmp/it's; __WORKTRUNK_EXEC__ \";
+        assert_eq!(
+            DirectiveShell::Posix.quote(value),
+            r"'/tmp/it'\''s; __WORKTRUNK_EXEC__ \'"
+        );
+        assert_eq!(
+            DirectiveShell::Fish.quote(value),
+            r"'/tmp/it\'s; __WORKTRUNK_EXEC__ \\'"
+        );
+        assert_eq!(
+            DirectiveShell::PowerShell.quote(value),
+            r"'/tmp/it''s; __WORKTRUNK_EXEC__ \'"
+        );
+        assert_eq!(
+            DirectiveShell::Cmd.quote(r"C:\work\100% & done"),
+            r#""C:\work\100%% & done""#
+        );
+    }
+
+    #[test]
+    fn test_directive_shell_with_env() {
+        assert_eq!(DirectiveShell::Posix.with_env("claude", &[]), "claude");
+
+        let env = [
+            ("WORKTRUNK_BRANCH", "feature".to_string()),
+            ("WORKTRUNK_WORKTREE_PATH", "/tmp/it's here".to_string()),
+        ];
+        assert_eq!(
+            DirectiveShell::Posix.with_env("a && b", &env),
+            "export WORKTRUNK_BRANCH=feature\nexport WORKTRUNK_WORKTREE_PATH='/tmp/it'\\''s here'\na && b\nunset WORKTRUNK_BRANCH WORKTRUNK_WORKTREE_PATH"
+        );
+        assert_eq!(
+            DirectiveShell::Fish.with_env("a; and b", &env),
+            "set -gx WORKTRUNK_BRANCH 'feature'\nset -gx WORKTRUNK_WORKTREE_PATH '/tmp/it\\'s here'\na; and b\nset -e WORKTRUNK_BRANCH WORKTRUNK_WORKTREE_PATH"
+        );
+        assert_eq!(
+            DirectiveShell::PowerShell.with_env("claude", &env[..1]),
+            "$env:WORKTRUNK_BRANCH = 'feature'\nclaude\nRemove-Item Env:WORKTRUNK_BRANCH -ErrorAction Ignore"
+        );
+        assert_eq!(
+            DirectiveShell::Cmd.with_env("claude", &env[..1]),
+            "set \"WORKTRUNK_BRANCH=feature\"\nclaude\nset \"WORKTRUNK_BRANCH=\""
+        );
+    }
 }
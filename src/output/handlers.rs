@@ -24,8 +24,9 @@ use worktrunk::git::Repository;
 use worktrunk::git::path_dir_name;
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{
-    FormattedMessage, eprintln, error_message, format_with_gutter, hint_message, info_message,
-    progress_message, success_message, suggest_command, warning_message,
+    FormattedMessage, eprintln, error_message, format_with_gutter, info_message, is_quiet,
+    print_hint, print_progress, progress_message, success_message, suggest_command,
+    warning_message,
 };
 
 use super::shell_integration::{
@@ -37,6 +38,8 @@ use super::shell_integration::{
 ///
 /// # Message formats
 /// - Branch + worktree created (`--create`): "Created branch X from Y and worktree @ path"
+/// - Branch tracking a same-named remote base (`--create X --base origin/X`):
+///   "Created branch X from origin/X (tracking) and worktree @ path"
 /// - Branch from remote + worktree (DWIM): "Created branch X (tracking remote) and worktree @ path"
 /// - Worktree only created: "Created worktree for X @ path"
 /// - Switched to existing: "Switched to worktree for X @ path"
@@ -52,11 +55,22 @@ fn format_switch_message(
 
     if created_branch {
         // --create flag: created branch and worktree
-        match base_branch {
-            Some(base) => cformat!(
+        match (base_branch, from_remote) {
+            // --base <remote>/<branch> with the same name: new branch tracks the base
+            (Some(base), Some(remote)) if base == remote => cformat!(
+                "Created branch <bold>{branch}</> from <bold>{base}</> (tracking) and worktree @ <bold>{path_display}</>"
+            ),
+            // --track <remote>: new branch tracks its same-named remote branch
+            (Some(base), Some(remote)) => cformat!(
+                "Created branch <bold>{branch}</> from <bold>{base}</> (tracking <bold>{remote}</>) and worktree @ <bold>{path_display}</>"
+            ),
+            (Some(base), None) => cformat!(
                 "Created branch <bold>{branch}</> from <bold>{base}</> and worktree @ <bold>{path_display}</>"
             ),
-            None => {
+            (None, Some(remote)) => cformat!(
+                "Created branch <bold>{branch}</> (tracking <bold>{remote}</>) and worktree @ <bold>{path_display}</>"
+            ),
+            (None, None) => {
                 cformat!("Created branch <bold>{branch}</> and worktree @ <bold>{path_display}</>")
             }
         }
@@ -111,12 +125,9 @@ fn handle_branch_deletion_result(
                     ))
                 );
                 let cmd = suggest_command("remove", &[branch_name], &["-D"]);
-                eprintln!(
-                    "{}",
-                    hint_message(cformat!(
-                        "To delete the unmerged branch, run <bright-black>{cmd}</>"
-                    ))
-                );
+                print_hint(cformat!(
+                    "To delete the unmerged branch, run <bright-black>{cmd}</>"
+                ));
             }
             Ok((r, defer_output))
         }
@@ -268,7 +279,7 @@ fn print_switch_message_if_changed(
                 "Cannot change directory — ran git wt; running through git prevents cd",
             )
         );
-        eprintln!("{}", hint_message(git_subcommand_warning()));
+        print_hint(git_subcommand_warning());
     } else {
         // Shell integration not active - compute specific reason
         let reason = compute_shell_warning_reason();
@@ -278,9 +289,9 @@ fn print_switch_message_if_changed(
         );
         // Show appropriate hint based on invocation mode
         if should_show_explicit_path_hint() {
-            eprintln!("{}", hint_message(explicit_path_hint(&dest_branch)));
+            print_hint(explicit_path_hint(&dest_branch));
         } else {
-            eprintln!("{}", hint_message(shell_integration_hint()));
+            print_hint(shell_integration_hint());
         }
     }
     Ok(())
@@ -309,6 +320,20 @@ fn resolve_subdir_in_target(target_root: &Path, source_root: Option<&Path>, cwd:
     target_root.to_path_buf()
 }
 
+/// Request a cd into `target_root`, keeping the user's subdirectory when it exists there.
+///
+/// `handle_switch_output` calls this before its messages; `wt switch --cd-only`
+/// calls it alone, so the directive is the only output.
+pub fn change_directory_to_worktree(
+    target_root: &Path,
+    source_worktree_root: Option<&Path>,
+    cwd: &Path,
+) -> anyhow::Result<()> {
+    let cd_target = resolve_subdir_in_target(target_root, source_worktree_root, cwd);
+    super::change_directory(&cd_target)?;
+    Ok(())
+}
+
 /// Handle output for a switch operation
 ///
 /// # Shell Integration Warnings
@@ -354,8 +379,7 @@ pub fn handle_switch_output(
     // If the user is in apps/gateway/ in the source worktree and that directory exists
     // in the target, cd to apps/gateway/ in the target instead of the root.
     if change_dir {
-        let cd_target = resolve_subdir_in_target(result.path(), source_worktree_root, cwd);
-        super::change_directory(&cd_target)?;
+        change_directory_to_worktree(result.path(), source_worktree_root, cwd)?;
     }
 
     let path = result.path();
@@ -420,9 +444,9 @@ pub fn handle_switch_output(
                 // Show appropriate hint based on invocation mode
                 // (regular shell integration hint is shown by prompt_shell_integration in main.rs)
                 if is_git_subcommand {
-                    eprintln!("{}", hint_message(git_subcommand_warning()));
+                    print_hint(git_subcommand_warning());
                 } else if should_show_explicit_path_hint() {
-                    eprintln!("{}", hint_message(explicit_path_hint(branch)));
+                    print_hint(explicit_path_hint(branch));
                 }
             } else {
                 // Shell integration active or --no-cd — user switched (or chose not to cd)
@@ -450,20 +474,24 @@ pub fn handle_switch_output(
             created_branch,
             base_branch,
             from_remote,
+            detached,
             ..
         } => {
             // Always show success for creation
-            eprintln!(
-                "{}",
-                success_message(format_switch_message(
+            let message = if *detached {
+                // No branch to name — `branch` holds the commit-ish
+                cformat!("Created detached worktree at <bold>{branch}</> @ <bold>{path_display}</>")
+            } else {
+                format_switch_message(
                     branch,
                     path,
                     true, // worktree_created
                     *created_branch,
                     base_branch.as_deref(),
                     from_remote.as_deref(),
-                ))
-            );
+                )
+            };
+            eprintln!("{}", success_message(message));
 
             // Show worktree-path config hint on first --create in this repo,
             // unless user already has a custom worktree-path config
@@ -472,10 +500,9 @@ pub fn handle_switch_output(
                     .map(|c| c.has_custom_worktree_path())
                     .unwrap_or(false);
                 if !has_custom_config && !repo.has_shown_hint("worktree-path") {
-                    let hint = hint_message(cformat!(
+                    print_hint(cformat!(
                         "To customize worktree locations, run <bright-black>wt config create</>"
                     ));
-                    eprintln!("{}", hint);
                     let _ = repo.mark_hint_shown("worktree-path");
                 }
             }
@@ -491,9 +518,9 @@ pub fn handle_switch_output(
                 // Show appropriate hint based on invocation mode
                 // (regular shell integration hint is shown by prompt_shell_integration in main.rs)
                 if is_git_subcommand {
-                    eprintln!("{}", hint_message(git_subcommand_warning()));
+                    print_hint(git_subcommand_warning());
                 } else if should_show_explicit_path_hint() {
-                    eprintln!("{}", hint_message(explicit_path_hint(branch)));
+                    print_hint(explicit_path_hint(branch));
                 }
             }
             // Return path for hook annotations if user won't be in the worktree
@@ -511,24 +538,32 @@ pub fn handle_switch_output(
     Ok(display_path_for_hooks)
 }
 
-/// Execute the --execute command after hooks have run
+/// Execute the --execute and/or --open command after hooks have run
 ///
-/// `display_path` is shown when the user's shell won't be in the worktree directory
+/// `flags` names the options that produced the command (e.g. `--execute, --open`)
+/// for the header. `display_path` is shown when the user's shell won't be in the worktree directory
 /// (shell integration not active). This helps users understand where the command runs.
-pub fn execute_user_command(command: &str, display_path: Option<&Path>) -> anyhow::Result<()> {
+pub fn execute_user_command(
+    command: &str,
+    flags: &str,
+    display_path: Option<&Path>,
+    env: &[(&str, String)],
+) -> anyhow::Result<()> {
     // Show what command is being executed (section header + gutter content)
     // Include path when user's shell won't be there (shell integration not active)
     let header = match display_path {
         Some(path) => {
             let path_display = format_path_for_display(path);
-            cformat!("Executing (--execute) @ <bold>{path_display}</>:")
+            cformat!("Executing ({flags}) @ <bold>{path_display}</>:")
         }
-        None => "Executing (--execute):".to_string(),
+        None => format!("Executing ({flags}):"),
     };
-    eprintln!("{}", progress_message(header));
-    eprintln!("{}", format_bash_with_gutter(command));
+    if !is_quiet() {
+        eprintln!("{}", progress_message(header));
+        eprintln!("{}", format_bash_with_gutter(command));
+    }
 
-    super::execute(command)?;
+    super::execute(command, env)?;
 
     Ok(())
 }
@@ -859,24 +894,18 @@ impl RemovalDisplayInfo {
             let target = self.integration_target.as_deref().unwrap_or("target");
             let desc = reason.description();
             let symbol = reason.symbol();
-            eprintln!(
-                "{}",
-                hint_message(cformat!(
-                    "Branch integrated ({desc} <bold>{target}</>, <dim>{symbol}</>); retained with <bright-black>--no-delete-branch</>"
-                ))
-            );
+            print_hint(cformat!(
+                "Branch integrated ({desc} <bold>{target}</>, <dim>{symbol}</>); retained with <bright-black>--no-delete-branch</>"
+            ));
         } else if self.show_unmerged_hint
             || (!deletion_mode.should_keep() && !self.branch_was_integrated)
         {
             // Unmerged, no flag - show how to force delete
             // (Background: !should_keep && !integrated, Foreground: show_unmerged_hint)
             let cmd = suggest_command("remove", &[branch_name], &["-D"]);
-            eprintln!(
-                "{}",
-                hint_message(cformat!(
-                    "Branch unmerged; to delete, run <bright-black>{cmd}</>"
-                ))
-            );
+            print_hint(cformat!(
+                "Branch unmerged; to delete, run <bright-black>{cmd}</>"
+            ));
         }
         // else: Unmerged + flag - no hint (flag had no effect)
 
@@ -942,14 +971,13 @@ fn handle_removed_worktree_output(
 
     // Handle detached HEAD case (no branch known)
     let Some(branch_name) = branch_name else {
-        // No branch associated - just remove the worktree
+        // No branch associated - just remove the worktree. Name it by directory
+        // so the message still says which worktree was affected.
+        let worktree_name = path_dir_name(worktree_path);
         if background {
-            eprintln!(
-                "{}",
-                progress_message(
-                    "Removing worktree in background (detached HEAD, no branch to delete)",
-                )
-            );
+            print_progress(cformat!(
+                "Removing <bold>{worktree_name}</> worktree in background (detached HEAD, no branch to delete)"
+            ));
             let remove_command = build_remove_command(worktree_path, None, force_worktree);
             spawn_detached(
                 &repo,
@@ -958,19 +986,19 @@ fn handle_removed_worktree_output(
                 "detached",
                 &HookLog::internal(InternalOp::Remove),
                 None,
+                &[],
             )?;
         } else {
             // Progress message after pre-remove hooks, before actual removal
-            eprintln!(
-                "{}",
-                progress_message("Removing worktree (detached HEAD, no branch to delete)...",)
-            );
+            print_progress(cformat!(
+                "Removing <bold>{worktree_name}</> worktree (detached HEAD, no branch to delete)..."
+            ));
             let _ = repo
                 .worktree_at(worktree_path)
                 .run_command(&["fsmonitor--daemon", "stop"]);
             if let Err(err) = repo.remove_worktree(worktree_path, force_worktree) {
                 return Err(GitError::WorktreeRemovalFailed {
-                    branch: path_dir_name(worktree_path).to_string(),
+                    branch: worktree_name.to_string(),
                     path: worktree_path.to_path_buf(),
                     error: err.to_string(),
                 }
@@ -978,7 +1006,9 @@ fn handle_removed_worktree_output(
             }
             eprintln!(
                 "{}",
-                success_message("Removed worktree (detached HEAD, no branch to delete)",)
+                success_message(cformat!(
+                    "Removed <bold>{worktree_name}</> worktree (detached HEAD, no branch to delete)"
+                ))
             );
         }
         // Post-remove hooks for detached HEAD use "HEAD" as the branch identifier
@@ -1027,6 +1057,7 @@ fn handle_removed_worktree_output(
             branch_name,
             &HookLog::internal(InternalOp::Remove),
             None,
+            &[],
         )?;
 
         spawn_hooks_after_remove(
@@ -1043,10 +1074,7 @@ fn handle_removed_worktree_output(
         // Foreground mode: remove immediately and report actual results
 
         // Progress message after pre-remove hooks, before actual removal
-        eprintln!(
-            "{}",
-            progress_message(cformat!("Removing <bold>{branch_name}</> worktree..."))
-        );
+        print_progress(cformat!("Removing <bold>{branch_name}</> worktree..."));
 
         // Foreground mode: show warning after progress (contextual info during operation)
         if let Some(expected) = expected_path {
@@ -1103,6 +1131,9 @@ fn handle_removed_worktree_output(
 /// If `stdin_content` is provided, it will be piped to the command's stdin. This is used to pass
 /// hook context as JSON to hook commands.
 ///
+/// `env` is added to the child's environment (e.g. `WORKTRUNK_BRANCH`, see
+/// [`CommandContext::worktree_env`](crate::commands::command_executor::CommandContext::worktree_env)).
+///
 /// ## Color Bleeding Prevention
 ///
 /// This function explicitly resets ANSI codes on stderr before executing child commands.
@@ -1122,6 +1153,7 @@ pub fn execute_command_in_worktree(
     worktree_path: &std::path::Path,
     command: &str,
     stdin_content: Option<&str>,
+    env: &[(&str, String)],
 ) -> anyhow::Result<()> {
     // Flush stdout before executing command to ensure all our messages appear
     // before the child process output
@@ -1139,6 +1171,10 @@ pub fn execute_command_in_worktree(
         .stdout(Stdio::from(std::io::stderr()))
         .forward_signals();
 
+    for (key, value) in env {
+        cmd = cmd.env(*key, value);
+    }
+
     if let Some(content) = stdin_content {
         cmd = cmd.stdin_bytes(content);
     }
@@ -1171,6 +1207,19 @@ mod tests {
         assert!(msg.contains("from"));
         assert!(msg.contains("main"));
 
+        // Created branch with --track: names the upstream it tracks
+        let msg = format_switch_message(
+            "feature",
+            &path,
+            true,
+            true,
+            Some("main"),
+            Some("origin/feature"),
+        );
+        assert!(msg.contains("from"));
+        assert!(msg.contains("tracking"));
+        assert!(msg.contains("origin/feature"));
+
         // Created worktree from remote (DWIM) - also creates local tracking branch
         let msg =
             format_switch_message("feature", &path, true, false, None, Some("origin/feature"));
@@ -13,7 +13,7 @@
 //!
 //! eprintln!("{}", success_message("Operation complete"));
 //! output::change_directory(&path);
-//! output::execute("git pull");
+//! output::execute("git pull", &[]);
 //! ```
 //!
 //! ## Shell Integration
@@ -42,7 +42,8 @@ pub(crate) use global::{
 };
 // Re-export output handlers
 pub(crate) use handlers::{
-    execute_command_in_worktree, execute_user_command, handle_remove_output, handle_switch_output,
+    change_directory_to_worktree, execute_command_in_worktree, execute_user_command,
+    handle_remove_output, handle_switch_output,
 };
 // Re-export shell integration functions
 pub(crate) use shell_integration::{
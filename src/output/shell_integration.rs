@@ -42,7 +42,7 @@
 //! | Git subcommand | Return early (warning already shown) |
 //! | Unsupported shell | Hint: `Shell integration not yet supported for <shell>` |
 //! | $SHELL not set | Hint: `To enable automatic cd, run wt config shell install` |
-//! | Current shell already installed | Hint: `Restart shell to activate shell integration` |
+//! | Current shell already installed | Hint: `Restart shell to activate shell integration` (unless `show-integration-hint = false`) |
 //! | `skip-shell-integration-prompt` / Non-TTY | Hint: `To enable automatic cd, run wt config shell install` |
 //! | TTY | Prompt: `Install shell integration? [y/N/?]` |
 //!
@@ -65,7 +65,7 @@ use worktrunk::config::UserConfig;
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell::{Shell, current_shell, extract_filename_from_path};
 use worktrunk::styling::{
-    eprintln, format_bash_with_gutter, hint_message, info_message, success_message, warning_message,
+    eprintln, format_bash_with_gutter, info_message, print_hint, success_message, warning_message,
 };
 
 use crate::commands::configure_shell::{
@@ -189,12 +189,9 @@ pub fn print_skipped_shells(
 ) -> anyhow::Result<()> {
     for (shell, path) in skipped {
         let path = format_path_for_display(path);
-        eprintln!(
-            "{}",
-            hint_message(cformat!(
-                "Skipped <bright-black>{shell}</>; <bright-black>{path}</> not found"
-            ))
-        );
+        print_hint(cformat!(
+            "Skipped <bright-black>{shell}</>; <bright-black>{path}</> not found"
+        ));
     }
     Ok(())
 }
@@ -346,7 +343,7 @@ pub fn print_shell_install_result(
         });
 
         if current_shell_result.is_some() {
-            eprintln!("{}", hint_message(shell_restart_hint()));
+            print_hint(shell_restart_hint());
         }
     }
 
@@ -383,7 +380,7 @@ pub fn prompt_shell_integration(
             // Point them to manual installation
             None => shell_integration_hint(),
         };
-        eprintln!("{}", hint_message(msg));
+        print_hint(msg);
         return Ok(false);
     };
 
@@ -394,7 +391,7 @@ pub fn prompt_shell_integration(
 
     // No config files exist - show install hint
     if scan.configured.is_empty() {
-        eprintln!("{}", hint_message(shell_integration_hint()));
+        print_hint(shell_integration_hint());
         return Ok(false);
     }
 
@@ -407,9 +404,9 @@ pub fn prompt_shell_integration(
 
     if current_shell_installed {
         // Shell integration is configured but not active for this invocation
-        if !crate::was_invoked_with_explicit_path() {
+        if !crate::was_invoked_with_explicit_path() && config.show_integration_hint() {
             // Invoked via PATH but wrapper isn't active - needs shell restart
-            eprintln!("{}", hint_message(shell_restart_hint()));
+            print_hint(shell_restart_hint());
         }
         // For explicit paths: no hint needed - handle_switch_output() warning already explains
         return Ok(false);
@@ -417,7 +414,7 @@ pub fn prompt_shell_integration(
 
     // Can't or shouldn't prompt - show install hint
     if config.skip_shell_integration_prompt || !is_tty || skip_prompt {
-        eprintln!("{}", hint_message(shell_integration_hint()));
+        print_hint(shell_integration_hint());
         return Ok(false);
     }
 
@@ -434,7 +431,7 @@ pub fn prompt_shell_integration(
     if !confirmed {
         // Only skip future prompts after explicit decline (not Ctrl+C)
         let _ = config.set_skip_shell_integration_prompt(None);
-        eprintln!("{}", hint_message(shell_integration_hint()));
+        print_hint(shell_integration_hint());
         return Ok(false);
     }
 
@@ -88,6 +88,51 @@ fn find_cygpath_from_shell(shell: &crate::shell_exec::ShellConfig) -> Option<Pat
 /// - Windows: `USERPROFILE` or `HOMEDRIVE`/`HOMEPATH`
 pub use home::home_dir;
 
+/// Expand a leading `~` or `~user` and environment variables (`$HOME`, `${VAR}`) in a path.
+///
+/// Like a shell, only a tilde at the start expands; `a/~b` stays literal. `~user`
+/// is resolved from the user database on Unix and left as-is elsewhere.
+///
+/// # Examples
+/// - `~/worktrees/x` → `/Users/alex/worktrees/x`
+/// - `$HOME/worktrees/x` → `/Users/alex/worktrees/x`
+/// - `/tmp/a~b` → `/tmp/a~b` (unchanged)
+pub fn expand_home_and_env(path: &str) -> Result<String, String> {
+    let path = expand_leading_tilde(path);
+    shellexpand::env(&path)
+        .map(Cow::into_owned)
+        .map_err(|e| format!("environment variable `{}` is not set", e.var_name))
+}
+
+fn expand_leading_tilde(path: &str) -> Cow<'_, str> {
+    let Some(rest) = path.strip_prefix('~') else {
+        return Cow::Borrowed(path);
+    };
+    let (user, tail) = rest.split_at(rest.find(['/', '\\']).unwrap_or(rest.len()));
+    let home = if user.is_empty() {
+        home_dir()
+    } else {
+        user_home_dir(user)
+    };
+    match home {
+        Some(home) => Cow::Owned(format!("{}{tail}", home.display())),
+        None => Cow::Borrowed(path),
+    }
+}
+
+#[cfg(unix)]
+fn user_home_dir(user: &str) -> Option<std::path::PathBuf> {
+    nix::unistd::User::from_name(user)
+        .ok()
+        .flatten()
+        .map(|u| u.dir)
+}
+
+#[cfg(not(unix))]
+fn user_home_dir(_user: &str) -> Option<std::path::PathBuf> {
+    None
+}
+
 /// Check if a string needs shell escaping (contains characters outside the safe set).
 fn needs_shell_escaping(s: &str) -> bool {
     !matches!(escape(Cow::Borrowed(s)), Cow::Borrowed(_))
@@ -134,6 +179,35 @@ pub fn format_path_for_display(path: &Path) -> String {
     }
 }
 
+/// Whether this platform's default filesystems ignore case (APFS on macOS, NTFS on Windows).
+const CASE_INSENSITIVE_FS: bool = cfg!(any(target_os = "macos", windows));
+
+/// Whether two paths name the same directory, e.g. a worktree and the current one.
+///
+/// Both paths are canonicalized first (falling back to the path as given), which
+/// resolves symlinks such as macOS `/var` → `/private/var`. Canonicalization keeps
+/// the case a path was spelled with, so on macOS and Windows `/Users/me` and
+/// `/users/me` are also treated as equal. Separators and trailing slashes are
+/// normalized on every platform.
+pub fn same_path(a: &Path, b: &Path) -> bool {
+    let canonical = |path: &Path| dunce::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    path_spellings_match(&canonical(a), &canonical(b), CASE_INSENSITIVE_FS)
+}
+
+/// Compare two already-canonical paths by spelling; see [`same_path`].
+fn path_spellings_match(a: &Path, b: &Path, case_insensitive: bool) -> bool {
+    let normalize = |path: &Path| {
+        let slashed = path.to_slash_lossy();
+        let trimmed = slashed.trim_end_matches('/');
+        if case_insensitive {
+            trimmed.to_lowercase()
+        } else {
+            trimmed.to_string()
+        }
+    };
+    normalize(a) == normalize(b)
+}
+
 /// Sanitize a string for use as a filename on all platforms.
 ///
 /// Uses `sanitize-filename` crate to handle invalid characters, control characters,
@@ -167,9 +241,77 @@ pub fn sanitize_for_filename(value: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
+
+    use super::{
+        expand_home_and_env, format_path_for_display, home_dir, path_spellings_match,
+        sanitize_for_filename, to_posix_path,
+    };
 
-    use super::{format_path_for_display, home_dir, sanitize_for_filename, to_posix_path};
+    #[test]
+    fn path_spellings_match_folds_case_when_insensitive() {
+        let upper = Path::new("/Users/Me/repo");
+        let lower = Path::new("/users/me/repo/");
+        assert!(path_spellings_match(upper, lower, true));
+        assert!(!path_spellings_match(upper, lower, false));
+        assert!(path_spellings_match(
+            upper,
+            Path::new("/Users/Me/repo/"),
+            false
+        ));
+        assert!(!path_spellings_match(
+            upper,
+            Path::new("/Users/Me/repo.feature"),
+            true
+        ));
+    }
+
+    #[test]
+    fn expands_leading_tilde() {
+        let Some(home) = home_dir() else {
+            return;
+        };
+        assert_eq!(
+            expand_home_and_env("~/worktrees/x").unwrap(),
+            format!("{}/worktrees/x", home.display())
+        );
+        assert_eq!(
+            expand_home_and_env("~").unwrap(),
+            home.display().to_string()
+        );
+    }
+
+    #[test]
+    fn expands_env_vars() {
+        let Ok(home) = std::env::var("HOME") else {
+            return;
+        };
+        assert_eq!(
+            expand_home_and_env("$HOME/worktrees").unwrap(),
+            format!("{home}/worktrees")
+        );
+        assert_eq!(
+            expand_home_and_env("${HOME}/worktrees").unwrap(),
+            format!("{home}/worktrees")
+        );
+        let err = expand_home_and_env("$WORKTRUNK_SURELY_UNSET_VAR/x").unwrap_err();
+        assert!(err.contains("WORKTRUNK_SURELY_UNSET_VAR"), "{err}");
+    }
+
+    #[test]
+    fn leaves_mid_path_tilde_literal() {
+        assert_eq!(expand_home_and_env("/tmp/a~b").unwrap(), "/tmp/a~b");
+        assert_eq!(expand_home_and_env("/tmp/~/x").unwrap(), "/tmp/~/x");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn leaves_unknown_user_tilde_literal() {
+        assert_eq!(
+            expand_home_and_env("~no-such-user-wt/x").unwrap(),
+            "~no-such-user-wt/x"
+        );
+    }
 
     #[test]
     fn shortens_path_under_home() {
@@ -9,7 +9,7 @@ use std::fs;
 use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 
-use super::paths::{home_dir_required, powershell_profile_paths};
+use super::paths::{clink_script_path, home_dir_required, powershell_profile_paths};
 
 /// Detect if a line contains shell integration for a specific command.
 ///
@@ -99,8 +99,8 @@ pub fn is_shell_integration_line_for_uninstall(line: &str, cmd: &str) -> bool {
 fn is_shell_integration_line_impl(line: &str, cmd: &str, strict: bool) -> bool {
     let trimmed = line.trim();
 
-    // Skip comments (# for POSIX shells, <# #> for PowerShell block comments)
-    if trimmed.starts_with('#') || trimmed.starts_with("<#") {
+    // Skip comments (# for POSIX shells, <# #> for PowerShell block comments, -- for Lua)
+    if trimmed.starts_with('#') || trimmed.starts_with("<#") || trimmed.starts_with("--") {
         return false;
     }
 
@@ -191,6 +191,11 @@ fn has_init_pattern_with_prefix_check(line: &str, cmd: &str, strict: bool) -> bo
                 if is_posix_shell {
                     return true;
                 }
+
+                // Clink (cmd.exe) Lua script loading the init output
+                if line.contains("io.popen") {
+                    return true;
+                }
             }
 
             // Continue searching after this match
@@ -234,6 +239,47 @@ fn is_valid_command_position(line: &str, pos: usize, cmd: &str) -> bool {
     matches!(last_char, ' ' | '\t' | '$' | '(' | '"' | '\'' | '`' | '/')
 }
 
+/// Detect which command prefix a shell integration line is for.
+///
+/// Returns the command name the line initializes (e.g., `wt` or `git-wt`), regardless
+/// of which binary is currently running. `git wt` is normalized to `git-wt` and a
+/// Windows `.exe` suffix is stripped, matching the function name the line creates.
+///
+/// Used to surface integration installed for a different prefix, which otherwise
+/// looks like "integration not configured" from the running binary's perspective.
+pub fn detect_integration_prefix(line: &str) -> Option<String> {
+    let trimmed = line.trim();
+    if trimmed.starts_with('#') || trimmed.starts_with("<#") || trimmed.starts_with("--") {
+        return None;
+    }
+
+    let mut search_start = 0;
+    while let Some(pos) = trimmed[search_start..].find(" config shell init") {
+        let end = search_start + pos;
+        let before = &trimmed[..end];
+        let token_start = before
+            .rfind(|c: char| matches!(c, ' ' | '\t' | '$' | '(' | '"' | '\'' | '`' | '/'))
+            .map_or(0, |i| i + 1);
+        let token = &before[token_start..];
+        let token = token.strip_suffix(".exe").unwrap_or(token);
+
+        // `git wt` dispatches to the `git-wt` binary
+        let prefix = if before[..token_start].ends_with("git ") {
+            format!("git-{token}")
+        } else {
+            token.to_string()
+        };
+
+        if !token.is_empty() && is_shell_integration_line(trimmed, &prefix) {
+            return Some(prefix);
+        }
+
+        search_start = end + 1;
+    }
+
+    None
+}
+
 /// Check if a line contains the command name at a word boundary.
 ///
 /// Used to identify potential false negatives - lines that contain the command
@@ -274,6 +320,9 @@ fn contains_cmd_at_word_boundary(line: &str, cmd: &str) -> bool {
 pub struct DetectedLine {
     pub line_number: usize,
     pub content: String,
+    /// Command prefix the line initializes integration for (e.g., `wt`, `git-wt`).
+    /// `None` for lines that aren't recognized as integration.
+    pub prefix: Option<String>,
 }
 
 /// Result of scanning a shell config file for integration detection.
@@ -286,6 +335,9 @@ pub struct FileDetectionResult {
     /// Lines containing the command at word boundary but NOT detected.
     /// These are potential false negatives.
     pub unmatched_candidates: Vec<DetectedLine>,
+    /// Integration lines for a different command prefix than the one scanned for.
+    /// e.g., `git wt config shell init` lines when running as `wt`.
+    pub other_prefix_lines: Vec<DetectedLine>,
     /// Aliases that bypass shell integration by pointing to a binary path.
     /// e.g., `alias gwt="/usr/bin/wt"` or `alias wt="wt.exe"`
     pub bypass_aliases: Vec<BypassAlias>,
@@ -310,6 +362,7 @@ fn scan_file(path: &std::path::Path, cmd: &str) -> Option<FileDetectionResult> {
     let reader = BufReader::new(file);
     let mut matched_lines = Vec::new();
     let mut unmatched_candidates = Vec::new();
+    let mut other_prefix_lines = Vec::new();
     let mut bypass_aliases = Vec::new();
 
     for (line_number, line) in reader.lines().map_while(Result::ok).enumerate() {
@@ -324,11 +377,19 @@ fn scan_file(path: &std::path::Path, cmd: &str) -> Option<FileDetectionResult> {
             matched_lines.push(DetectedLine {
                 line_number,
                 content: line.clone(),
+                prefix: Some(cmd.to_string()),
+            });
+        } else if let Some(prefix) = detect_integration_prefix(&line) {
+            other_prefix_lines.push(DetectedLine {
+                line_number,
+                content: line.clone(),
+                prefix: Some(prefix),
             });
         } else if contains_cmd_at_word_boundary(&line, cmd) {
             unmatched_candidates.push(DetectedLine {
                 line_number,
                 content: line.clone(),
+                prefix: None,
             });
         }
 
@@ -342,7 +403,11 @@ fn scan_file(path: &std::path::Path, cmd: &str) -> Option<FileDetectionResult> {
     }
 
     // Only return if we found something interesting
-    if matched_lines.is_empty() && unmatched_candidates.is_empty() && bypass_aliases.is_empty() {
+    if matched_lines.is_empty()
+        && unmatched_candidates.is_empty()
+        && other_prefix_lines.is_empty()
+        && bypass_aliases.is_empty()
+    {
         return None;
     }
 
@@ -350,6 +415,7 @@ fn scan_file(path: &std::path::Path, cmd: &str) -> Option<FileDetectionResult> {
         path: path.to_path_buf(),
         matched_lines,
         unmatched_candidates,
+        other_prefix_lines,
         bypass_aliases,
     })
 }
@@ -432,6 +498,7 @@ fn detect_bypass_alias(line: &str, cmd: &str, line_number: usize) -> Option<Bypa
 /// Returns information about:
 /// - Which lines matched as shell integration
 /// - Which lines contain the command but didn't match (potential false negatives)
+/// - Which lines set up integration for a different command prefix
 ///
 /// Used by `wt config show` to provide debugging output.
 pub fn scan_for_detection_details(cmd: &str) -> Result<Vec<FileDetectionResult>, std::io::Error> {
@@ -461,6 +528,9 @@ pub fn scan_for_detection_details(cmd: &str) -> Result<Vec<FileDetectionResult>,
     // Add PowerShell profiles
     config_files.extend(powershell_profile_paths(&home));
 
+    // Clink script (cmd.exe)
+    config_files.push(clink_script_path(&home, cmd));
+
     // Deduplicate and scan
     let mut seen = HashSet::new();
     for path in config_files {
@@ -701,6 +771,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_clink_lua_popen() {
+        assert_detects(
+            r#"local f = io.popen("wt config shell init cmd 2>nul")"#,
+            "wt",
+            "clink Lua io.popen",
+        );
+        // Lua comments don't count
+        assert_not_detects(
+            r#"-- local f = io.popen("wt config shell init cmd 2>nul")"#,
+            "wt",
+            "Lua comment should not match",
+        );
+    }
+
     // ------------------------------------------------------------------------
     // FALSE NEGATIVE: PowerShell iex alias
     // ------------------------------------------------------------------------
@@ -1069,4 +1154,22 @@ mod tests {
         let result = detect_bypass_alias(r#"alias vim="nvim""#, "wt", 1);
         assert!(result.is_none());
     }
+
+    // ==========================================================================
+    // PREFIX DETECTION TESTS
+    // ==========================================================================
+
+    /// Integration lines report the command prefix they set up
+    #[rstest]
+    #[case::wt(r#"eval "$(wt config shell init bash)""#, Some("wt"))]
+    #[case::git_space_wt(r#"eval "$(command git wt config shell init bash)""#, Some("git-wt"))]
+    #[case::git_hyphen_wt(r#"source <(git-wt config shell init zsh)"#, Some("git-wt"))]
+    #[case::exe(r#"eval "$(git-wt.exe config shell init bash)""#, Some("git-wt"))]
+    #[case::custom(r#"eval "$(worktrunk config shell init bash)""#, Some("worktrunk"))]
+    #[case::fish("wt config shell init fish | source", Some("wt"))]
+    #[case::comment(r#"# eval "$(wt config shell init bash)""#, None)]
+    #[case::no_execution_context("wt config shell init bash", None)]
+    fn test_detect_integration_prefix(#[case] line: &str, #[case] expected: Option<&str>) {
+        assert_eq!(detect_integration_prefix(line).as_deref(), expected);
+    }
 }
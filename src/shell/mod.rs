@@ -3,7 +3,7 @@
 //! This module provides:
 //! - Shell detection and configuration path discovery
 //! - Shell integration line detection for config files
-//! - Shell initialization code generation (bash, zsh, fish, powershell)
+//! - Shell initialization code generation (bash, zsh, fish, powershell, cmd)
 
 mod detection;
 mod paths;
@@ -13,20 +13,23 @@ use std::io::{BufRead, BufReader};
 
 use askama::Template;
 
+use crate::shell_exec::DIRECTIVE_PROTOCOL_VERSION;
+
 // Re-export public types and functions
 pub use detection::{
-    BypassAlias, DetectedLine, FileDetectionResult, is_shell_integration_line,
-    is_shell_integration_line_for_uninstall, scan_for_detection_details,
+    BypassAlias, DetectedLine, FileDetectionResult, detect_integration_prefix,
+    is_shell_integration_line, is_shell_integration_line_for_uninstall, scan_for_detection_details,
 };
 pub use paths::{completion_path, config_paths, legacy_fish_conf_d_path};
 pub use utils::{current_shell, detect_zsh_compinit, extract_filename_from_path};
 
 /// Supported shells
 ///
-/// Currently supported: bash, fish, zsh, powershell
+/// Currently supported: bash, fish, zsh, powershell, cmd
 ///
 /// On Windows, Git Bash users should use `bash` for shell integration.
-/// PowerShell integration is available for native Windows users without Git Bash.
+/// PowerShell integration is available for native Windows users without Git Bash,
+/// and cmd integration (via clink) for users who stay in cmd.exe.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum, strum::Display, strum::EnumString)]
 #[strum(serialize_all = "kebab-case", ascii_case_insensitive)]
 pub enum Shell {
@@ -36,6 +39,7 @@ pub enum Shell {
     #[strum(serialize = "powershell")]
     #[clap(name = "powershell")]
     PowerShell,
+    Cmd,
 }
 
 impl Shell {
@@ -98,6 +102,13 @@ impl Shell {
                     "if (Get-Command {cmd} -ErrorAction SilentlyContinue) {{ Invoke-Expression (& {cmd} config shell init powershell | Out-String) }}",
                 )
             }
+            Self::Cmd => {
+                // A clink Lua script: run the init command and load its output as Lua.
+                // With the command missing, the output is empty and nothing loads.
+                format!(
+                    "local f = io.popen(\"{cmd} config shell init cmd 2>nul\"); if f then local init = load(f:read(\"*a\")); f:close(); if init then init() end end",
+                )
+            }
         }
     }
 
@@ -157,19 +168,36 @@ impl ShellInit {
                 let template = BashTemplate {
                     shell_name: self.shell.to_string(),
                     cmd: &self.cmd,
+                    protocol_version: DIRECTIVE_PROTOCOL_VERSION,
                 };
                 template.render()
             }
             Shell::Zsh => {
-                let template = ZshTemplate { cmd: &self.cmd };
+                let template = ZshTemplate {
+                    cmd: &self.cmd,
+                    protocol_version: DIRECTIVE_PROTOCOL_VERSION,
+                };
                 template.render()
             }
             Shell::Fish => {
-                let template = FishTemplate { cmd: &self.cmd };
+                let template = FishTemplate {
+                    cmd: &self.cmd,
+                    protocol_version: DIRECTIVE_PROTOCOL_VERSION,
+                };
                 template.render()
             }
             Shell::PowerShell => {
-                let template = PowerShellTemplate { cmd: &self.cmd };
+                let template = PowerShellTemplate {
+                    cmd: &self.cmd,
+                    protocol_version: DIRECTIVE_PROTOCOL_VERSION,
+                };
+                template.render()
+            }
+            Shell::Cmd => {
+                let template = CmdTemplate {
+                    cmd: &self.cmd,
+                    protocol_version: DIRECTIVE_PROTOCOL_VERSION,
+                };
                 template.render()
             }
         }
@@ -192,6 +220,7 @@ impl ShellInit {
 struct BashTemplate<'a> {
     shell_name: String,
     cmd: &'a str,
+    protocol_version: u32,
 }
 
 /// Zsh shell template
@@ -199,6 +228,7 @@ struct BashTemplate<'a> {
 #[template(path = "zsh.zsh", escape = "none")]
 struct ZshTemplate<'a> {
     cmd: &'a str,
+    protocol_version: u32,
 }
 
 /// Fish shell template (full function for `wt config shell init fish`)
@@ -206,6 +236,7 @@ struct ZshTemplate<'a> {
 #[template(path = "fish.fish", escape = "none")]
 struct FishTemplate<'a> {
     cmd: &'a str,
+    protocol_version: u32,
 }
 
 /// Fish wrapper template (minimal wrapper for `functions/wt.fish`)
@@ -224,6 +255,15 @@ struct FishWrapperTemplate<'a> {
 #[template(path = "powershell.ps1", escape = "none")]
 struct PowerShellTemplate<'a> {
     cmd: &'a str,
+    protocol_version: u32,
+}
+
+/// cmd.exe template (clink Lua script)
+#[derive(Template)]
+#[template(path = "cmd.lua", escape = "none")]
+struct CmdTemplate<'a> {
+    cmd: &'a str,
+    protocol_version: u32,
 }
 
 #[cfg(test)]
@@ -245,6 +285,7 @@ mod tests {
             "POWERSHELL".parse::<Shell>(),
             Ok(Shell::PowerShell)
         ));
+        assert!(matches!("cmd".parse::<Shell>(), Ok(Shell::Cmd)));
         assert!("invalid".parse::<Shell>().is_err());
     }
 
@@ -254,6 +295,7 @@ mod tests {
         assert_eq!(Shell::Fish.to_string(), "fish");
         assert_eq!(Shell::Zsh.to_string(), "zsh");
         assert_eq!(Shell::PowerShell.to_string(), "powershell");
+        assert_eq!(Shell::Cmd.to_string(), "cmd");
     }
 
     #[test]
@@ -265,6 +307,7 @@ mod tests {
             "config_line_powershell",
             Shell::PowerShell.config_line("wt")
         );
+        insta::assert_snapshot!("config_line_cmd", Shell::Cmd.config_line("wt"));
     }
 
     #[test]
@@ -279,11 +322,18 @@ mod tests {
             "config_line_powershell_custom",
             Shell::PowerShell.config_line("git-wt")
         );
+        insta::assert_snapshot!("config_line_cmd_custom", Shell::Cmd.config_line("git-wt"));
     }
 
     #[test]
     fn test_shell_init_generate() {
-        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+        for shell in [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::PowerShell,
+            Shell::Cmd,
+        ] {
             let init = ShellInit::with_prefix(shell, "wt".to_string());
             let output = init.generate().expect("Failed to generate");
             insta::assert_snapshot!(format!("init_{shell}"), output);
@@ -293,7 +343,13 @@ mod tests {
     #[test]
     fn test_shell_config_paths_returns_paths() {
         // All shells should return at least one config path
-        let shells = [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell];
+        let shells = [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::PowerShell,
+            Shell::Cmd,
+        ];
         for shell in shells {
             let result = shell.config_paths("wt");
             assert!(result.is_ok(), "Failed to get config paths for {:?}", shell);
@@ -309,7 +365,13 @@ mod tests {
     #[test]
     fn test_shell_completion_path_returns_path() {
         // All shells should return a completion path
-        let shells = [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell];
+        let shells = [
+            Shell::Bash,
+            Shell::Zsh,
+            Shell::Fish,
+            Shell::PowerShell,
+            Shell::Cmd,
+        ];
         for shell in shells {
             let result = shell.completion_path("wt");
             assert!(
@@ -410,7 +472,7 @@ mod tests {
     /// the .exe suffix on Windows (MSYS2/Git Bash handles the resolution).
     #[rstest]
     fn test_config_line_detected_by_is_shell_integration_line(
-        #[values(Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell)] shell: Shell,
+        #[values(Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell, Shell::Cmd)] shell: Shell,
         #[values("wt", "git-wt")] prefix: &str,
     ) {
         let line = shell.config_line(prefix);
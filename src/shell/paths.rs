@@ -45,9 +45,23 @@ pub fn powershell_profile_paths(home: &std::path::Path) -> Vec<PathBuf> {
     }
 }
 
+/// Get the clink script that holds cmd.exe integration.
+/// Clink loads every `.lua` file in its profile directory at startup; on Windows that's
+/// `%LOCALAPPDATA%\clink`. On Unix, where clink doesn't run, uses ~/.local/share/clink.
+pub fn clink_script_path(home: &std::path::Path, cmd: &str) -> PathBuf {
+    #[cfg(windows)]
+    let profile = dirs::data_local_dir()
+        .unwrap_or_else(|| home.join("AppData").join("Local"))
+        .join("clink");
+    #[cfg(not(windows))]
+    let profile = home.join(".local").join("share").join("clink");
+    profile.join(format!("{cmd}.lua"))
+}
+
 /// Returns the config file paths for a shell.
 ///
-/// The `cmd` parameter affects the Fish functions filename (e.g., `wt.fish` or `git-wt.fish`).
+/// The `cmd` parameter affects the Fish functions filename (e.g., `wt.fish` or `git-wt.fish`)
+/// and the clink script filename (e.g., `wt.lua`).
 /// Returns paths in order of preference. The first existing file should be used.
 pub fn config_paths(shell: super::Shell, cmd: &str) -> Result<Vec<PathBuf>, std::io::Error> {
     let home = home_dir_required()?;
@@ -76,6 +90,7 @@ pub fn config_paths(shell: super::Shell, cmd: &str) -> Result<Vec<PathBuf>, std:
             ]
         }
         super::Shell::PowerShell => powershell_profile_paths(&home),
+        super::Shell::Cmd => vec![clink_script_path(&home, cmd)],
     })
 }
 
@@ -138,5 +153,9 @@ pub fn completion_path(shell: super::Shell, cmd: &str) -> Result<PathBuf, std::i
             // Return a dummy path that won't be used
             home.join(format!(".{}-powershell-completions", cmd))
         }
+        super::Shell::Cmd => {
+            // cmd integration has no completions; like PowerShell, return a dummy path
+            home.join(format!(".{}-cmd-completions", cmd))
+        }
     })
 }
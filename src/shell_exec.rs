@@ -180,6 +180,13 @@ fn find_git_bash() -> Option<PathBuf> {
 /// Hooks and other child processes should not be able to write to the directive file.
 pub const DIRECTIVE_FILE_ENV_VAR: &str = "WORKTRUNK_DIRECTIVE_FILE";
 
+/// Version of the directive file format shared by the binary and the shell wrappers.
+///
+/// The binary writes `# worktrunk-protocol <N>` as the first line of a non-empty
+/// directive file; the wrappers (templated with the same number) warn when the
+/// versions differ. Bump when directives change in a way an older wrapper can't run.
+pub const DIRECTIVE_PROTOCOL_VERSION: u32 = 1;
+
 // ============================================================================
 // Thread-Local Command Timeout
 // ============================================================================
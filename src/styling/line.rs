@@ -34,9 +34,21 @@ fn truncate_visible_with_ellipsis(rendered: &str, max_width: usize, ellipsis: &s
         return out;
     }
 
+    let mut out = cut_visible(rendered, budget);
+    out.push_str(ellipsis);
+    out.push_str("\u{1b}[0m");
+    out
+}
+
+/// Keep the leading `budget` visible columns of `rendered`, preserving escapes.
+///
+/// Cuts on char boundaries; a wide char that would straddle the budget is
+/// dropped rather than split, so the result may be one column short.
+fn cut_visible(rendered: &str, budget: usize) -> String {
+    let plain = rendered.ansi_strip();
     let mut cut_at = 0;
     let mut width = 0;
-    for (i, ch) in plain_str.char_indices() {
+    for (i, ch) in plain.char_indices() {
         let w = UnicodeWidthChar::width(ch).unwrap_or(0);
         if width + w > budget {
             break;
@@ -44,11 +56,7 @@ fn truncate_visible_with_ellipsis(rendered: &str, max_width: usize, ellipsis: &s
         width += w;
         cut_at = i + ch.len_utf8();
     }
-
-    let mut out = rendered.ansi_cut(..cut_at).into_owned();
-    out.push_str(ellipsis);
-    out.push_str("\u{1b}[0m");
-    out
+    rendered.ansi_cut(..cut_at).into_owned()
 }
 
 /// A piece of text with an optional style
@@ -144,15 +152,38 @@ impl StyledLine {
     }
 
     /// Truncate if the line exceeds the given width, preserving ANSI codes.
-    /// Returns a new StyledLine with truncated content and ellipsis.
+    ///
+    /// Clips segment by segment so each kept segment retains its style; the
+    /// ellipsis ("…") takes the style of the segment it replaces. Segments with
+    /// embedded escapes get a trailing ESC[0m when cut so nothing bleeds.
     pub fn truncate_to_width(self, max_width: usize) -> StyledLine {
         if self.width() <= max_width {
             return self;
         }
-        let rendered = self.render();
-        let truncated = truncate_visible(&rendered, max_width);
         let mut new_line = StyledLine::new();
-        new_line.push_raw(truncated);
+        if max_width == 0 {
+            return new_line;
+        }
+
+        let budget = max_width - 1; // Room for the ellipsis
+        let mut used = 0;
+        for segment in self.segments {
+            let width = segment.width();
+            if used + width <= budget {
+                used += width;
+                new_line.push(segment);
+                continue;
+            }
+            let mut text = cut_visible(&segment.text, budget - used);
+            if text.contains('\u{1b}') {
+                text.push_str("\u{1b}[0m");
+            }
+            if !text.is_empty() {
+                new_line.push(StyledString::new(text, segment.style));
+            }
+            new_line.push(StyledString::new("…", segment.style));
+            break;
+        }
         new_line
     }
 }
@@ -388,6 +419,53 @@ mod tests {
         assert!(truncated.width() <= 10);
     }
 
+    #[test]
+    fn test_styled_line_truncate_to_width_keeps_segment_styles() {
+        let bold = Style::new().bold();
+        let mut line = StyledLine::new();
+        line.push_styled("feature", bold);
+        line.push_raw(" some/long/path");
+        let truncated = line.truncate_to_width(12);
+        assert_eq!(truncated.plain_text(), "feature som…");
+        assert_eq!(truncated.segments[0].style, Some(bold));
+        assert_eq!(truncated.segments[1].style, None);
+        // Every segment renders its own reset, so no style leaks past the line
+        assert!(truncated.render().ends_with('…'));
+    }
+
+    #[test]
+    fn test_styled_line_truncate_to_width_cjk() {
+        let mut line = StyledLine::new();
+        line.push_styled("機能ブランチ", Style::new().bold());
+        // Budget of 4 for text + 1 for "…": two 2-wide chars fit
+        let truncated = line.clone().truncate_to_width(5);
+        assert_eq!(truncated.plain_text(), "機能…");
+        assert_eq!(truncated.width(), 5);
+        // Odd budget: the next wide char doesn't fit, so the line comes up short
+        let truncated = line.truncate_to_width(4);
+        assert_eq!(truncated.plain_text(), "機…");
+        assert_eq!(truncated.width(), 3);
+    }
+
+    #[test]
+    fn test_styled_line_truncate_to_width_emoji_and_raw_ansi() {
+        let mut line = StyledLine::new();
+        line.push_raw("\u{1b}[32m🚀🚀🚀\u{1b}[39m");
+        let truncated = line.truncate_to_width(4);
+        assert_eq!(truncated.plain_text().ansi_strip(), "🚀…");
+        let rendered = truncated.render();
+        // Cut raw segment is reset before the ellipsis
+        assert!(rendered.contains("\u{1b}[0m…"), "{rendered:?}");
+        assert_eq!(visible_width(&rendered), 3);
+    }
+
+    #[test]
+    fn test_styled_line_truncate_to_width_zero() {
+        let mut line = StyledLine::new();
+        line.push_raw("hello");
+        assert!(line.truncate_to_width(0).segments.is_empty());
+    }
+
     #[test]
     fn test_styled_line_default() {
         let line = StyledLine::default();
@@ -19,6 +19,7 @@ mod highlighting;
 mod hyperlink;
 mod line;
 mod suggest;
+mod theme;
 
 use ansi_str::AnsiStr;
 use unicode_width::UnicodeWidthStr;
@@ -38,12 +39,13 @@ pub use highlighting::format_toml;
 pub use hyperlink::{Stream, hyperlink_stdout, strip_osc8_hyperlinks, supports_hyperlinks};
 pub use line::{StyledLine, StyledString, truncate_visible};
 pub use suggest::suggest_command;
+pub use theme::{Theme, parse_style, set_theme, theme};
 
 // ============================================================================
 // Verbosity
 // ============================================================================
 
-use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 
 /// Global verbosity level, set at startup.
 /// 0 = normal, 1 = verbose (-v), 2+ = debug (-vv)
@@ -65,6 +67,39 @@ pub fn verbosity() -> u8 {
     VERBOSITY.load(Ordering::Relaxed)
 }
 
+/// Whether `--quiet` was passed, set at startup.
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Set quiet mode: hints and progress messages are suppressed.
+///
+/// Call this once at startup after parsing CLI arguments. Success messages,
+/// warnings, and errors still print.
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether quiet mode is active (`--quiet`).
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Print a hint to stderr, unless quiet mode is active.
+///
+/// Use for transient guidance. Hints that are part of requested output (like
+/// `wt config show`) or of an error's `Display` should use [`hint_message`] directly.
+pub fn print_hint(content: impl AsRef<str>) {
+    if !is_quiet() {
+        eprintln!("{}", hint_message(content));
+    }
+}
+
+/// Print a progress message to stderr, unless quiet mode is active.
+pub fn print_progress(content: impl AsRef<str>) {
+    if !is_quiet() {
+        eprintln!("{}", progress_message(content));
+    }
+}
+
 /// Get terminal width, or `usize::MAX` if detection fails.
 ///
 /// Prefers direct terminal size detection over COLUMNS environment variable,
@@ -0,0 +1,156 @@
+//! Color theme for `wt list` table rendering.
+//!
+//! Semantic roles (additions, deletions, CI status, ...) map to [`Style`]s.
+//! The defaults are the built-in colors; the `[theme]` table in user config
+//! overrides individual roles:
+//!
+//! ```
+//! use worktrunk::styling::parse_style;
+//!
+//! let style = parse_style("bold #ff8800").unwrap();
+//! assert!(style.get_effects().contains(anstyle::Effects::BOLD));
+//! assert!(parse_style("grene").is_err());
+//! ```
+
+use std::sync::OnceLock;
+
+use anstyle::{AnsiColor, Color, Effects, RgbColor, Style};
+
+use super::{ADDITION, DELETION};
+
+/// Styles for each themable role in the list table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Added lines and commits ahead
+    pub addition: Style,
+    /// Deleted lines and commits behind
+    pub deletion: Style,
+    /// `@` gutter marker for the current worktree
+    pub current: Style,
+    /// `^` gutter marker for the primary worktree
+    pub primary: Style,
+    /// CI indicator when checks passed
+    pub ci_passed: Style,
+    /// CI indicator when checks failed
+    pub ci_failed: Style,
+    /// Secondary text (times, commits, messages, rows that can be removed)
+    pub dimmed: Style,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            addition: ADDITION,
+            deletion: DELETION,
+            current: Style::new(),
+            primary: Style::new(),
+            ci_passed: Style::new().fg_color(Some(Color::Ansi(AnsiColor::Green))),
+            ci_failed: Style::new().fg_color(Some(Color::Ansi(AnsiColor::Red))),
+            dimmed: Style::new().dimmed(),
+        }
+    }
+}
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Set the theme for this process.
+///
+/// Call once at startup, before any rendering; later calls are ignored.
+pub fn set_theme(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+/// The active theme (the default unless [`set_theme`] was called).
+pub fn theme() -> &'static Theme {
+    THEME.get_or_init(Theme::default)
+}
+
+/// Parse a style spec such as `green`, `bright-blue`, `#ff8800` or `bold red`.
+///
+/// Words are separated by whitespace. Each is a color (the first sets the
+/// foreground), an effect (`bold`, `dim`, `italic`, `underline`), or `none`
+/// for no styling.
+pub fn parse_style(spec: &str) -> Result<Style, String> {
+    let mut style = Style::new();
+    let mut has_color = false;
+    for word in spec.split_whitespace() {
+        let word = word.to_ascii_lowercase();
+        let effect = match word.as_str() {
+            "none" => Some(Effects::new()),
+            "bold" => Some(Effects::BOLD),
+            "dim" | "dimmed" => Some(Effects::DIMMED),
+            "italic" => Some(Effects::ITALIC),
+            "underline" => Some(Effects::UNDERLINE),
+            _ => None,
+        };
+        if let Some(effect) = effect {
+            style = style.effects(style.get_effects() | effect);
+            continue;
+        }
+        if has_color {
+            return Err(format!("more than one color in '{spec}'"));
+        }
+        style = style.fg_color(Some(parse_color(&word).ok_or_else(|| {
+            format!("unknown color '{word}' (expected a color name like 'green' or a hex value like '#00ff00')")
+        })?));
+        has_color = true;
+    }
+    Ok(style)
+}
+
+fn parse_color(word: &str) -> Option<Color> {
+    if let Some(hex) = word.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        return Some(Color::Rgb(RgbColor(channel(0)?, channel(2)?, channel(4)?)));
+    }
+    let ansi = match word {
+        "black" => AnsiColor::Black,
+        "red" => AnsiColor::Red,
+        "green" => AnsiColor::Green,
+        "yellow" => AnsiColor::Yellow,
+        "blue" => AnsiColor::Blue,
+        "magenta" => AnsiColor::Magenta,
+        "cyan" => AnsiColor::Cyan,
+        "white" => AnsiColor::White,
+        "bright-black" | "gray" | "grey" => AnsiColor::BrightBlack,
+        "bright-red" => AnsiColor::BrightRed,
+        "bright-green" => AnsiColor::BrightGreen,
+        "bright-yellow" => AnsiColor::BrightYellow,
+        "bright-blue" => AnsiColor::BrightBlue,
+        "bright-magenta" => AnsiColor::BrightMagenta,
+        "bright-cyan" => AnsiColor::BrightCyan,
+        "bright-white" => AnsiColor::BrightWhite,
+        _ => return None,
+    };
+    Some(Color::Ansi(ansi))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_style() {
+        assert_eq!(parse_style("green").unwrap(), ADDITION);
+        assert_eq!(parse_style("none").unwrap(), Style::new());
+        assert_eq!(parse_style("dim").unwrap(), Theme::default().dimmed);
+        assert_eq!(
+            parse_style("bold #FF8800").unwrap(),
+            Style::new()
+                .fg_color(Some(Color::Rgb(RgbColor(0xff, 0x88, 0x00))))
+                .bold()
+        );
+        assert_eq!(
+            parse_style("Bright-Blue underline").unwrap(),
+            Style::new()
+                .fg_color(Some(Color::Ansi(AnsiColor::BrightBlue)))
+                .underline()
+        );
+        assert!(parse_style("grene").is_err());
+        assert!(parse_style("#fff").is_err());
+        assert!(parse_style("red blue").is_err());
+    }
+}
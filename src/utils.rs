@@ -50,10 +50,71 @@ pub fn get_now() -> u64 {
         })
 }
 
+/// Levenshtein edit distance between two strings, counted in chars.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut curr = vec![0; b_chars.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b_chars.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b_chars.len()]
+}
+
+/// Find the candidate closest to `target`, for "did you mean" suggestions.
+///
+/// Only returns candidates within a third of the target's length (at least 2 edits),
+/// so unrelated names aren't suggested. Ties go to the earliest candidate.
+pub fn closest_match<'a>(
+    target: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max_distance = (target.chars().count() / 3).max(2);
+    candidates
+        .into_iter()
+        .filter(|c| *c != target)
+        .map(|c| (edit_distance(target, c), c))
+        .filter(|(d, _)| *d <= max_distance)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, c)| c)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("main", "main"), 0);
+        assert_eq!(edit_distance("mian", "main"), 2);
+        assert_eq!(edit_distance("feature", "featur"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_closest_match() {
+        let branches = ["main", "feature-auth", "feature-api"];
+        assert_eq!(
+            closest_match("feature-aut", branches.iter().copied()),
+            Some("feature-auth")
+        );
+        assert_eq!(
+            closest_match("mian", branches.iter().copied()),
+            Some("main")
+        );
+        assert_eq!(closest_match("man", branches.iter().copied()), Some("main"));
+        assert_eq!(closest_match("unrelated", branches.iter().copied()), None);
+    }
+
     #[test]
     fn test_get_now_returns_reasonable_timestamp() {
         let now = get_now();
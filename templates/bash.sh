@@ -33,6 +33,13 @@ if command -v {{ cmd }} >/dev/null 2>&1 || [[ -n "${WORKTRUNK_BIN:-}" ]]; then
         fi
 
         if [[ -s "$directive_file" ]]; then
+            # The binary writes "# worktrunk-protocol N" first. A different N means
+            # this function came from another {{ cmd }} version (e.g. upgraded mid-session).
+            local protocol_line
+            IFS= read -r protocol_line < "$directive_file"
+            if [[ "$protocol_line" == "# worktrunk-protocol "* && "$protocol_line" != "# worktrunk-protocol {{ protocol_version }}" ]]; then
+                echo "{{ cmd }}: shell integration speaks directive protocol {{ protocol_version }} but {{ cmd }} wrote ${protocol_line##* }; restart your shell to reload it" >&2
+            fi
             source "$directive_file"
             if [[ $exit_code -eq 0 ]]; then
                 exit_code=$?
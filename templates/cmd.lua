@@ -0,0 +1,44 @@
+-- worktrunk shell integration for cmd (via clink)
+--
+-- Clink loads this script at startup. It writes a small batch wrapper and points a
+-- `{{ cmd }}` doskey alias at it. The wrapper passes a directive file to {{ cmd }} via
+-- WORKTRUNK_DIRECTIVE_FILE and `call`s it afterwards, so `cd` and `--execute` take
+-- effect in the cmd session.
+--
+-- Limitations compared to bash/zsh/fish:
+-- - No tab completions
+-- - `--execute` commands use cmd syntax and run as batch lines (`%` must be doubled)
+-- - Hooks using bash syntax won't work without Git Bash
+
+local temp = os.getenv("TEMP")
+if temp then
+    local wrapper = temp .. "\\worktrunk-{{ cmd }}.cmd"
+    local file = io.open(wrapper, "w")
+    if file then
+        -- WORKTRUNK_BIN can override the binary path (for testing dev builds).
+        -- The binary writes "@rem worktrunk-protocol N" first. A different N means
+        -- this script came from another {{ cmd }} version (e.g. upgraded mid-session).
+        file:write([[
+@echo off
+set "_WT_DIRECTIVE=%TEMP%\worktrunk-%RANDOM%%RANDOM%.cmd"
+type nul > "%_WT_DIRECTIVE%"
+set "WORKTRUNK_DIRECTIVE_FILE=%_WT_DIRECTIVE%"
+set "WORKTRUNK_SHELL=cmd"
+if defined WORKTRUNK_BIN ("%WORKTRUNK_BIN%" %*) else ({{ cmd }}.exe %*)
+set "_WT_EXIT=%ERRORLEVEL%"
+set "WORKTRUNK_DIRECTIVE_FILE="
+set "WORKTRUNK_SHELL="
+for %%F in ("%_WT_DIRECTIVE%") do set "_WT_SIZE=%%~zF"
+set "_WT_PROTOCOL="
+if %_WT_SIZE% gtr 0 set /p _WT_PROTOCOL=<"%_WT_DIRECTIVE%"
+if %_WT_SIZE% gtr 0 if not "%_WT_PROTOCOL%"=="@rem worktrunk-protocol {{ protocol_version }}" echo {{ cmd }}: shell integration speaks directive protocol {{ protocol_version }} but {{ cmd }} wrote %_WT_PROTOCOL:~24%; restart your shell to reload it 1>&2
+if %_WT_SIZE% gtr 0 call "%_WT_DIRECTIVE%"
+if %_WT_SIZE% gtr 0 if "%_WT_EXIT%"=="0" set "_WT_EXIT=%ERRORLEVEL%"
+del "%_WT_DIRECTIVE%" 2>nul
+set "_WT_DIRECTIVE=" & set "_WT_SIZE=" & set "_WT_PROTOCOL="
+set "_WT_EXIT=" & exit /b %_WT_EXIT%
+]])
+        file:close()
+        os.setalias("{{ cmd }}", '"' .. wrapper .. '" $*')
+    end
+end
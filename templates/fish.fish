@@ -28,13 +28,20 @@ function {{ cmd }}
 
     # --source: use cargo run (builds from source)
     if test $use_source = true
-        WORKTRUNK_DIRECTIVE_FILE=$directive_file cargo run --bin {{ cmd }} --quiet -- $args
+        WORKTRUNK_SHELL=fish WORKTRUNK_DIRECTIVE_FILE=$directive_file cargo run --bin {{ cmd }} --quiet -- $args
     else
-        WORKTRUNK_DIRECTIVE_FILE=$directive_file command $WORKTRUNK_BIN $args
+        WORKTRUNK_SHELL=fish WORKTRUNK_DIRECTIVE_FILE=$directive_file command $WORKTRUNK_BIN $args
     end
     set -l exit_code $status
 
     if test -s "$directive_file"
+        # The binary writes "# worktrunk-protocol N" first. A different N means
+        # this function came from another {{ cmd }} version (e.g. upgraded mid-session).
+        read -l protocol_line < "$directive_file"
+        if string match -q -- '# worktrunk-protocol *' "$protocol_line"; and test "$protocol_line" != "# worktrunk-protocol {{ protocol_version }}"
+            set -l binary_protocol (string replace -- '# worktrunk-protocol ' '' "$protocol_line")
+            echo "{{ cmd }}: shell integration speaks directive protocol {{ protocol_version }} but {{ cmd }} wrote $binary_protocol; restart your shell to reload it" >&2
+        end
         eval (cat "$directive_file" | string collect)
         if test $exit_code -eq 0
             set exit_code $status
@@ -42,6 +42,13 @@ if ((Get-Command {{ cmd }} -ErrorAction SilentlyContinue) -or $env:WORKTRUNK_BIN
         try {
             if ((Test-Path $directiveFile) -and (Get-Item $directiveFile).Length -gt 0) {
                 $script = Get-Content -Path $directiveFile -Raw
+                # The binary writes "# worktrunk-protocol N" first. A different N means
+                # this function came from another {{ cmd }} version (e.g. upgraded mid-session).
+                $protocolLine = ($script -split "`n", 2)[0].TrimEnd("`r")
+                if ($protocolLine -like '# worktrunk-protocol *' -and $protocolLine -ne '# worktrunk-protocol {{ protocol_version }}') {
+                    $binaryProtocol = $protocolLine.Substring('# worktrunk-protocol '.Length)
+                    Write-Warning "{{ cmd }}: shell integration speaks directive protocol {{ protocol_version }} but {{ cmd }} wrote $binaryProtocol; restart your shell to reload it"
+                }
                 if ($script.Trim()) {
                     Invoke-Expression $script
                     # If wt succeeded, use the directive script's exit code
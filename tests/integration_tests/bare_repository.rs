@@ -50,6 +50,24 @@ fn test_bare_repo_list_shows_no_bare_entry() {
     });
 }
 
+#[test]
+fn test_bare_repo_list_without_worktrees_hints_first_switch() {
+    let test = BareRepoTest::new();
+
+    let mut cmd = wt_command();
+    test.configure_wt_cmd(&mut cmd);
+    let output = cmd
+        .arg("list")
+        .current_dir(test.bare_repo_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("No worktrees yet"), "{stderr}");
+    // No branches yet, so the suggestion creates one
+    assert!(stderr.contains("wt switch -c main"), "{stderr}");
+}
+
 #[test]
 fn test_bare_repo_switch_creates_worktree() {
     let test = BareRepoTest::new();
@@ -1319,3 +1319,47 @@ fn test_complete_switch_shows_all_remotes_for_ambiguous_branch(mut repo: TestRep
         "Should show both remotes for ambiguous branch: {stdout}"
     );
 }
+
+#[rstest]
+fn test_complete_switch_shows_commit_subject(repo: TestRepo) {
+    repo.commit("initial");
+    repo.run_git(&["checkout", "-b", "opaque-1234"]);
+    repo.commit("Rework the parser");
+    repo.run_git(&["checkout", "main"]);
+
+    // Fish gets the last commit subject after the category and age
+    let output = repo
+        .completion_cmd_for_shell(&["wt", "switch", ""], "fish")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let line = stdout
+        .lines()
+        .find(|l| l.starts_with("opaque-1234\t"))
+        .unwrap_or_else(|| panic!("missing opaque-1234: {stdout}"));
+    assert!(line.ends_with(" · Rework the parser"), "line: {line}");
+
+    // zsh gets the same description in `branch:description` form
+    let output = repo
+        .completion_cmd_for_shell(&["wt", "switch", ""], "zsh")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let line = stdout
+        .lines()
+        .find(|l| l.starts_with("opaque-1234:"))
+        .unwrap_or_else(|| panic!("missing opaque-1234: {stdout}"));
+    assert!(line.ends_with(" · Rework the parser"), "line: {line}");
+
+    // bash doesn't display descriptions
+    let output = repo
+        .completion_cmd_for_shell(&["wt", "switch", ""], "bash")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("opaque-1234"), "bash: {stdout}");
+    assert!(!stdout.contains("Rework the parser"), "bash: {stdout}");
+}
@@ -119,3 +119,40 @@ run = "echo hello"
         ");
     });
 }
+
+#[rstest]
+fn test_config_edit_creates_missing_file(repo: TestRepo) {
+    let config_path = repo.test_config_path().to_path_buf();
+    let _ = fs::remove_file(&config_path);
+
+    let output = repo
+        .wt_command()
+        .args(["config", "edit"])
+        .env("EDITOR", "true")
+        .env_remove("VISUAL")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Created user config"));
+    assert!(
+        fs::read_to_string(&config_path)
+            .unwrap()
+            .contains("# worktree-path")
+    );
+}
+
+#[rstest]
+fn test_config_edit_reports_parse_error(repo: TestRepo) {
+    repo.write_test_config("worktree-path = [\n");
+
+    let output = repo
+        .wt_command()
+        .args(["config", "edit"])
+        .env("EDITOR", "true")
+        .env_remove("VISUAL")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Invalid config"), "{stderr}");
+}
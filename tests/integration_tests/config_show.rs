@@ -866,6 +866,48 @@ alias wt="git worktree"
     });
 }
 
+/// When a shell config has integration for a different command prefix (`git-wt`
+/// while running as `wt`), warn that it's a separate install.
+#[rstest]
+fn test_config_show_other_prefix_integration_warning(mut repo: TestRepo, temp_home: TempDir) {
+    repo.setup_mock_ci_tools_unauthenticated();
+
+    let global_config_dir = temp_home.path().join(".config").join("worktrunk");
+    fs::create_dir_all(&global_config_dir).unwrap();
+    fs::write(global_config_dir.join("config.toml"), "").unwrap();
+
+    fs::write(
+        temp_home.path().join(".bashrc"),
+        r#"# Some bash config
+eval "$(command git wt config shell init bash)"
+"#,
+    )
+    .unwrap();
+
+    let settings = setup_snapshot_settings_with_home(&repo, &temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        repo.configure_mock_commands(&mut cmd);
+        cmd.arg("config").arg("show").current_dir(repo.root_path());
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("WORKTRUNK_TEST_COMPINIT_CONFIGURED", "1");
+
+        let output = cmd.output().unwrap();
+        assert!(output.status.success());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains("Found integration for")
+                && stderr.contains("git-wt config shell uninstall"),
+            "Expected other-prefix warning, got:\n{stderr}"
+        );
+        assert!(
+            !stderr.contains("but not detected as integration"),
+            "Other-prefix line should not be reported as a false negative:\n{stderr}"
+        );
+    });
+}
+
 /// When a config uses deprecated variables (repo_root, worktree, main_worktree),
 /// the CLI should:
 /// 1. Show a warning listing the deprecated variables and their replacements
@@ -1768,3 +1810,114 @@ fn test_explicit_config_path_not_found_shows_warning(repo: TestRepo) {
         assert_cmd_snapshot!(cmd);
     });
 }
+
+#[rstest]
+fn test_config_show_effective(repo: TestRepo) {
+    repo.write_test_config(&format!(
+        r#"worktree-path = "../{{{{ repo }}}}.{{{{ branch }}}}"
+
+[merge]
+squash = false
+
+[projects.'{}'.merge]
+squash = true
+remove = false
+"#,
+        repo.project_id()
+    ));
+
+    let output = repo
+        .wt_command()
+        .args(["config", "show", "--effective", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["project"], repo.project_id());
+    let entry = |key: &str| {
+        json["values"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|v| v["key"] == key)
+            .cloned()
+            .unwrap_or_else(|| panic!("missing {key}: {json}"))
+    };
+    assert_eq!(
+        entry("worktree-path")["value"],
+        "../{{ repo }}.{{ branch }}"
+    );
+    assert_eq!(entry("worktree-path")["source"], "user");
+    assert_eq!(entry("merge.squash")["value"], true);
+    assert_eq!(entry("merge.squash")["source"], "project");
+    assert_eq!(entry("merge.remove")["value"], false);
+    assert_eq!(entry("merge.verify")["value"], true);
+    assert_eq!(entry("merge.verify")["source"], "default");
+
+    // Default format prints TOML with each value's source
+    let output = repo
+        .wt_command()
+        .args(["config", "show", "--effective"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("merge.squash = true  # project"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("list.full = false  # default"), "{stdout}");
+}
+
+#[rstest]
+fn test_config_profile_selection(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[merge]
+squash = false
+
+[profiles.work.merge]
+squash = true
+"#,
+    );
+
+    let effective = |args: &[&str], env: Option<&str>| {
+        let mut cmd = repo.wt_command();
+        cmd.args(args)
+            .args(["config", "show", "--effective"])
+            .env_remove("WORKTRUNK_CONFIG_PROFILE");
+        if let Some(profile) = env {
+            cmd.env("WORKTRUNK_CONFIG_PROFILE", profile);
+        }
+        cmd.output().unwrap()
+    };
+    let stdout = |output: std::process::Output| {
+        assert!(output.status.success(), "{output:?}");
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    assert!(stdout(effective(&[], None)).contains("merge.squash = false"));
+    assert!(stdout(effective(&["--profile", "work"], None)).contains("merge.squash = true"));
+    assert!(stdout(effective(&[], Some("work"))).contains("merge.squash = true"));
+
+    let output = effective(&["--profile", "home"], None);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Config profile 'home' not found") && stderr.contains("available: work"),
+        "{stderr}"
+    );
+}
+
+#[rstest]
+fn test_config_show_rejects_list_only_formats(repo: TestRepo) {
+    for format in ["--format=jsonl", "--format=markdown"] {
+        let output = repo
+            .wt_command()
+            .args(["config", "show", "--effective", format])
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "{format}: {output:?}");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("invalid value"), "{format}: {stderr}");
+    }
+}
@@ -1046,6 +1046,23 @@ fn test_state_hints_get_with_hints(repo: TestRepo) {
     assert!(stdout.contains("another-hint"));
 }
 
+/// Hints in global git config count as shown, but `clear` only touches the repo's.
+#[rstest]
+fn test_state_hints_global(repo: TestRepo) {
+    repo.git_command()
+        .args(["config", "--global", "worktrunk.hints.global-hint", "true"])
+        .status()
+        .unwrap();
+
+    let output = wt_state_cmd(&repo, "hints", "get", &[]).output().unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("global-hint"));
+
+    let output = wt_state_cmd(&repo, "hints", "clear", &[]).output().unwrap();
+    assert!(output.status.success());
+    assert_snapshot!(String::from_utf8_lossy(&output.stderr), @"[2m○[22m No hints to clear");
+}
+
 #[rstest]
 fn test_state_hints_clear_empty(repo: TestRepo) {
     let output = wt_state_cmd(&repo, "hints", "clear", &[]).output().unwrap();
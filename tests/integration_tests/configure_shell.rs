@@ -757,6 +757,7 @@ fn test_uninstall_shell(repo: TestRepo, temp_home: TempDir) {
         [32m✓[39m [32mRemoved shell extension & completions for [1mzsh[22m @ [1m~/.zshrc[22m[39m
         [2m↳[22m [2mNo [90mbash[39m shell extension & completions in ~/.bashrc[22m
         [2m↳[22m [2mNo [90mfish[39m shell extension in ~/.config/fish/functions/wt.fish[22m
+        [2m↳[22m [2mNo [90mcmd[39m shell extension in ~/.local/share/clink/wt.lua[22m
         [2m↳[22m [2mNo [90mfish[39m completions in ~/.config/fish/completions/wt.fish[22m
 
         [32m✓[39m [32mRemoved integration from 1 shell[39m
@@ -813,6 +814,7 @@ fn test_uninstall_shell_multiple(repo: TestRepo, temp_home: TempDir) {
         [32m✓[39m [32mRemoved shell extension & completions for [1mbash[22m @ [1m~/.bashrc[22m[39m
         [32m✓[39m [32mRemoved shell extension & completions for [1mzsh[22m @ [1m~/.zshrc[22m[39m
         [2m↳[22m [2mNo [90mfish[39m shell extension in ~/.config/fish/functions/wt.fish[22m
+        [2m↳[22m [2mNo [90mcmd[39m shell extension in ~/.local/share/clink/wt.lua[22m
         [2m↳[22m [2mNo [90mfish[39m completions in ~/.config/fish/completions/wt.fish[22m
 
         [32m✓[39m [32mRemoved integration from 2 shells[39m
@@ -1680,3 +1682,73 @@ fn test_powershell_env_detection(repo: TestRepo, temp_home: TempDir) {
         content
     );
 }
+
+#[rstest]
+fn test_config_shell_status_reports_configured_shell(repo: TestRepo, temp_home: TempDir) {
+    fs::write(
+        temp_home.path().join(".zshrc"),
+        "if command -v wt >/dev/null 2>&1; then eval \"$(command wt config shell init zsh)\"; fi\n",
+    )
+    .unwrap();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    set_temp_home_env(&mut cmd, temp_home.path());
+    cmd.env("SHELL", "/bin/zsh");
+    cmd.args(["config", "shell", "status"])
+        .current_dir(repo.root_path());
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("SHELL INTEGRATION"), "stderr: {stderr}");
+    assert!(stderr.contains("Already configured"), "stderr: {stderr}");
+    assert!(stderr.contains(".zshrc:1"), "stderr: {stderr}");
+    assert!(output.stdout.is_empty());
+}
+
+/// `init --print-path` reports install targets without writing them.
+#[rstest]
+fn test_init_print_path(repo: TestRepo, temp_home: TempDir) {
+    let home = dunce::canonicalize(temp_home.path()).unwrap();
+    let run = |args: &[&str]| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/zsh")
+            .env_remove("ZDOTDIR")
+            .args(["config", "shell", "init"])
+            .args(args)
+            .current_dir(repo.root_path());
+        let output = cmd.output().unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    // Shell detected from $SHELL
+    assert_eq!(
+        run(&["--print-path"]).trim(),
+        home.join(".zshrc").to_str().unwrap()
+    );
+
+    let json: serde_json::Value =
+        serde_json::from_str(&run(&["fish", "--print-path", "--format=json"])).unwrap();
+    assert_eq!(json["shell"], "fish");
+    assert_eq!(
+        json["config_paths"][0],
+        home.join(".config/fish/functions/wt.fish")
+            .to_str()
+            .unwrap()
+    );
+    assert!(
+        json["completion_path"]
+            .as_str()
+            .unwrap()
+            .ends_with("fish/completions/wt.fish")
+    );
+    assert!(!home.join(".config/fish").exists());
+}
@@ -0,0 +1,88 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_diff_counts_committed_and_uncommitted(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature");
+    repo.commit_in_worktree(&worktree_path, "a.txt", "one\ntwo\n", "Add a");
+    std::fs::write(worktree_path.join("b.txt"), "three\n").unwrap();
+    repo.run_git_in(&worktree_path, &["add", "b.txt"]);
+
+    let output = repo
+        .wt_command()
+        .args(["diff"])
+        .current_dir(&worktree_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("+3"), "unexpected stdout: {stdout}");
+    assert!(!stdout.contains('-'), "unexpected stdout: {stdout}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("forked from") && stderr.contains("main"),
+        "unexpected stderr: {stderr}"
+    );
+}
+
+/// Commits that land on the target after the fork don't count against the worktree.
+#[rstest]
+fn test_diff_ignores_target_commits_since_fork(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature");
+    repo.commit_in_worktree(&worktree_path, "a.txt", "one\n", "Add a");
+    std::fs::write(repo.root_path().join("main.txt"), "x\ny\nz\n").unwrap();
+    repo.run_git(&["add", "main.txt"]);
+    repo.run_git(&["commit", "-m", "Advance main"]);
+
+    let output = repo
+        .wt_command()
+        .args(["diff"])
+        .current_dir(&worktree_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("+1"), "unexpected stdout: {stdout}");
+    assert!(!stdout.contains('-'), "unexpected stdout: {stdout}");
+}
+
+#[rstest]
+fn test_diff_branch_vs_ref(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature");
+    repo.commit_in_worktree(&worktree_path, "a.txt", "one\n", "Add a");
+
+    // Versus its own HEAD, only uncommitted changes count: none
+    let output = repo
+        .wt_command()
+        .args(["diff", "feature", "--vs", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No changes"), "unexpected stderr: {stderr}");
+}
+
+#[rstest]
+fn test_diff_unknown_ref(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["diff", "--vs", "no-such-ref"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+#[rstest]
+fn test_diff_branch_without_worktree(repo: TestRepo) {
+    repo.create_branch("orphan-branch");
+
+    let output = repo
+        .wt_command()
+        .args(["diff", "orphan-branch"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
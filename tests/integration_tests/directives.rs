@@ -42,6 +42,32 @@ fn test_switch_directive_file(#[from(repo_with_remote)] mut repo: TestRepo) {
     });
 }
 
+/// The first line of the directive file names the protocol version, so the shell
+/// wrapper can detect when it was generated by a different binary.
+#[rstest]
+fn test_directive_file_starts_with_protocol_header(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "feature"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "wt switch failed: {output:?}");
+
+    let directives = fs::read_to_string(&directive_path).unwrap();
+    let mut lines = directives.lines();
+    assert_eq!(lines.next(), Some("# worktrunk-protocol 1"), "{directives}");
+    assert!(
+        lines.all(|line| !line.starts_with("# worktrunk-protocol")),
+        "header should be written once: {directives}"
+    );
+}
+
 #[rstest]
 fn test_merge_directive_file(mut repo_with_remote_and_feature: TestRepo) {
     let repo = &mut repo_with_remote_and_feature;
@@ -334,6 +360,104 @@ fn test_switch_no_cd_execute_runs_in_target_worktree(#[from(repo_with_remote)] r
     });
 }
 
+/// The directive file is sourced as a script, so `--execute` commands with quotes,
+/// semicolons, or directive-like substrings must run exactly as typed.
+#[rstest]
+#[cfg(unix)]
+fn test_switch_execute_special_characters_directive(repo: TestRepo) {
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args([
+            "switch",
+            "--create",
+            "special",
+            "--execute",
+            r#"printf '%s\n' "it's; fine" __WORKTRUNK_EXEC__marker; echo "$(basename "$PWD")""#,
+        ])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "wt switch failed: {output:?}");
+
+    let sourced = std::process::Command::new("sh")
+        .arg(&directive_path)
+        .output()
+        .unwrap();
+    assert!(sourced.status.success(), "sourcing failed: {sourced:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&sourced.stdout),
+        "it's; fine\n__WORKTRUNK_EXEC__marker\nrepo.special\n"
+    );
+}
+
+/// `--execute` env vars reach every part of a compound command and are unset in
+/// the shell that sources the directive file afterwards.
+#[rstest]
+#[cfg(unix)]
+fn test_switch_execute_env_scoped_to_command(repo: TestRepo) {
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args([
+            "switch",
+            "--create",
+            "scoped",
+            "--execute",
+            r#"if true; then echo "$WORKTRUNK_BRANCH"; fi && echo "$WORKTRUNK_BRANCH" # done"#,
+        ])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "wt switch failed: {output:?}");
+
+    let sourced = std::process::Command::new("sh")
+        .arg("-c")
+        .arg(r#". "$1"; echo "${WORKTRUNK_BRANCH:-unset}""#)
+        .arg("sh")
+        .arg(&directive_path)
+        .output()
+        .unwrap();
+    assert!(sourced.status.success(), "sourcing failed: {sourced:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&sourced.stdout),
+        "scoped\nscoped\nunset\n"
+    );
+}
+
+/// `--execute` runs in the sourcing shell, so changes it makes to the session stick.
+#[rstest]
+#[cfg(unix)]
+fn test_switch_execute_changes_persist_in_shell(repo: TestRepo) {
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "--create", "exported", "--execute", "export X=1"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "wt switch failed: {output:?}");
+
+    let sourced = std::process::Command::new("sh")
+        .arg("-c")
+        .arg(r#". "$1"; echo "${X:-unset}""#)
+        .arg("sh")
+        .arg(&directive_path)
+        .output()
+        .unwrap();
+    assert!(sourced.status.success(), "sourcing failed: {sourced:?}");
+    assert_eq!(String::from_utf8_lossy(&sourced.stdout), "1\n");
+}
+
 // ============================================================================
 // Non-Directive Mode Tests (no WORKTRUNK_DIRECTIVE_FILE)
 // ============================================================================
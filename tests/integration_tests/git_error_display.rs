@@ -42,6 +42,7 @@ fn branch_not_found() {
     let err = GitError::BranchNotFound {
         branch: "nonexistent".into(),
         show_create_hint: true,
+        suggestion: None,
     };
 
     assert_snapshot!("branch_not_found", err.to_string());
@@ -52,11 +53,25 @@ fn branch_not_found_no_create_hint() {
     let err = GitError::BranchNotFound {
         branch: "nonexistent".into(),
         show_create_hint: false,
+        suggestion: None,
     };
 
     assert_snapshot!("branch_not_found_no_create_hint", err.to_string());
 }
 
+#[test]
+fn branch_not_found_with_suggestion() {
+    let err = GitError::BranchNotFound {
+        branch: "featur".into(),
+        show_create_hint: true,
+        suggestion: Some("feature".into()),
+    };
+
+    let message = err.to_string();
+    assert!(message.contains("Did you mean"), "{message}");
+    assert!(message.contains("wt switch feature"), "{message}");
+}
+
 #[test]
 fn display_worktree_path_occupied() {
     let err = GitError::WorktreePathOccupied {
@@ -1,6 +1,6 @@
 use crate::common::{
     DAY, HOUR, MINUTE, TestRepo, list_snapshots, make_snapshot_cmd,
-    mock_commands::create_mock_llm_quickstart, repo, repo_with_remote, wt_command,
+    mock_commands::create_mock_llm_quickstart, repo, repo_with_remote, wt_bin, wt_command,
 };
 use insta_cmd::assert_cmd_snapshot;
 use path_slash::PathExt as _;
@@ -426,6 +426,137 @@ fn test_list_with_remotes_and_full(#[from(repo_with_remote)] repo: TestRepo) {
     });
 }
 
+#[rstest]
+fn test_list_fetch_without_remote(repo: TestRepo) {
+    // No remote configured: --fetch is skipped rather than failing
+    let output = repo
+        .wt_command()
+        .args(["list", "--fetch"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "command should succeed");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("Failed to fetch"),
+        "should not attempt a fetch: {stderr}"
+    );
+}
+
+#[rstest]
+fn test_list_fetch_refreshes_remote_refs(#[from(repo_with_remote)] repo: TestRepo) {
+    repo.create_branch("feature-remote");
+    repo.push_branch("feature-remote");
+
+    // Drop the remote-tracking ref so only a fetch can restore it
+    repo.run_git(&["update-ref", "-d", "refs/remotes/origin/feature-remote"]);
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--fetch"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "command should succeed");
+
+    let refs = repo
+        .git_command()
+        .args(["for-each-ref", "refs/remotes/origin/feature-remote"])
+        .output()
+        .unwrap()
+        .stdout;
+    assert!(
+        !refs.is_empty(),
+        "--fetch should restore refs/remotes/origin/feature-remote"
+    );
+}
+
+/// `--no-upstream` (or `list.upstream = false`) skips upstream tracking status.
+#[rstest]
+fn test_list_no_upstream_skips_remote_status(mut repo: TestRepo) {
+    repo.setup_remote("main");
+    let tracked = repo.add_worktree("tracked");
+    repo.run_git_in(&tracked, &["push", "-u", "origin", "tracked"]);
+
+    let remote_of = |args: &[&str]| {
+        let output = repo.wt_command().args(args).output().unwrap();
+        assert!(
+            output.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+        items
+            .into_iter()
+            .find(|item| item["branch"] == "tracked")
+            .unwrap()["remote"]
+            .clone()
+    };
+
+    assert!(remote_of(&["list", "--format=json"]).is_object());
+    assert!(remote_of(&["list", "--format=json", "--no-upstream"]).is_null());
+
+    repo.write_test_config("[list]\nupstream = false\n");
+    assert!(remote_of(&["list", "--format=json"]).is_null());
+}
+
+#[rstest]
+fn test_list_dim_removable_config(mut repo: TestRepo) {
+    // Same commit as main, so the row is potentially removable
+    repo.add_worktree("feature");
+
+    let list_stdout = |repo: &TestRepo| {
+        let output = repo.wt_command().arg("list").output().unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    assert!(list_stdout(&repo).contains("\x1b[2mfeature"));
+
+    repo.write_test_config("[list]\ndim-removable = false\n");
+    let stdout = list_stdout(&repo);
+    assert!(stdout.contains("feature"));
+    assert!(!stdout.contains("\x1b[2mfeature"), "stdout: {stdout}");
+}
+
+#[rstest]
+fn test_list_no_worktrees_shows_only_branches(mut repo: TestRepo) {
+    repo.add_worktree("feature-wt");
+    repo.create_branch("feature-branch");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--branches", "--no-worktrees", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "command should succeed");
+
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let branches: Vec<&str> = items
+        .iter()
+        .map(|item| {
+            assert_eq!(item["kind"], "branch", "unexpected row: {item}");
+            item["branch"].as_str().unwrap()
+        })
+        .collect();
+    assert_eq!(branches, ["feature-branch"]);
+}
+
+#[rstest]
+fn test_list_no_worktrees_requires_branches(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["list", "--no-worktrees"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success(), "command should fail");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--no-worktrees requires --branches"),
+        "unexpected stderr: {stderr}"
+    );
+}
+
 #[rstest]
 fn test_list_with_orphaned_remote_ref(#[from(repo_with_remote)] repo: TestRepo) {
     // Create a remote-tracking ref for a non-existent remote to exercise the
@@ -586,6 +717,181 @@ fn test_list_with_upstream_tracking(mut repo: TestRepo) {
     });
 }
 
+/// `--remote-names` prefixes the Upstream column with the remote name.
+#[rstest]
+fn test_list_remote_names(mut repo: TestRepo) {
+    repo.commit("Initial commit on main");
+    repo.setup_remote("main");
+
+    let ahead_wt = repo.add_worktree("ahead");
+    repo.run_git_in(&ahead_wt, &["push", "-u", "origin", "ahead"]);
+    std::fs::write(ahead_wt.join("ahead.txt"), "ahead").unwrap();
+    repo.run_git_in(&ahead_wt, &["add", "."]);
+    repo.run_git_in(&ahead_wt, &["commit", "-m", "Ahead commit"]);
+
+    let list = |extra: &[&str]| {
+        let output = repo
+            .wt_command()
+            .arg("list")
+            .args(extra)
+            .current_dir(repo.root_path())
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    // Single remote: names hidden by default
+    assert!(!list(&[]).contains("origin"));
+    assert!(list(&["--remote-names"]).contains("origin"));
+}
+
+/// `--conflicts-only` keeps just the rows that would conflict with main.
+#[rstest]
+fn test_list_conflicts_only(mut repo: TestRepo) {
+    std::fs::write(repo.root_path().join("shared.txt"), "base\n").unwrap();
+    repo.run_git(&["add", "shared.txt"]);
+    repo.run_git(&["commit", "-m", "Add shared file"]);
+
+    let clash = repo.add_worktree("clash");
+    std::fs::write(clash.join("shared.txt"), "clash\n").unwrap();
+    repo.run_git_in(&clash, &["commit", "-am", "Clash edit"]);
+
+    let calm = repo.add_worktree("calm");
+    std::fs::write(calm.join("calm.txt"), "calm\n").unwrap();
+    repo.run_git_in(&calm, &["add", "calm.txt"]);
+    repo.run_git_in(&calm, &["commit", "-m", "Calm edit"]);
+
+    std::fs::write(repo.root_path().join("shared.txt"), "main\n").unwrap();
+    repo.run_git(&["commit", "-am", "Main edit"]);
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--conflicts-only", "--format=json"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let branches: Vec<_> = items.iter().map(|item| item["branch"].clone()).collect();
+    assert_eq!(branches, [serde_json::json!("clash")]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Checking for conflicts"));
+}
+
+/// `--stats` counts worktrees that would conflict with main, even without `--full`.
+#[rstest]
+fn test_list_stats_counts_conflicts_without_full(mut repo: TestRepo) {
+    std::fs::write(repo.root_path().join("shared.txt"), "base\n").unwrap();
+    repo.run_git(&["add", "shared.txt"]);
+    repo.run_git(&["commit", "-m", "Add shared file"]);
+
+    let clash = repo.add_worktree("clash");
+    std::fs::write(clash.join("shared.txt"), "clash\n").unwrap();
+    repo.run_git_in(&clash, &["commit", "-am", "Clash edit"]);
+
+    std::fs::write(repo.root_path().join("shared.txt"), "main\n").unwrap();
+    repo.run_git(&["commit", "-am", "Main edit"]);
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--stats"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(combined.contains("1 with conflicts"), "{combined}");
+}
+
+/// `[list] time-format = "long"` spells out the Age column.
+#[rstest]
+fn test_list_time_format_long(repo: TestRepo) {
+    let list = || {
+        let output = repo
+            .wt_command()
+            .arg("list")
+            .current_dir(repo.root_path())
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    assert!(!list().contains(" ago"));
+    repo.write_test_config("[list]\ntime-format = \"long\"\n");
+    assert!(list().contains(" ago"));
+}
+
+#[rstest]
+fn test_list_max_width(mut repo: TestRepo) {
+    repo.add_worktree("feature-with-a-long-branch-name");
+
+    let list = |max_width: &str| {
+        let output = repo
+            .wt_command()
+            .args(["list", "--max-width", max_width])
+            .env_remove("CLICOLOR_FORCE")
+            .env("NO_COLOR", "1")
+            .current_dir(repo.root_path())
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    // Rows are laid out for the requested width, not COLUMNS
+    let stdout = list("40");
+    assert!(stdout.contains("feature"), "{stdout}");
+    for line in stdout.lines() {
+        assert!(line.chars().count() <= 40, "line too wide: {line:?}");
+    }
+
+    // Tiny widths are raised to the minimum rather than hiding everything
+    let stdout = list("1");
+    assert!(stdout.contains("Branch"), "{stdout}");
+    for line in stdout.lines() {
+        assert!(line.chars().count() <= 20, "line too wide: {line:?}");
+    }
+}
+
+/// `maintenance = true` starts a background commit-graph write once and records it.
+#[rstest]
+fn test_list_maintenance_records_commit_graph(repo: TestRepo) {
+    let info = repo.root_path().join(".git/objects/info");
+    let _ = std::fs::remove_file(info.join("commit-graph"));
+    let _ = std::fs::remove_dir_all(info.join("commit-graphs"));
+
+    let recorded = || {
+        repo.git_command()
+            .args(["config", "--get", "worktrunk.maintenance.commit-graph"])
+            .output()
+            .unwrap()
+            .status
+            .success()
+    };
+    let list = || {
+        let output = repo
+            .wt_command()
+            .arg("list")
+            .current_dir(repo.root_path())
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+    };
+
+    // Off by default
+    list();
+    assert!(!recorded());
+
+    repo.write_test_config("maintenance = true\n");
+    list();
+    assert!(recorded());
+}
+
 #[rstest]
 fn test_list_primary_on_different_branch(mut repo: TestRepo) {
     repo.switch_primary_to("develop");
@@ -727,6 +1033,105 @@ fn test_list_json_with_git_operation(mut repo: TestRepo) {
     });
 }
 
+#[rstest]
+fn test_list_legend(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["list", "--legend"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "command should succeed");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for expected in [
+        "Working tree",
+        "Worktree",
+        "Default branch",
+        "Remote",
+        "⤴",
+        "⇡",
+    ] {
+        assert!(
+            stdout.contains(expected),
+            "legend missing {expected}: {stdout}"
+        );
+    }
+}
+
+#[rstest]
+fn test_list_json_with_bisect_operation(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    repo.run_git_in(&feature, &["bisect", "start"]);
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "command should succeed");
+
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let feature_item = items
+        .iter()
+        .find(|item| item["branch"] == "feature")
+        .expect("feature worktree should be listed");
+    assert_eq!(feature_item["operation_state"], "bisect");
+}
+
+/// A submodule that drifted from the recorded commit shows as `§`, not as modified files.
+#[rstest]
+fn test_list_json_submodule_changes(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+
+    // A standalone repo to vendor as a submodule
+    let vendored_src = repo.root_path().parent().unwrap().join("vendored-src");
+    std::fs::create_dir(&vendored_src).unwrap();
+    repo.run_git_in(&vendored_src, &["init", "-q"]);
+    repo.run_git_in(
+        &vendored_src,
+        &["commit", "-q", "--allow-empty", "-m", "Initial"],
+    );
+
+    repo.run_git_in(
+        &feature,
+        &[
+            "-c",
+            "protocol.file.allow=always",
+            "submodule",
+            "add",
+            "-q",
+            vendored_src.to_str().unwrap(),
+            "vendored",
+        ],
+    );
+    repo.run_git_in(&feature, &["commit", "-q", "-m", "Add submodule"]);
+
+    // New commit inside the submodule that the parent doesn't record
+    repo.run_git_in(
+        &feature.join("vendored"),
+        &["commit", "-q", "--allow-empty", "-m", "Drift"],
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "command should succeed");
+
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let feature_item = items
+        .iter()
+        .find(|item| item["branch"] == "feature")
+        .expect("feature worktree should be listed");
+    assert_eq!(feature_item["working_tree"]["submodule"], true);
+    assert_eq!(feature_item["working_tree"]["modified"], false);
+    assert!(
+        feature_item["symbols"].as_str().unwrap().contains('§'),
+        "{feature_item}"
+    );
+}
+
 #[rstest]
 fn test_list_branch_only_with_status(repo: TestRepo) {
     // Test that branch-only entries (no worktree) can display branch-keyed status
@@ -2788,6 +3193,38 @@ fn test_list_skips_operations_for_prunable_worktrees(mut repo: TestRepo) {
     assert_cmd_snapshot!(list_snapshots::command(&repo, repo.root_path()));
 }
 
+/// Git doesn't report locked worktrees as prunable, so a locked worktree whose
+/// directory was deleted out-of-band must be detected by checking the path.
+#[rstest]
+fn test_list_handles_missing_locked_worktree(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature");
+    repo.run_git(&["worktree", "lock", worktree_path.to_str().unwrap()]);
+    std::fs::remove_dir_all(&worktree_path).unwrap();
+
+    let output = repo.wt_command().args(["list"]).output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("⚠ (missing)"), "stdout: {stdout}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("Failed"), "stderr: {stderr}");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = items
+        .iter()
+        .find(|item| item["branch"] == "feature")
+        .unwrap();
+    assert_eq!(feature["worktree"]["state"], "prunable");
+}
+
 /// Tests that branches far behind main show `…` instead of diff stats when
 /// skip_expensive_for_stale is enabled. This saves time in `wt select` for
 /// repos with many stale branches.
@@ -2928,3 +3365,403 @@ fn test_list_nested_worktree_json_is_current(mut repo: TestRepo) {
         "Parent worktree 'main' should NOT be marked as current"
     );
 }
+
+#[rstest]
+fn test_list_since_hides_stale_items(mut repo: TestRepo) {
+    // Default test commits are one day older than TEST_EPOCH
+    repo.add_worktree("stale");
+    repo.create_branch("stale-branch");
+    let recent = repo.add_worktree("recent");
+    std::fs::write(recent.join("recent.txt"), "recent").unwrap();
+    repo.run_git_in(&recent, &["add", "recent.txt"]);
+    repo.git_command()
+        .current_dir(&recent)
+        .env("GIT_AUTHOR_DATE", "2025-01-01T23:00:00Z")
+        .env("GIT_COMMITTER_DATE", "2025-01-01T23:00:00Z")
+        .args(["commit", "-m", "Recent work"])
+        .output()
+        .unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--branches", "--since", "12h", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let branches: Vec<&str> = items
+        .iter()
+        .filter_map(|item| item["branch"].as_str())
+        .collect();
+    // The primary worktree is shown regardless of age
+    assert_eq!(branches, ["main", "recent"]);
+}
+
+#[rstest]
+fn test_list_sort_paths(mut repo: TestRepo) {
+    repo.add_worktree("zeta");
+    repo.add_worktree("alpha");
+    repo.create_branch("yankee");
+    repo.create_branch("bravo");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--branches", "--sort", "paths", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let paths: Vec<&str> = items
+        .iter()
+        .filter_map(|item| item["path"].as_str())
+        .collect();
+    let mut sorted_paths = paths.clone();
+    sorted_paths.sort();
+    assert_eq!(paths, sorted_paths);
+    // Branch-only rows follow the worktrees, by name
+    let branch_only: Vec<&str> = items
+        .iter()
+        .filter(|item| item["path"].is_null())
+        .filter_map(|item| item["branch"].as_str())
+        .collect();
+    assert_eq!(branch_only, ["bravo", "yankee"]);
+}
+
+#[rstest]
+fn test_list_path_filter(mut repo: TestRepo) {
+    let alpha = repo.add_worktree("alpha");
+    repo.add_worktree("beta");
+    repo.create_branch("loose");
+
+    let pattern = format!("{}*", alpha.display());
+    let output = repo
+        .wt_command()
+        .args(["list", "--branches", "--format=json", "--path-filter"])
+        .arg(&pattern)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let branches: Vec<&str> = items
+        .iter()
+        .filter_map(|item| item["branch"].as_str())
+        .collect();
+    // Branch-only rows have no path, so they're dropped too
+    assert_eq!(branches, ["alpha"]);
+}
+
+/// `--active-only` keeps worktrees that some process has as its working directory.
+///
+/// `wt` runs from a shell inside `idle`, which mustn't make it active.
+#[rstest]
+#[cfg(target_os = "linux")]
+fn test_list_active_only(mut repo: TestRepo) {
+    let active = repo.add_worktree("active");
+    let idle = repo.add_worktree("idle");
+
+    // A process in a subdirectory still marks the worktree active
+    let subdir = active.join("src");
+    std::fs::create_dir_all(&subdir).unwrap();
+    let mut process = std::process::Command::new("sleep")
+        .arg("30")
+        .current_dir(&subdir)
+        .spawn()
+        .unwrap();
+
+    let mut shell = std::process::Command::new("sh");
+    repo.configure_wt_cmd(&mut shell);
+    // Not a lone command, so the shell stays around as wt's parent instead of exec'ing it
+    let output = shell
+        .args(["-c", r#""$0" "$@"; status=$?; exit $status"#])
+        .arg(wt_bin())
+        .args(["list", "--branches", "--format=json", "--active-only"])
+        .current_dir(&idle)
+        .output()
+        .unwrap();
+    process.kill().unwrap();
+    process.wait().unwrap();
+
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let branches: Vec<&str> = items
+        .iter()
+        .filter_map(|item| item["branch"].as_str())
+        .collect();
+    assert!(branches.contains(&"active"), "branches: {branches:?}");
+    assert!(!branches.contains(&"idle"), "branches: {branches:?}");
+    let item = items
+        .iter()
+        .find(|item| item["branch"] == "active")
+        .unwrap();
+    assert_eq!(item["active"], true);
+}
+
+#[rstest]
+fn test_list_since_invalid_duration(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["list", "--since", "7x"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown unit"));
+}
+
+#[rstest]
+fn test_list_group_by_status(mut repo: TestRepo) {
+    let dirty = repo.add_worktree("dirty-wt");
+    std::fs::write(dirty.join("scratch.txt"), "wip").unwrap();
+    repo.add_worktree("clean-wt");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--group-by", "status"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    let position = |pred: &dyn Fn(&str) -> bool| {
+        lines
+            .iter()
+            .position(|l| pred(l))
+            .unwrap_or_else(|| panic!("missing line in:\n{stdout}"))
+    };
+    let dirty_header = position(&|l| l.trim() == "Dirty");
+    let clean_header = position(&|l| l.trim() == "Clean");
+    let dirty_row = position(&|l| l.contains("dirty-wt"));
+    let clean_row = position(&|l| l.contains("clean-wt"));
+
+    // Groups needing attention come first, each row under its group's header
+    assert!(dirty_header < dirty_row && dirty_row < clean_header);
+    assert!(clean_header < clean_row);
+}
+
+/// `--fields` keeps only the requested keys, nested ones under their parent.
+#[rstest]
+fn test_list_json_fields(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--fields", "branch,main.ahead"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(!items.is_empty());
+    for item in &items {
+        let keys: Vec<_> = item.as_object().unwrap().keys().collect();
+        assert!(
+            keys.iter().all(|k| *k == "branch" || *k == "main"),
+            "{keys:?}"
+        );
+        if let Some(main) = item.get("main") {
+            let main_keys: Vec<_> = main.as_object().unwrap().keys().collect();
+            assert_eq!(main_keys, ["ahead"]);
+        }
+    }
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--fields", "branch,nope"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown field 'nope'"));
+}
+
+/// `--format=jsonl` prints the `--format=json` array elements, one per line.
+#[rstest]
+fn test_list_jsonl_matches_json_items(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+
+    let run = |format: &str| {
+        let output = repo
+            .wt_command()
+            .args(["list", format])
+            .current_dir(repo.root_path())
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    let array: Vec<serde_json::Value> = serde_json::from_str(&run("--format=json")).unwrap();
+    let lines: Vec<serde_json::Value> = run("--format=jsonl")
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines, array);
+}
+
+/// `--format=markdown` prints a markdown table with escaped pipes and no summary.
+#[rstest]
+fn test_list_format_markdown(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature, "a.txt", "a", "Use a | b");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=markdown"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!stdout.contains('\x1b'), "{stdout}");
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert!(
+        lines[0].starts_with("| ") && lines[0].contains(" Branch "),
+        "{stdout}"
+    );
+    assert!(lines[1].starts_with("| --- |"), "{stdout}");
+    // Every row has the same number of cells as the header
+    let cells = |line: &str| line.replace("\\|", "").matches('|').count();
+    assert!(
+        lines.iter().all(|line| cells(line) == cells(lines[0])),
+        "{stdout}"
+    );
+    assert!(stdout.contains("Use a \\| b"), "{stdout}");
+    assert!(!stdout.contains("Showing"), "{stdout}");
+}
+
+/// `--plain` prints rows with ASCII symbols and single-space separators.
+#[rstest]
+fn test_list_plain_is_ascii(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    std::fs::write(feature.join("new.txt"), "untracked").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--plain"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.is_ascii(), "{stdout}");
+    assert!(!stdout.contains("  "), "{stdout}");
+    let feature_row = stdout
+        .lines()
+        .find(|line| line.split(' ').nth(1) == Some("feature"))
+        .unwrap_or_else(|| panic!("no feature row: {stdout}"));
+    assert!(feature_row.starts_with("+ feature ?"), "{feature_row}");
+    assert!(
+        stdout.lines().last().unwrap().starts_with("Showing"),
+        "{stdout}"
+    );
+}
+
+/// `--since-fetch` marks upstreams whose remote-tracking ref is older than the threshold.
+#[rstest]
+fn test_list_since_fetch_marks_stale_upstream(mut repo: TestRepo) {
+    repo.setup_remote("main");
+    let tracked = repo.add_worktree("tracked");
+    repo.run_git_in(&tracked, &["push", "-u", "origin", "tracked"]);
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let tracked_row = |epoch: u64, args: &[&str]| {
+        let output = repo
+            .wt_command()
+            .args(["list", "--format=markdown"])
+            .args(args)
+            .env("WT_TEST_EPOCH", epoch.to_string())
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .lines()
+            .find(|line| line.contains(" tracked "))
+            .unwrap()
+            .to_string()
+    };
+
+    // Refs were just written, so they're stale only once the clock moves past the threshold
+    let later = now + 3 * 86_400;
+    assert!(tracked_row(later, &["--since-fetch", "1d"]).contains('◷'));
+    assert!(!tracked_row(now, &["--since-fetch", "1d"]).contains('◷'));
+    assert!(!tracked_row(later, &[]).contains('◷'));
+}
+
+/// On case-insensitive filesystems, a cwd spelled with different case than git's
+/// worktree list still marks the current worktree.
+#[cfg(target_os = "macos")]
+#[rstest]
+fn test_list_current_worktree_case_variant_cwd(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    let name = feature
+        .file_name()
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_uppercase();
+    let variant = feature.with_file_name(name);
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .current_dir(&variant)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let items: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let current: Vec<_> = items
+        .iter()
+        .filter(|item| item["is_current"] == true)
+        .map(|item| item["branch"].as_str().unwrap())
+        .collect();
+    assert_eq!(current, ["feature"]);
+}
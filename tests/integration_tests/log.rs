@@ -0,0 +1,94 @@
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_log_lists_branch_commits(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature");
+    repo.commit_in_worktree(&worktree_path, "a.txt", "a", "Add a");
+    repo.commit_in_worktree(&worktree_path, "b.txt", "b", "Add b");
+
+    let output = repo
+        .wt_command()
+        .args(["log"])
+        .current_dir(&worktree_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2, "unexpected stdout: {stdout}");
+    // Newest first
+    assert!(lines[0].ends_with("Add b"), "unexpected stdout: {stdout}");
+    assert!(lines[1].ends_with("Add a"), "unexpected stdout: {stdout}");
+}
+
+#[rstest]
+fn test_log_shows_fork_point(mut repo: TestRepo) {
+    let fork_point = repo.head_sha()[..7].to_string();
+    let worktree_path = repo.add_worktree("feature");
+    repo.commit_in_worktree(&worktree_path, "a.txt", "a", "Add a");
+    // Advancing main doesn't move the fork point
+    repo.commit("Main moves on");
+
+    let output = repo.wt_command().args(["log", "feature"]).output().unwrap();
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("forked from"),
+        "unexpected stderr: {stderr}"
+    );
+    assert!(stderr.contains(&fork_point), "unexpected stderr: {stderr}");
+    // stdout stays just the commit list
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().count(), 1, "unexpected stdout: {stdout}");
+}
+
+#[rstest]
+fn test_log_limit(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature");
+    for i in 0..3 {
+        let name = format!("{i}.txt");
+        repo.commit_in_worktree(&worktree_path, &name, "x", &format!("Commit {i}"));
+    }
+
+    let output = repo
+        .wt_command()
+        .args(["log", "feature", "--limit", "2"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().count(), 2, "unexpected stdout: {stdout}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Showing 2 of 3"),
+        "unexpected stderr: {stderr}"
+    );
+}
+
+#[rstest]
+fn test_log_limit_zero_rejected(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["log", "--limit", "0"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--limit"), "unexpected stderr: {stderr}");
+}
+
+#[rstest]
+fn test_log_default_branch(repo: TestRepo) {
+    let output = repo.wt_command().args(["log"]).output().unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("default branch"),
+        "unexpected stderr: {stderr}"
+    );
+}
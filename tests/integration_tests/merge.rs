@@ -66,6 +66,119 @@ fn test_merge_fast_forward(merge_scenario: (TestRepo, PathBuf)) {
     ));
 }
 
+#[rstest]
+fn test_merge_format_json(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--format=json"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "merge should succeed");
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    assert_eq!(json["target"], "main");
+    assert_eq!(json["removed_worktree"], true);
+    assert_eq!(json["kept"], false);
+    assert_eq!(
+        json["merge_commit"].as_str(),
+        Some(repo.git_output(&["rev-parse", "main"]).as_str())
+    );
+    assert!(json["squashed"].is_boolean());
+    assert!(json.get("preserved_reason").is_none());
+}
+
+#[rstest]
+fn test_merge_format_json_no_remove(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--no-remove", "--format=json"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "merge should succeed");
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    assert_eq!(json["removed_worktree"], false);
+    assert_eq!(json["kept"], true);
+}
+
+/// `--no-ff` records a merge commit even though the history is linear.
+#[rstest]
+fn test_merge_no_ff_creates_merge_commit(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+    let feature_head = repo.git_output(&["rev-parse", "feature"]);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--no-ff", "--no-remove"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    // main's new HEAD has two parents: the old main, then the feature branch
+    let parents = repo.git_output(&["rev-list", "--parents", "-n1", "main"]);
+    let parents: Vec<&str> = parents.split_whitespace().skip(1).collect();
+    assert_eq!(parents, [main_before.as_str(), feature_head.as_str()]);
+
+    let subject = repo.git_output(&["log", "-1", "--format=%s", "main"]);
+    assert_eq!(subject, "Merge branch 'feature' into main");
+
+    // The feature branch itself is left untouched
+    assert_eq!(repo.git_output(&["rev-parse", "feature"]), feature_head);
+}
+
+#[rstest]
+fn test_merge_no_ff_conflicts_with_squash(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--no-ff", "--squash"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--no-ff"), "{stderr}");
+}
+
+/// `--confirm` can't prompt without a terminal, so it requires `--yes` (or `--no-remove`).
+#[rstest]
+fn test_merge_confirm_requires_yes_without_terminal(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--confirm"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--no-remove"), "{stderr}");
+    assert!(feature_wt.exists(), "worktree must survive a refused merge");
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--confirm", "--yes", "--format=json"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    assert_eq!(json["removed_worktree"], true);
+}
+
 ///
 /// When git runs a subcommand, it sets `GIT_EXEC_PATH` in the environment.
 /// Shell integration cannot work in this case because cd directives cannot
@@ -116,6 +229,143 @@ fn test_merge_already_on_target(repo: TestRepo) {
     assert_cmd_snapshot!(make_snapshot_cmd(&repo, "merge", &[], None));
 }
 
+/// `--into-current` merges the default branch into the feature worktree and keeps it.
+#[rstest]
+fn test_merge_into_current(mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "feature.txt", "feature", "Add feature");
+    repo.commit_in_worktree(repo.root_path(), "main.txt", "main", "Advance main");
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "--into-current"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "merge should succeed: {stderr}");
+    assert!(stderr.contains("Merged main into feature"), "{stderr}");
+
+    assert!(
+        feature_wt.join("main.txt").exists(),
+        "main's changes merged"
+    );
+    assert!(feature_wt.exists(), "worktree should be kept");
+}
+
+/// On conflicts, `--into-current` stops with the merge in progress.
+#[rstest]
+fn test_merge_into_current_conflict(mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "shared.txt", "feature side", "Feature edit");
+    repo.commit_in_worktree(repo.root_path(), "shared.txt", "main side", "Main edit");
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "--into-current"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "merge should stop on conflict");
+    assert!(stderr.contains("will conflict"), "{stderr}");
+    assert!(stderr.contains("git merge --continue"), "{stderr}");
+
+    let merge_head = repo
+        .git_command()
+        .args(["rev-parse", "-q", "--verify", "MERGE_HEAD"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        merge_head.status.success(),
+        "merge should be left in progress"
+    );
+}
+
+/// `wt merge` that stopped on a rebase conflict: (feature worktree, feature HEAD before merge)
+fn stopped_merge(repo: &mut TestRepo) -> (PathBuf, String) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "shared.txt", "feature side", "Feature edit");
+    repo.commit_in_worktree(repo.root_path(), "shared.txt", "main side", "Main edit");
+    let feature_head = repo.git_output(&["rev-parse", "feature"]);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success(), "merge should stop on conflict");
+    (feature_wt, feature_head)
+}
+
+#[rstest]
+fn test_merge_abort_after_conflict(mut repo: TestRepo) {
+    let (feature_wt, feature_head) = stopped_merge(&mut repo);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "--abort"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Aborted the rebase of feature"), "{stderr}");
+
+    // Back on the branch, at its pre-merge commit
+    assert_eq!(repo.git_output(&["rev-parse", "feature"]), feature_head);
+    let branch = repo
+        .git_command()
+        .args(["branch", "--show-current"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&branch.stdout).trim(), "feature");
+}
+
+#[rstest]
+fn test_merge_abort_without_merge_in_progress(mut repo: TestRepo) {
+    let feature_wt = repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "--abort"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No merge in progress"), "{stderr}");
+}
+
+#[rstest]
+fn test_merge_continue_after_resolving(mut repo: TestRepo) {
+    let (feature_wt, _) = stopped_merge(&mut repo);
+
+    std::fs::write(feature_wt.join("shared.txt"), "resolved").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "shared.txt"]);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "--continue", "--no-remove", "main"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Rebase completed"), "{stderr}");
+
+    // The target now points at the resolved feature commit
+    assert_eq!(
+        repo.git_output(&["rev-parse", "main"]),
+        repo.git_output(&["rev-parse", "feature"])
+    );
+    let resolved = repo.git_output(&["show", "main:shared.txt"]);
+    assert_eq!(resolved, "resolved");
+}
+
 #[rstest]
 fn test_merge_dirty_working_tree(mut repo: TestRepo) {
     // Create a feature worktree with uncommitted changes
@@ -673,6 +923,33 @@ fn test_merge_post_merge_command_success(mut repo: TestRepo) {
     );
 }
 
+#[rstest]
+fn test_merge_post_merge_env_vars(mut repo: TestRepo) {
+    let config_dir = repo.root_path().join(".config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("wt.toml"),
+        r#"post-merge = 'echo "$WORKTRUNK_MERGED_BRANCH $WORKTRUNK_TARGET_BRANCH $WORKTRUNK_SQUASHED" > post-merge-env.txt'"#,
+    )
+    .unwrap();
+
+    repo.commit("Add config");
+
+    let feature_wt = repo.add_feature();
+
+    let output = make_snapshot_cmd(&repo, "merge", &["main", "--yes"], Some(&feature_wt))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let content = fs::read_to_string(repo.root_path().join("post-merge-env.txt")).unwrap();
+    assert_eq!(content.trim(), "feature main false");
+}
+
 #[rstest]
 fn test_merge_post_merge_command_skipped_with_no_verify(mut repo: TestRepo) {
     // Create project config with post-merge command that writes a marker file
@@ -24,6 +24,7 @@ pub mod config_state;
 pub mod configure_shell;
 pub mod default_branch;
 pub mod diagnostic;
+pub mod diff;
 pub mod directives;
 pub mod doc_templates;
 pub mod e2e_shell;
@@ -37,6 +38,7 @@ pub mod list;
 pub mod list_column_alignment;
 pub mod list_config;
 pub mod list_progressive;
+pub mod log;
 pub mod merge;
 pub mod output_system_guard;
 pub mod post_start_commands;
@@ -501,6 +501,48 @@ approved-commands = [
     );
 }
 
+#[rstest]
+fn test_post_create_env_vars(repo: TestRepo) {
+    use crate::common::wt_command;
+
+    // Hook writes the exported worktree env vars to a file
+    repo.write_project_config(
+        r#"post-create = 'echo "$WORKTRUNK_BRANCH $WORKTRUNK_WORKTREE_PATH" > env.txt'"#,
+    );
+
+    repo.commit("Add config");
+
+    repo.write_test_config(
+        r#"[projects."../origin"]
+approved-commands = ['echo "$WORKTRUNK_BRANCH $WORKTRUNK_WORKTREE_PATH" > env.txt']
+"#,
+    );
+
+    let temp_home = TempDir::new().unwrap();
+    let mut cmd = wt_command();
+    cmd.args(["switch", "--create", "feature-env"])
+        .current_dir(repo.root_path())
+        .env("WORKTRUNK_CONFIG_PATH", repo.test_config_path());
+    set_temp_home_env(&mut cmd, temp_home.path());
+    let output = cmd.output().expect("failed to run wt switch");
+
+    assert!(
+        output.status.success(),
+        "wt switch should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature-env");
+    let contents = fs::read_to_string(worktree_path.join("env.txt")).unwrap();
+    let (branch, path) = contents.trim().split_once(' ').unwrap();
+
+    assert_eq!(branch, "feature-env");
+    assert!(
+        path.ends_with("repo.feature-env"),
+        "WORKTRUNK_WORKTREE_PATH should point at the new worktree: {path}"
+    );
+}
+
 #[rstest]
 fn test_post_create_json_stdin(repo: TestRepo) {
     use crate::common::wt_command;
@@ -236,3 +236,39 @@ fn test_push_no_remote(#[from(repo_with_feature_worktree)] repo: TestRepo) {
     // Try to push without specifying target (should fail - no remote to get default branch)
     snapshot_push("push_no_remote", &repo, &[], Some(feature_wt));
 }
+
+#[rstest]
+fn test_push_set_upstream(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+    let _ = repo
+        .git_command()
+        .args(["branch", "--unset-upstream", "main"])
+        .output();
+    repo.run_git(&["branch", "develop", "main"]);
+
+    let push = |target: &str| {
+        let output = repo
+            .wt_command()
+            .args(["step", "push", "-u", target])
+            .current_dir(&feature_wt)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        String::from_utf8_lossy(&output.stderr).into_owned()
+    };
+
+    // A target with a remote counterpart starts tracking it
+    assert!(push("main").contains("now tracks"));
+    assert_eq!(
+        repo.git_output(&["rev-parse", "--abbrev-ref", "main@{u}"]),
+        "origin/main"
+    );
+
+    // A local-only target is still pushed; tracking is skipped
+    assert!(push("develop").contains("upstream not set"));
+    assert_eq!(
+        repo.git_output(&["rev-parse", "develop"]),
+        repo.git_output(&["rev-parse", "feature"])
+    );
+}
@@ -847,7 +847,7 @@ fn test_remove_from_detached_head_in_worktree(mut repo: TestRepo) {
 
 ///
 /// Covers the foreground detached HEAD code path in handlers.rs.
-/// The output should be "✓ Removed worktree (detached HEAD, no branch to delete)".
+/// The output should be "✓ Removed repo.feature-detached-fg worktree (detached HEAD, no branch to delete)".
 ///
 /// Ignored on Windows: subprocess tests stay in the worktree, causing file locking errors.
 #[rstest]
@@ -1809,3 +1809,142 @@ fn test_remove_pruned_worktree_keep_branch(mut repo: TestRepo) {
         "Branch should still exist"
     );
 }
+
+/// Force-deleting an unmerged branch loses its commits, so removal needs confirmation.
+/// Without a terminal to prompt on, it requires `--yes`.
+#[rstest]
+fn test_remove_force_delete_unmerged_requires_confirmation(mut repo: TestRepo) {
+    let worktree_path =
+        repo.add_worktree_with_commit("feature-unique", "unique.txt", "work", "Add unique work");
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "-D", "--foreground", "feature-unique"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("1 commit not in"),
+        "Should warn about unique commits, stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains("wt remove feature-unique --yes"),
+        "Should suggest --yes, stderr: {stderr}"
+    );
+    assert!(worktree_path.exists(), "Worktree should not be removed");
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "-D", "--yes", "--foreground", "feature-unique"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "--yes should skip confirmation, stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!worktree_path.exists(), "Worktree should be removed");
+}
+
+/// Unpushed commits only matter when the branch is force-deleted while unmerged.
+#[rstest]
+fn test_remove_unpushed_commits_requires_confirmation(mut repo: TestRepo) {
+    repo.setup_remote("main");
+    let worktree_path = repo.add_worktree("feature-unpushed");
+    repo.run_git_in(
+        &worktree_path,
+        &["push", "-u", "origin", "feature-unpushed"],
+    );
+    repo.commit_in_worktree(&worktree_path, "local.txt", "local", "Add local work");
+
+    // Force-deleting loses the commits, and the prompt notes the upstream is behind
+    let output = repo
+        .wt_command()
+        .args(["remove", "-D", "--foreground", "feature-unpushed"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("will be lost"),
+        "Should warn about lost commits, stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains("not pushed to origin/feature-unpushed"),
+        "Should warn about unpushed commits, stderr: {stderr}"
+    );
+    assert!(worktree_path.exists(), "Worktree should not be removed");
+
+    // A safe delete keeps the unmerged branch, so no confirmation is needed
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "feature-unpushed"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!worktree_path.exists(), "Worktree should be removed");
+}
+
+/// A branch integrated into the target is removed without asking, even if unpushed.
+#[rstest]
+fn test_remove_integrated_unpushed_branch_skips_confirmation(mut repo: TestRepo) {
+    repo.setup_remote("main");
+    let worktree_path = repo.add_worktree("feature-landed");
+    repo.run_git_in(&worktree_path, &["push", "-u", "origin", "feature-landed"]);
+    repo.commit_in_worktree(&worktree_path, "landed.txt", "landed", "Add landed work");
+    repo.run_git(&["merge", "--ff-only", "feature-landed"]);
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "-D", "--foreground", "feature-landed"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!worktree_path.exists(), "Worktree should be removed");
+}
+
+/// `remove-confirm` tunes when removal asks for confirmation
+#[rstest]
+fn test_remove_confirm_config(mut repo: TestRepo) {
+    let unique_path =
+        repo.add_worktree_with_commit("feature-unique", "unique.txt", "work", "Add unique work");
+    let merged_path = repo.add_worktree("feature-merged");
+
+    // "never" removes unique work without asking
+    repo.write_test_config("remove-confirm = \"never\"\n");
+    let output = repo
+        .wt_command()
+        .args(["remove", "-D", "--foreground", "feature-unique"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!unique_path.exists());
+
+    // "always" asks even when nothing would be lost
+    repo.write_test_config("remove-confirm = \"always\"\n");
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "feature-merged"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("without confirmation"),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(merged_path.exists());
+}
@@ -2,7 +2,7 @@
 
 use std::fs;
 
-use worktrunk::git::Repository;
+use worktrunk::git::{ConfigScope, Repository, WorktreeCheckout};
 
 use crate::common::TestRepo;
 
@@ -337,6 +337,161 @@ fn test_set_config() {
     assert_eq!(value, Some("new-value".to_string()));
 }
 
+#[test]
+fn test_config_get_set_unset_scoped() {
+    let mut repo = TestRepo::new();
+    let feature_path = repo.add_worktree("feature");
+
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    assert_eq!(
+        repository
+            .config_get("worktrunk.test", ConfigScope::Local)
+            .unwrap(),
+        None
+    );
+    repository
+        .config_set("worktrunk.test", "shared", ConfigScope::Local)
+        .unwrap();
+    assert_eq!(
+        repository
+            .config_get("worktrunk.test", ConfigScope::Local)
+            .unwrap(),
+        Some("shared".to_string())
+    );
+    assert!(
+        repository
+            .config_unset("worktrunk.test", ConfigScope::Local)
+            .unwrap()
+    );
+    assert!(
+        !repository
+            .config_unset("worktrunk.test", ConfigScope::Local)
+            .unwrap()
+    );
+
+    // Worktree scope enables extensions.worktreeConfig and stays in this worktree
+    assert_eq!(
+        repository
+            .config_get("worktrunk.test", ConfigScope::Worktree)
+            .unwrap(),
+        None
+    );
+    repository
+        .config_set("worktrunk.test", "main-only", ConfigScope::Worktree)
+        .unwrap();
+    assert_eq!(
+        repository
+            .config_get("extensions.worktreeConfig", ConfigScope::Local)
+            .unwrap(),
+        Some("true".to_string())
+    );
+    assert_eq!(
+        repository
+            .config_get("worktrunk.test", ConfigScope::Worktree)
+            .unwrap(),
+        Some("main-only".to_string())
+    );
+    let feature = Repository::at(feature_path).unwrap();
+    assert_eq!(
+        feature
+            .config_get("worktrunk.test", ConfigScope::Worktree)
+            .unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_config_get_regexp() {
+    let repo = TestRepo::new();
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    let pattern = r"^worktrunk\.state\..+\.marker$";
+    assert!(
+        repository
+            .config_get_regexp(pattern, ConfigScope::Local)
+            .unwrap()
+            .is_empty()
+    );
+
+    repository
+        .config_set("worktrunk.state.feature.marker", "a b", ConfigScope::Local)
+        .unwrap();
+    repository
+        .config_set("worktrunk.state.feature.stash", "abc", ConfigScope::Local)
+        .unwrap();
+    assert_eq!(
+        repository
+            .config_get_regexp(pattern, ConfigScope::Local)
+            .unwrap(),
+        vec![(
+            "worktrunk.state.feature.marker".to_string(),
+            "a b".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_rev_list_commits() {
+    let repo = TestRepo::new();
+    repo.run_git(&["switch", "-c", "feature"]);
+    repo.commit("First");
+    repo.commit("Second");
+
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    let commits = repository.rev_list_commits("main", "feature", 10).unwrap();
+    let subjects: Vec<_> = commits.iter().map(|(_, s)| s.as_str()).collect();
+    assert_eq!(subjects, ["Second", "First"]);
+    assert!(commits.iter().all(|(sha, _)| !sha.is_empty()));
+
+    let limited = repository.rev_list_commits("main", "feature", 1).unwrap();
+    assert_eq!(limited.len(), 1);
+}
+
+#[test]
+fn test_branch_exists() {
+    let repo = TestRepo::new();
+    repo.create_branch("feature");
+    repo.run_git(&["tag", "v1.0"]);
+
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    assert!(repository.branch_exists("main", false).unwrap());
+    assert!(repository.branch_exists("feature", true).unwrap());
+    // Tags resolve as refs but aren't branches
+    assert!(!repository.branch_exists("v1.0", true).unwrap());
+    assert!(!repository.branch_exists("nonexistent", true).unwrap());
+}
+
+#[test]
+fn test_current_branch() {
+    let repo = TestRepo::new();
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    assert_eq!(repository.current_branch().as_deref(), Some("main"));
+
+    repo.detach_head();
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    assert_eq!(repository.current_branch(), None);
+}
+
+#[test]
+fn test_set_upstream_rejects_non_remote_refs() {
+    let mut repo = TestRepo::new();
+    repo.setup_remote("main");
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+
+    // Local branches, malformed names, and missing remote branches are refused
+    for remote_ref in ["main", "origin/", "/main", "origin/missing"] {
+        assert!(
+            repository.set_upstream("main", remote_ref).is_err(),
+            "{remote_ref} should be rejected"
+        );
+    }
+
+    repository.set_upstream("main", "origin/main").unwrap();
+    assert_eq!(
+        repository.branch("main").upstream().unwrap().as_deref(),
+        Some("origin/main")
+    );
+}
+
 // =============================================================================
 // Bug #1: Tag/branch name collision tests
 // =============================================================================
@@ -487,6 +642,57 @@ fn test_integration_functions_handle_shas() {
     assert!(repository.is_ancestor(&main_sha, &feature_sha).unwrap());
 }
 
+/// Fast-forwards in either direction can't conflict; diverged branches still
+/// go through merge-tree.
+#[test]
+fn test_has_merge_conflicts_ancestry() {
+    let repo = TestRepo::new();
+    fs::write(repo.root_path().join("file.txt"), "base\n").unwrap();
+    repo.run_git(&["add", "file.txt"]);
+    repo.run_git(&["commit", "-m", "Base"]);
+
+    // feature edits file.txt, then main edits the same line
+    repo.run_git(&["checkout", "-b", "feature"]);
+    fs::write(repo.root_path().join("file.txt"), "feature\n").unwrap();
+    repo.run_git(&["commit", "-am", "Feature edit"]);
+
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    // Ancestor in either direction: no conflicts
+    assert!(!repository.has_merge_conflicts("main", "feature").unwrap());
+    assert!(!repository.has_merge_conflicts("feature", "main").unwrap());
+
+    repo.run_git(&["checkout", "main"]);
+    fs::write(repo.root_path().join("file.txt"), "main\n").unwrap();
+    repo.run_git(&["commit", "-am", "Main edit"]);
+    // Diverged: merge-tree finds the conflict
+    assert!(repository.has_merge_conflicts("main", "feature").unwrap());
+}
+
+#[test]
+fn test_commit_metadata_batch() {
+    let repo = TestRepo::new();
+    repo.run_git(&["checkout", "-b", "feature"]);
+    repo.commit("Subject with  spaces ");
+    let feature = repo.git_output(&["rev-parse", "feature"]);
+    let main = repo.git_output(&["rev-parse", "main"]);
+
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    let metadata = repository
+        .commit_metadata(&[feature.as_str(), main.as_str()])
+        .unwrap();
+    assert_eq!(metadata.len(), 2);
+
+    let meta = &metadata[&feature];
+    assert_eq!(meta.subject, "Subject with  spaces");
+    assert_eq!(
+        meta.timestamp,
+        repository.commit_timestamp(&feature).unwrap()
+    );
+    assert_eq!(meta, &repository.commit_details(&feature).unwrap());
+
+    assert!(repository.commit_metadata(&[]).unwrap().is_empty());
+}
+
 /// Test that integration functions correctly handle remote refs.
 #[test]
 fn test_integration_functions_handle_remote_refs() {
@@ -728,6 +934,38 @@ fn test_branch_caches_result() {
     assert_eq!(result1, Some("main".to_string()));
 }
 
+#[test]
+fn test_root_and_git_dir_cached_per_repository() {
+    let repo = TestRepo::new();
+    let root = repo.root_path().to_path_buf();
+    std::fs::create_dir(root.join("sub")).unwrap();
+    let repository = Repository::at(&root).unwrap();
+    let wt = repository.worktree_at(root.join("sub"));
+
+    let worktree_root = wt.root().unwrap();
+    let git_dir = wt.git_dir().unwrap();
+
+    // With `.git` gone, any repeated rev-parse would fail; cached values must not re-run it
+    let hidden = root.join(".git-hidden");
+    std::fs::rename(root.join(".git"), &hidden).unwrap();
+    let cached = (wt.root().unwrap(), wt.git_dir());
+    // A cloned handle shares the cache
+    let clone_git_dir = repository.clone().worktree_at(root.join("sub")).git_dir();
+    std::fs::rename(&hidden, root.join(".git")).unwrap();
+
+    assert_eq!(cached.0, worktree_root);
+    assert_eq!(cached.1.unwrap(), git_dir);
+    assert_eq!(clone_git_dir.unwrap(), git_dir);
+
+    // A fresh instance has its own cache and resolves again
+    let fresh = Repository::at(&root).unwrap();
+    assert!(!fresh.shares_cache_with(&repository));
+    assert_eq!(
+        fresh.worktree_at(root.join("sub")).git_dir().unwrap(),
+        git_dir
+    );
+}
+
 // =============================================================================
 // is_dirty() behavior tests
 // =============================================================================
@@ -763,3 +1001,160 @@ fn test_is_dirty_does_not_detect_skip_worktree_changes() {
         "is_dirty() does not detect skip-worktree changes by design"
     );
 }
+
+#[test]
+fn test_worktree_for_branch_skips_detached_worktree() {
+    let mut repo = TestRepo::new();
+    repo.add_worktree("detached-wt");
+    repo.detach_head_in_worktree("detached-wt");
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+
+    assert_eq!(repository.worktree_for_branch("detached-wt").unwrap(), None);
+    assert!(
+        repository
+            .worktree_info_for_branch("detached-wt")
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_worktree_for_branch_mid_rebase() {
+    let mut repo = TestRepo::new();
+    let wt_path =
+        repo.add_worktree_with_commit("rebasing", "conflict.txt", "feature side", "Feature change");
+    fs::write(repo.root_path().join("conflict.txt"), "main side").unwrap();
+    repo.run_git(&["add", "conflict.txt"]);
+    repo.run_git(&["commit", "-m", "Main change"]);
+
+    // The rebase stops on the conflict, leaving the worktree detached
+    let output = repo
+        .git_command()
+        .args(["rebase", "main"])
+        .current_dir(&wt_path)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    // Resolved from the repository itself, regardless of the process cwd
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    let found = repository
+        .worktree_info_for_branch("rebasing")
+        .unwrap()
+        .unwrap();
+    assert!(found.detached);
+    assert_eq!(
+        found.path.canonicalize().unwrap(),
+        wt_path.canonicalize().unwrap()
+    );
+}
+
+#[test]
+fn test_run_command_streaming_returns_exit_status() {
+    let repo = TestRepo::new();
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+
+    let status = repository
+        .run_command_streaming(&["rev-parse", "HEAD"])
+        .unwrap();
+    assert!(status.success());
+
+    // A failing git command is reported through the status, not as an error
+    let status = repository
+        .run_command_streaming(&["rev-parse", "--verify", "no-such-ref"])
+        .unwrap();
+    assert!(!status.success());
+}
+
+#[test]
+fn test_delete_branch() {
+    let repo = TestRepo::new();
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+
+    // Merged branch: plain -d succeeds
+    repo.run_git(&["branch", "merged"]);
+    repository.delete_branch("merged", false).unwrap();
+    assert!(!repository.branch_exists("merged", false).unwrap());
+
+    // Unmerged branch: -d refuses, force deletes
+    repo.run_git(&["checkout", "-q", "-b", "unmerged"]);
+    repo.commit("Unmerged work");
+    repo.run_git(&["checkout", "-q", "main"]);
+    assert!(repository.delete_branch("unmerged", false).is_err());
+    repository.delete_branch("unmerged", true).unwrap();
+    assert!(!repository.branch_exists("unmerged", false).unwrap());
+
+    // The default branch is never deleted
+    let err = repository.delete_branch("main", true).unwrap_err();
+    assert!(err.to_string().contains("default branch"), "{err}");
+}
+
+#[test]
+fn test_create_worktree() {
+    let repo = TestRepo::new();
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    let parent = repo.root_path().parent().unwrap();
+
+    // New branch from a base
+    let path = parent.join("create-new");
+    let wt = repository
+        .create_worktree(
+            &path,
+            "new",
+            WorktreeCheckout::NewBranch { base: Some("main") },
+        )
+        .unwrap();
+    assert_eq!(wt.branch().unwrap().as_deref(), Some("new"));
+
+    // Existing branch
+    repo.run_git(&["branch", "existing"]);
+    let path = parent.join("create-existing");
+    let wt = repository
+        .create_worktree(&path, "existing", WorktreeCheckout::Existing)
+        .unwrap();
+    assert_eq!(wt.branch().unwrap().as_deref(), Some("existing"));
+
+    // Detached at a commit
+    let head = repo.head_sha();
+    let path = parent.join("create-detached");
+    let wt = repository
+        .create_worktree(&path, &head, WorktreeCheckout::Detached)
+        .unwrap();
+    assert_eq!(wt.branch().unwrap(), None);
+    assert_eq!(repo.head_sha_in(&path), head);
+
+    // Failures name the branch and base
+    let err = repository
+        .create_worktree(
+            &parent.join("create-missing-base"),
+            "other",
+            WorktreeCheckout::NewBranch {
+                base: Some("no-such-base"),
+            },
+        )
+        .unwrap_err();
+    let message = err.to_string();
+    assert!(
+        message.contains("other") && message.contains("no-such-base"),
+        "{message}"
+    );
+}
+
+#[test]
+fn test_has_unpushed_commits() {
+    let mut repo = TestRepo::new();
+    repo.setup_remote("main");
+    let feature_path = repo.add_worktree("feature");
+
+    // No upstream yet
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    assert!(!repository.has_unpushed_commits("feature").unwrap());
+
+    repo.run_git_in(&feature_path, &["push", "-u", "origin", "feature"]);
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    assert!(!repository.has_unpushed_commits("feature").unwrap());
+
+    repo.commit_in_worktree(&feature_path, "local.txt", "local", "Add local work");
+    let repository = Repository::at(repo.root_path().to_path_buf()).unwrap();
+    assert!(repository.has_unpushed_commits("feature").unwrap());
+}
@@ -74,6 +74,52 @@ fn test_switch_create_shows_progress_when_forced(repo: TestRepo) {
     });
 }
 
+/// `--quiet` suppresses hints and progress but keeps the success message.
+#[rstest]
+fn test_switch_create_quiet(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["--quiet", "switch", "--create", "feature-quiet"])
+        .env("WT_TEST_DELAYED_STREAM_MS", "0")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "switch --create should succeed");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Created branch"),
+        "unexpected stderr: {stderr}"
+    );
+    assert!(!stderr.contains('↳'), "hint not suppressed: {stderr}");
+    assert!(!stderr.contains('◎'), "progress not suppressed: {stderr}");
+}
+
+/// A typo in the branch name suggests the closest existing branch.
+#[rstest]
+fn test_switch_nonexistent_branch_suggests_closest(repo: TestRepo) {
+    repo.create_branch("feature-auth");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "feature-aut"])
+        .output()
+        .unwrap();
+    assert!(
+        !output.status.success(),
+        "switch to missing branch should fail"
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("No branch named") && stderr.contains("Did you mean"),
+        "unexpected stderr: {stderr}"
+    );
+    assert!(
+        stderr.contains("feature-auth"),
+        "unexpected stderr: {stderr}"
+    );
+}
+
 #[rstest]
 fn test_switch_create_existing_branch_error(mut repo: TestRepo) {
     // Create a branch first
@@ -119,6 +165,416 @@ fn test_switch_dwim_from_remote(#[from(repo_with_remote)] repo: TestRepo) {
     snapshot_switch("switch_dwim_from_remote", &repo, &["dwim-feature"]);
 }
 
+#[rstest]
+fn test_switch_format_json_create(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature-json", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "switch should succeed");
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    assert_eq!(json["branch"], "feature-json");
+    assert_eq!(json["created"], true);
+    assert_eq!(json["base"], "main");
+    assert!(json["from_remote"].is_null());
+    assert!(
+        json["path"]
+            .as_str()
+            .is_some_and(|p| p.ends_with("repo.feature-json")),
+        "unexpected path: {json}"
+    );
+}
+
+#[rstest]
+fn test_switch_format_json_existing(mut repo: TestRepo) {
+    repo.add_worktree("feature-existing");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "feature-existing", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "switch should succeed");
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    assert_eq!(json["branch"], "feature-existing");
+    assert_eq!(json["created"], false);
+    assert!(json["base"].is_null());
+}
+
+#[rstest]
+fn test_switch_create_base_tag_and_sha(repo: TestRepo) {
+    repo.run_git(&["tag", "v1.2.0"]);
+    let sha = repo.git_output(&["rev-parse", "--short", "HEAD"]);
+
+    for (branch, base) in [("from-tag", "v1.2.0"), ("from-sha", sha.as_str())] {
+        let output = repo
+            .wt_command()
+            .args([
+                "switch",
+                "--create",
+                branch,
+                "--base",
+                base,
+                "--format=json",
+            ])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        let json: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+        // The base is echoed exactly as given, not resolved to a branch or full SHA
+        assert_eq!(json["base"], base);
+        assert!(
+            String::from_utf8_lossy(&output.stderr).contains(base),
+            "success message should name the base"
+        );
+    }
+}
+
+/// `--create --reuse` switches to an existing worktree instead of failing.
+#[rstest]
+fn test_switch_create_reuse(repo: TestRepo) {
+    let switch = |extra: &[&str]| {
+        repo.wt_command()
+            .args(["switch", "--create", "reused", "--format=json"])
+            .args(extra)
+            .output()
+            .unwrap()
+    };
+
+    let first = switch(&["--reuse"]);
+    assert!(first.status.success(), "{first:?}");
+    let json: serde_json::Value = serde_json::from_slice(&first.stdout).unwrap();
+    assert_eq!(json["created"], true);
+
+    // Without --reuse, creating again fails; with it, the existing worktree is used
+    assert!(!switch(&[]).status.success());
+    let again = switch(&["--reuse"]);
+    assert!(again.status.success(), "{again:?}");
+    let json: serde_json::Value = serde_json::from_slice(&again.stdout).unwrap();
+    assert_eq!(json["created"], false);
+    assert!(String::from_utf8_lossy(&again.stderr).contains("Switched to worktree for"));
+}
+
+#[rstest]
+fn test_switch_open_runs_editor_command_last(repo: TestRepo) {
+    let repo_name = repo.root_path().file_name().unwrap().to_str().unwrap();
+    let wt_path = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join(format!("{repo_name}.open-test"));
+
+    // Without editor-command or $EDITOR there's nothing to open; fail before switching
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "open-test", "--open"])
+        .env_remove("VISUAL")
+        .env_remove("EDITOR")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("--open needs an editor"),
+        "{output:?}"
+    );
+    assert!(!wt_path.exists());
+
+    repo.write_test_config("editor-command = \"echo '{{ branch }}' >> order.txt\"\n");
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "open-test", "--open"])
+        .args(["--execute", "echo first > order.txt"])
+        .env_remove("VISUAL")
+        .env_remove("EDITOR")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Executing (--execute, --open)"));
+    assert_eq!(
+        std::fs::read_to_string(wt_path.join("order.txt")).unwrap(),
+        "first\nopen-test\n"
+    );
+}
+
+/// Without `editor-command`, `--open` prefers `$VISUAL` over `$EDITOR`, like `wt config edit`.
+#[rstest]
+fn test_switch_open_prefers_visual(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "visual-test", "--open"])
+        .env("VISUAL", "echo visual > editor.txt")
+        .env("EDITOR", "echo editor > editor.txt")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let repo_name = repo.root_path().file_name().unwrap().to_str().unwrap();
+    let wt_path = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join(format!("{repo_name}.visual-test"));
+    assert_eq!(
+        std::fs::read_to_string(wt_path.join("editor.txt")).unwrap(),
+        "visual .\n"
+    );
+}
+
+/// `--base HEAD` branches from the worktree the command runs in, not the primary.
+#[rstest]
+fn test_switch_create_base_head_from_worktree(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    std::fs::write(feature_path.join("f.txt"), "feature").unwrap();
+    repo.run_git_in(&feature_path, &["add", "f.txt"]);
+    repo.run_git_in(&feature_path, &["commit", "-m", "feature work"]);
+    let feature_head = repo.git_output(&["rev-parse", "feature"]);
+    assert_ne!(feature_head, repo.git_output(&["rev-parse", "main"]));
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "-c", "from-here", "-b", "HEAD", "--no-verify"])
+        .current_dir(&feature_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(repo.git_output(&["rev-parse", "from-here"]), feature_head);
+}
+
+#[rstest]
+fn test_switch_create_dry_run(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "dry-feature", "--dry-run"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let path = std::path::Path::new(stdout.trim());
+    assert!(path.is_absolute(), "expected absolute path: {stdout}");
+    assert!(
+        path.ends_with("repo.dry-feature"),
+        "unexpected path: {stdout}"
+    );
+    assert!(!path.exists(), "dry run must not create the worktree");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Would create worktree"));
+
+    // Nothing was created, so the branch doesn't exist either
+    let branches = repo.git_output(&["branch", "--list", "dry-feature"]);
+    assert!(branches.is_empty());
+
+    let output = repo
+        .wt_command()
+        .args([
+            "switch",
+            "--create",
+            "dry-feature",
+            "--dry-run",
+            "--format=json",
+        ])
+        .output()
+        .unwrap();
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    assert_eq!(json["created"], true);
+    assert_eq!(json["base"], "main");
+    assert_eq!(json["dry_run"], true);
+}
+
+#[rstest]
+fn test_switch_from_stdin(mut repo: TestRepo) {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    repo.add_worktree("feature-stdin");
+
+    let run = |input: &str| {
+        let mut child = repo
+            .wt_command()
+            .args(["switch", "--from-stdin", "--format=json"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(input.as_bytes())
+            .unwrap();
+        child.wait_with_output().unwrap()
+    };
+
+    let output = run("  feature-stdin\n");
+    assert!(output.status.success(), "switch should succeed");
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    assert_eq!(json["branch"], "feature-stdin");
+
+    let output = run("\n");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No branch name on stdin"));
+}
+
+#[rstest]
+fn test_switch_json_stdin(repo: TestRepo) {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let manifest = r#"[
+        {"branch": "bulk-a"},
+        {"branch": "bulk-b", "base": "main"},
+        {"branch": "bulk-a"}
+    ]"#;
+    let mut child = repo
+        .wt_command()
+        .args(["switch", "--json-stdin"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(manifest.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    // The duplicate entry fails, so the command does too, after creating the rest
+    assert!(!output.status.success());
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    assert_eq!(json["created"], 2);
+    assert_eq!(json["failed"], 1);
+    let results = json["results"].as_array().unwrap();
+    assert_eq!(results[0]["branch"], "bulk-a");
+    assert_eq!(results[1]["base"], "main");
+    assert_eq!(results[2]["created"], false);
+    assert_eq!(results[2]["error"], "duplicate in manifest");
+
+    let parent = repo.root_path().parent().unwrap();
+    assert!(parent.join("repo.bulk-a").is_dir());
+    assert!(parent.join("repo.bulk-b").is_dir());
+}
+
+/// Per-switch options don't apply to manifest entries, so they're rejected.
+#[rstest]
+fn test_switch_json_stdin_rejects_switch_options(repo: TestRepo) {
+    for flag in [
+        "--create",
+        "--base=main",
+        "--clobber",
+        "--copy-env",
+        "--stash",
+    ] {
+        let output = repo
+            .wt_command()
+            .args(["switch", "--json-stdin", flag])
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "{flag}");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("cannot be used with"), "{flag}: {stderr}");
+    }
+}
+
+#[rstest]
+fn test_switch_detach_creates_detached_worktree(repo: TestRepo) {
+    let sha = repo.git_output(&["rev-parse", "HEAD"]);
+    let sha = sha.trim();
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--detach", sha, "--format=json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "switch --detach should succeed");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Created detached worktree at"),
+        "unexpected stderr: {stderr}"
+    );
+    assert!(
+        !stderr.contains("worktree for"),
+        "unexpected stderr: {stderr}"
+    );
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    assert!(json["branch"].is_null());
+    assert_eq!(json["created"], true);
+
+    let path = std::path::PathBuf::from(json["path"].as_str().unwrap());
+    let head = repo
+        .git_command()
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .current_dir(&path)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&head.stdout).trim(), "HEAD");
+}
+
+/// A commit-ish that's also a branch name gets its own path, not the branch's
+#[rstest]
+fn test_switch_detach_branch_name(repo: TestRepo) {
+    let short = repo.git_output(&["rev-parse", "--short", "main"]);
+    let output = repo
+        .wt_command()
+        .args(["switch", "--detach", "main", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("Should output valid JSON");
+    let path = std::path::PathBuf::from(json["path"].as_str().unwrap());
+    let expected = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join(format!("repo.detached-{short}"));
+    assert_eq!(
+        dunce::canonicalize(&path).unwrap(),
+        dunce::canonicalize(&expected).unwrap()
+    );
+}
+
+#[rstest]
+fn test_switch_detach_conflicts_with_create(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--detach", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("cannot be used with"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_switch_detach_unknown_commit(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--detach", "no-such-ref"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
 /// When creating a new branch from a remote tracking branch (e.g., origin/main),
 /// the new branch should NOT track the remote base branch.
 /// This prevents accidental `git push` to the base branch (e.g., pushing to main).
@@ -154,6 +610,158 @@ fn test_switch_create_from_remote_base_no_upstream(#[from(repo_with_remote)] rep
     );
 }
 
+/// `--create X --base origin/X` creates X from the remote branch and tracks it,
+/// since pushes go to the same-named branch the user asked for.
+#[rstest]
+fn test_switch_create_from_same_named_remote_base_tracks(#[from(repo_with_remote)] repo: TestRepo) {
+    repo.create_branch("remote-feature");
+    repo.push_branch("remote-feature");
+    repo.run_git(&["branch", "-D", "remote-feature"]);
+
+    let output = repo
+        .wt_command()
+        .args([
+            "switch",
+            "--create",
+            "remote-feature",
+            "--base=origin/remote-feature",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "switch should succeed");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("from origin/remote-feature (tracking)"),
+        "should report tracking: {stderr}"
+    );
+    assert!(
+        !stderr.contains("exists on remote"),
+        "should not warn about shadowing the base: {stderr}"
+    );
+
+    let upstream = repo.git_output(&["rev-parse", "--abbrev-ref", "remote-feature@{upstream}"]);
+    assert_eq!(upstream, "origin/remote-feature");
+}
+
+/// `--create X --track origin` sets `origin/X` as the upstream.
+#[rstest]
+fn test_switch_create_track_sets_upstream(#[from(repo_with_remote)] repo: TestRepo) {
+    repo.create_branch("tracked");
+    repo.push_branch("tracked");
+    repo.run_git(&["branch", "-D", "tracked"]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "tracked", "--track", "origin"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "switch should succeed: {stderr}");
+    assert!(
+        stderr.contains("(tracking origin/tracked)"),
+        "should report tracking: {stderr}"
+    );
+
+    let upstream = repo.git_output(&["rev-parse", "--abbrev-ref", "tracked@{upstream}"]);
+    assert_eq!(upstream, "origin/tracked");
+}
+
+/// `--track` doesn't apply when `--reuse` switches to an existing worktree, so it warns.
+#[rstest]
+fn test_switch_reuse_track_existing_worktree_warns(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.add_worktree("existing");
+
+    let output = repo
+        .wt_command()
+        .args([
+            "switch", "--create", "existing", "--reuse", "--track", "origin",
+        ])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "switch should succeed: {stderr}");
+    assert!(
+        stderr.contains("already has a worktree; --track ignored"),
+        "should warn that --track was ignored: {stderr}"
+    );
+}
+
+/// `--track` warns, rather than fails, when the remote branch doesn't exist yet.
+#[rstest]
+fn test_switch_create_track_missing_remote_branch(#[from(repo_with_remote)] repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "unpushed", "--track", "origin"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "switch should succeed: {stderr}");
+    assert!(
+        stderr.contains("origin/unpushed doesn't exist yet"),
+        "should warn about missing remote branch: {stderr}"
+    );
+
+    let upstream = repo
+        .git_command()
+        .args(["rev-parse", "--abbrev-ref", "unpushed@{upstream}"])
+        .output()
+        .unwrap();
+    assert!(!upstream.status.success(), "upstream should not be set");
+}
+
+/// `--track` with an unknown remote fails before creating anything.
+#[rstest]
+fn test_switch_create_track_unknown_remote(#[from(repo_with_remote)] repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "nowhere", "--track", "upstream"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("No remote named 'upstream'"),
+        "unexpected stderr: {stderr}"
+    );
+
+    let branches = repo.git_output(&["branch", "--list", "nowhere"]);
+    assert!(branches.is_empty(), "branch should not be created");
+}
+
+/// When a local branch is literally named `origin/X`, `--base origin/X` still
+/// means the remote tracking branch.
+#[rstest]
+fn test_switch_create_remote_base_preferred_over_ambiguous_local(
+    #[from(repo_with_remote)] repo: TestRepo,
+) {
+    let old_head = repo.git_output(&["rev-parse", "HEAD"]);
+    repo.run_git(&[
+        "commit",
+        "--allow-empty",
+        "-m",
+        "remote-only commit",
+        "--quiet",
+    ]);
+    repo.run_git(&["branch", "shadowed"]);
+    repo.push_branch("shadowed");
+    let remote_head = repo.git_output(&["rev-parse", "refs/remotes/origin/shadowed"]);
+    repo.run_git(&["branch", "-D", "shadowed"]);
+    repo.run_git(&["branch", "origin/shadowed", &old_head]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "shadowed", "--base=origin/shadowed"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "switch should succeed");
+
+    assert_eq!(
+        repo.git_output(&["rev-parse", "refs/heads/shadowed"]),
+        remote_head
+    );
+}
+
 /// When local branch already exists and tracks a remote, should report
 /// "Created worktree for X" NOT "Created branch X (tracking remote)".
 /// This is the bug fix for GitHub issue #656.
@@ -1367,21 +1975,60 @@ fn test_switch_clobber_path_with_extension(repo: TestRepo) {
 }
 
 #[rstest]
-fn test_switch_create_no_hint_with_custom_worktree_path(repo: TestRepo) {
-    // Set up custom worktree-path in user config
-    repo.write_test_config(r#"worktree-path = ".worktrees/{{ branch | sanitize }}""#);
+fn test_switch_create_no_hint_with_custom_worktree_path(repo: TestRepo) {
+    // Set up custom worktree-path in user config
+    repo.write_test_config(r#"worktree-path = ".worktrees/{{ branch | sanitize }}""#);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "test-no-hint"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("Customize worktree locations"),
+        "Hint should be suppressed when user has custom worktree-path config"
+    );
+}
+
+#[rstest]
+fn test_switch_create_with_named_template(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[templates]
+fix = ".worktrees/fixes/{{ branch | sanitize }}"
+"#,
+    );
 
     let output = repo
         .wt_command()
-        .args(["switch", "--create", "test-no-hint"])
+        .args(["switch", "--create", "bug-1", "--template", "fix"])
         .output()
         .unwrap();
     assert!(output.status.success());
+    assert!(repo.root_path().join(".worktrees/fixes/bug-1").is_dir());
+}
+
+#[rstest]
+fn test_switch_unknown_template_lists_available(repo: TestRepo) {
+    repo.write_test_config(
+        r#"[templates]
+fix = ".worktrees/fixes/{{ branch | sanitize }}"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "bug-2", "--template", "feature"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
 
     let stderr = String::from_utf8_lossy(&output.stderr);
     assert!(
-        !stderr.contains("Customize worktree locations"),
-        "Hint should be suppressed when user has custom worktree-path config"
+        stderr.contains("Unknown template 'feature'; available: fix"),
+        "unexpected stderr: {stderr}"
     );
 }
 
@@ -2989,3 +3636,436 @@ fn test_switch_base_without_create_warns_not_errors(repo: TestRepo) {
         &["base-test", "--base", "-"],
     );
 }
+
+/// `--stash` stashes the current worktree's changes, and switching back restores them.
+#[rstest]
+fn test_switch_stash_restores_on_return(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    std::fs::write(repo.root_path().join("file.txt"), "modified").unwrap();
+    std::fs::write(repo.root_path().join("scratch.txt"), "untracked").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--stash", "feature"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        repo.git_output(&["status", "--porcelain"])
+            .trim()
+            .is_empty(),
+        "main worktree should be clean after --stash"
+    );
+    assert!(!repo.root_path().join("scratch.txt").exists());
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "main"])
+        .current_dir(&feature_path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Restored stashed changes"), "{stderr}");
+    assert_eq!(
+        std::fs::read_to_string(repo.root_path().join("file.txt")).unwrap(),
+        "modified"
+    );
+    assert!(repo.root_path().join("scratch.txt").exists());
+    assert!(
+        repo.git_output(&["stash", "list"]).trim().is_empty(),
+        "stash should be dropped after restoring"
+    );
+}
+
+/// When the switch fails after stashing, `--stash` puts the changes back.
+#[rstest]
+fn test_switch_stash_restored_when_switch_fails(repo: TestRepo) {
+    // A file where the worktree's parent directory should be makes `git worktree add` fail
+    repo.write_test_config(r#"worktree-path = "../blocker/{{ branch }}""#);
+    std::fs::write(repo.root_path().parent().unwrap().join("blocker"), "").unwrap();
+    std::fs::write(repo.root_path().join("file.txt"), "modified").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--stash", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success(), "switch should fail");
+    assert_eq!(
+        std::fs::read_to_string(repo.root_path().join("file.txt")).unwrap(),
+        "modified"
+    );
+    assert!(
+        repo.git_output(&["stash", "list"]).trim().is_empty(),
+        "stash should be dropped after restoring"
+    );
+}
+
+/// `--stash` to the current worktree's branch from a subdirectory stays put and
+/// leaves the changes in place.
+#[rstest]
+fn test_switch_stash_from_subdirectory_of_target(repo: TestRepo) {
+    let subdir = repo.root_path().join("sub");
+    std::fs::create_dir(&subdir).unwrap();
+    std::fs::write(repo.root_path().join("file.txt"), "modified").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--stash", "main"])
+        .current_dir(&subdir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        std::fs::read_to_string(repo.root_path().join("file.txt")).unwrap(),
+        "modified"
+    );
+    assert!(
+        repo.git_output(&["stash", "list"]).trim().is_empty(),
+        "nothing should be stashed"
+    );
+}
+
+/// `--stash` with a clean worktree is a no-op, not an error.
+#[rstest]
+fn test_switch_stash_clean_worktree(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--stash", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No changes to stash"), "{stderr}");
+}
+
+/// `--cd-only` writes the cd directive and nothing else.
+#[rstest]
+fn test_switch_cd_only(mut repo: TestRepo) {
+    let feature_wt = repo.add_worktree("feature");
+
+    let (directive_path, _guard) = directive_file();
+    let mut cmd = repo.wt_command();
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "--cd-only", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert!(output.stdout.is_empty());
+    assert!(
+        output.stderr.is_empty(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let directives = fs::read_to_string(&directive_path).unwrap();
+    // Protocol header, then the cd directive
+    let directives = directives
+        .strip_prefix("# worktrunk-protocol 1\n")
+        .unwrap_or_else(|| panic!("missing protocol header: {directives}"));
+    assert_eq!(directives.lines().count(), 1, "{directives}");
+    assert!(directives.starts_with("cd '"), "{directives}");
+    assert!(directives.ends_with("'\n"), "{directives}");
+    let feature_name = feature_wt.file_name().unwrap().to_str().unwrap();
+    assert!(directives.contains(feature_name), "{directives}");
+}
+
+#[rstest]
+fn test_switch_cd_only_requires_shell_integration(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--cd-only", "feature"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--cd-only needs shell integration"),
+        "{stderr}"
+    );
+}
+
+#[rstest]
+fn test_switch_create_with_name(repo: TestRepo) {
+    let (directive_path, _guard) = directive_file();
+    let mut cmd = repo.wt_command();
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args(["switch", "--create", "feature", "--name", "scratch"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let expected = repo.root_path().parent().unwrap().join("scratch");
+    assert!(expected.is_dir(), "worktree should be at {expected:?}");
+    let branch = repo.git_output(&["-C", expected.to_str().unwrap(), "branch", "--show-current"]);
+    assert_eq!(branch.trim(), "feature");
+
+    let directives = fs::read_to_string(&directive_path).unwrap();
+    assert!(directives.contains("scratch"), "{directives}");
+}
+
+/// `--name` is placed in the template's base directory, not next to where the
+/// branch's own path would go (which differs for branches with slashes).
+#[rstest]
+fn test_switch_create_with_name_uses_worktree_base(repo: TestRepo) {
+    repo.write_test_config("worktree-path = \"../{{ repo }}-wt/{{ branch }}\"\n");
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature/auth", "--name", "scratch"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let repo_name = repo.root_path().file_name().unwrap().to_str().unwrap();
+    let base = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join(format!("{repo_name}-wt"));
+    assert!(base.join("scratch").is_dir());
+    assert!(!base.join("feature").exists());
+}
+
+/// The base comes from rendering the template, so filters, whitespace control
+/// and blocks around the branch don't change where `--name` lands.
+#[rstest]
+#[case::filter_without_spaces("../{{ repo }}-wt/{{branch|sanitize}}")]
+#[case::whitespace_control("../{{ repo }}-wt/{{- branch -}}")]
+#[case::block("{% if true %}../{{ repo }}-wt/{{ branch }}{% endif %}")]
+#[case::set_block("{% set dir = repo ~ '-wt' %}../{{ dir }}/{{ branch | sanitize }}")]
+fn test_switch_create_with_name_template_variants(repo: TestRepo, #[case] template: &str) {
+    repo.write_test_config(&format!("worktree-path = \"{template}\"\n"));
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature/auth", "--name", "scratch"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let repo_name = repo.root_path().file_name().unwrap().to_str().unwrap();
+    let base = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join(format!("{repo_name}-wt"));
+    assert!(
+        base.join("scratch").is_dir(),
+        "worktree should be in {base:?}"
+    );
+}
+
+/// A template that climbs out of the directory holding the branch is rejected
+/// before anything is created.
+#[rstest]
+fn test_switch_create_rejects_template_escaping_base(repo: TestRepo) {
+    repo.write_test_config("worktree-path = \"{{ branch }}/../../outside\"\n");
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("falls outside"), "{stderr}");
+    assert!(!repo.root_path().parent().unwrap().join("outside").exists());
+    assert!(
+        repo.git_output(&["branch", "--list", "feature"])
+            .trim()
+            .is_empty(),
+        "branch should not be created"
+    );
+}
+
+#[rstest]
+fn test_switch_create_with_name_rejects_escaping_path(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature", "--name", "../escape"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--name must be a relative directory name"),
+        "{stderr}"
+    );
+}
+
+#[rstest]
+fn test_switch_name_requires_create(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    let output = repo
+        .wt_command()
+        .args(["switch", "feature", "--name", "scratch"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+}
+
+/// `--base-from-remote-default` branches from `origin/main`, not a local main that's ahead.
+#[rstest]
+fn test_switch_create_base_from_remote_default(#[from(repo_with_remote)] repo: TestRepo) {
+    repo.commit("Local-only commit");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "fresh", "--base-from-remote-default"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("origin/main"));
+
+    assert_eq!(
+        repo.git_output(&["rev-parse", "fresh"]),
+        repo.git_output(&["rev-parse", "origin/main"])
+    );
+    assert_ne!(
+        repo.git_output(&["rev-parse", "fresh"]),
+        repo.git_output(&["rev-parse", "main"])
+    );
+}
+
+/// Without a remote HEAD, `--base-from-remote-default` warns and uses the local default.
+#[rstest]
+fn test_switch_create_base_from_remote_default_falls_back(
+    #[from(repo_with_remote)] repo: TestRepo,
+) {
+    repo.clear_origin_head();
+    repo.commit("Local-only commit");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "fresh", "--base-from-remote-default"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Remote default branch unknown"), "{stderr}");
+    assert_eq!(
+        repo.git_output(&["rev-parse", "fresh"]),
+        repo.git_output(&["rev-parse", "main"])
+    );
+}
+
+/// `--create` from a base that's behind its upstream warns and suggests the remote base.
+#[rstest]
+fn test_switch_create_warns_on_stale_base(mut repo: TestRepo) {
+    repo.setup_remote("main");
+    repo.commit("Remote commit 1");
+    repo.commit("Remote commit 2");
+    repo.run_git(&["push", "origin", "main"]);
+    repo.run_git(&["reset", "--hard", "HEAD~2"]);
+    repo.write_test_config("stale-base-threshold = 1\n");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "fresh"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("2 commits behind"), "{stderr}");
+    assert!(stderr.contains("--base origin/main"), "{stderr}");
+
+    // 0 disables the check
+    repo.write_test_config("stale-base-threshold = 0\n");
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "fresh-2"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("behind"));
+}
+
+/// `--copy-env` copies untracked env files into the new worktree without
+/// overwriting files the branch already has.
+#[rstest]
+fn test_switch_copy_env(repo: TestRepo) {
+    repo.commit_in_worktree(repo.root_path(), ".envrc", "committed", "Add .envrc");
+    std::fs::write(repo.root_path().join(".env"), "SECRET=1\n").unwrap();
+    std::fs::write(repo.root_path().join(".envrc"), "local").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature", "--copy-env"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let feature_path = repo.root_path().parent().unwrap().join("repo.feature");
+    assert_eq!(
+        std::fs::read_to_string(feature_path.join(".env")).unwrap(),
+        "SECRET=1\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(feature_path.join(".envrc")).unwrap(),
+        "committed"
+    );
+}
+
+/// `copy-on-create` copies its files on every create, without `--copy-env`.
+#[rstest]
+fn test_switch_copy_on_create_config(repo: TestRepo) {
+    repo.write_test_config(
+        r#"copy-on-create = ["config/local.toml", "missing.env"]
+"#,
+    );
+    std::fs::create_dir_all(repo.root_path().join("config")).unwrap();
+    std::fs::write(repo.root_path().join("config/local.toml"), "key = 1").unwrap();
+    std::fs::write(repo.root_path().join(".env"), "SECRET=1").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let feature_path = repo.root_path().parent().unwrap().join("repo.feature");
+    assert_eq!(
+        std::fs::read_to_string(feature_path.join("config/local.toml")).unwrap(),
+        "key = 1"
+    );
+    assert!(!feature_path.join(".env").exists());
+    assert!(!feature_path.join("missing.env").exists());
+}